@@ -0,0 +1,39 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! An xcm-emulator network of a Westend relay chain and two VFlow parachains, which exchange XCM
+//! through the relay's UMP and DMP queues and through HRMP channels.
+
+mod vflow;
+mod westend;
+
+pub use vflow::{sibling_sovereign_account, VFlow, VFlowSibling, ALICE, BOB, SIBLING_ID, VFLOW_ID};
+pub use westend::Westend;
+use xcm_emulator::decl_test_networks;
+
+// Also declares `WestendRelay`, `VFlowPara` and `VFlowSiblingPara`, the chains of the network.
+decl_test_networks! {
+    pub struct WestendMockNet {
+        relay_chain = Westend,
+        parachains = vec![
+            VFlow,
+            VFlowSibling,
+        ],
+        bridge = ()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    sibling_sovereign_account, VFlowPara, VFlowParaPallet, VFlowSiblingPara, SIBLING_ID, VFLOW_ID,
+};
+use frame_support::{assert_ok, BoundedVec};
+use parity_scale_codec::Encode;
+use sp_core::{ConstU32, H160, U256};
+use vflow_runtime::{
+    configs::xcm::{RelayLocation, XcmRouter},
+    constants::currency::tVFY,
+    DeploymentPermissions, Runtime, RuntimeCall, RuntimeEvent,
+};
+use xcm::latest::prelude::*;
+use xcm_emulator::{Chain, TestExt};
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV2};
+
+const CONTRACT_BYTECODE: &[u8] = &hex_literal::hex!("6080604052348015600e575f5ffd5b506101298061001c5f395ff3fe6080604052348015600e575f5ffd5b50600436106030575f3560e01c80632e64cec11460345780636057361d14604e575b5f5ffd5b603a6066565b60405160459190608d565b60405180910390f35b606460048036038101906060919060cd565b606e565b005b5f5f54905090565b805f8190555050565b5f819050919050565b6087816077565b82525050565b5f602082019050609e5f8301846080565b92915050565b5f5ffd5b60af816077565b811460b8575f5ffd5b50565b5f8135905060c78160a8565b92915050565b5f6020828403121560df5760de60a4565b5b5f60ea8482850160bb565b9150509291505056fea264697066735822122063f96a57b86a37af1ac0fbf522233470beb0ae3e330dcafa317cb897259fa87364736f6c634300081e0033");
+
+const FEE: u128 = tVFY / 2;
+
+type TrustedSiblings = <VFlowPara as VFlowParaPallet>::TrustedSiblings;
+
+/// The sibling paying, with its sovereign account, for an `EthereumXcm::transact` creating a
+/// contract.
+fn ethereum_create_message() -> Xcm<()> {
+    let call = RuntimeCall::from(pallet_ethereum_xcm::Call::<Runtime>::transact {
+        xcm_transaction:
+            EthereumXcmTransaction::V2(
+                EthereumXcmTransactionV2 {
+                    gas_limit: U256::from(300000),
+                    action: ethereum::TransactionAction::Create,
+                    value: U256::zero(),
+                    input: BoundedVec::<
+                        u8,
+                        ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>,
+                    >::try_from(CONTRACT_BYTECODE.to_vec())
+                    .unwrap(),
+                    access_list: None,
+                },
+            ),
+    });
+    Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), FEE).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), FEE).into(),
+            weight_limit: Unlimited,
+        },
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            fallback_max_weight: None,
+            call: call.encode().into(),
+        },
+    ])
+}
+
+/// Trusts the sibling and, if `deployer`, grants deploy permission to its sovereign account.
+fn setup(deployer: bool) {
+    VFlowPara::execute_with(|| {
+        assert_ok!(TrustedSiblings::trust_sibling(
+            <VFlowPara as Chain>::RuntimeOrigin::root(),
+            SIBLING_ID.into()
+        ));
+        if deployer {
+            assert_ok!(DeploymentPermissions::grant_deploy_permission(
+                <VFlowPara as Chain>::RuntimeOrigin::root(),
+                sibling_sovereign_account(SIBLING_ID).into(),
+            ));
+        }
+    });
+}
+
+fn send_create_from_sibling() {
+    VFlowSiblingPara::execute_with(|| {
+        assert_ok!(send_xcm::<XcmRouter>(
+            Location::new(1, [Parachain(VFLOW_ID)]),
+            ethereum_create_message()
+        ));
+    });
+}
+
+fn created_contract() -> Option<H160> {
+    VFlowPara::events()
+        .into_iter()
+        .find_map(|event| match event {
+            RuntimeEvent::EVM(pallet_evm::Event::Created { address }) => Some(address),
+            _ => None,
+        })
+}
+
+#[test]
+fn deployer_sibling_can_create_contract() {
+    setup(true);
+
+    send_create_from_sibling();
+
+    VFlowPara::execute_with(|| {
+        let contract = created_contract().expect("contract should be created");
+        assert!(!pallet_evm::AccountCodes::<Runtime>::get(contract).is_empty());
+    });
+}
+
+#[test]
+fn sibling_without_deploy_permission_cannot_create_contract() {
+    setup(false);
+
+    send_create_from_sibling();
+
+    VFlowPara::execute_with(|| {
+        assert_eq!(created_contract(), None);
+    });
+}
@@ -0,0 +1,162 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{VFlowPara, VFlowParaPallet, VFlowSiblingPara, BOB, SIBLING_ID, VFLOW_ID};
+use frame_support::{assert_ok, BoundedVec};
+use parity_scale_codec::Encode;
+use sp_core::{ConstU32, U256};
+use vflow_runtime::{
+    configs::xcm::{RelayLocation, XcmRouter},
+    constants::currency::tVFY,
+    Runtime, RuntimeCall, RuntimeEvent,
+};
+use xcm::latest::prelude::*;
+use xcm_emulator::{Chain, TestExt};
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV2};
+
+const FEE: u128 = tVFY / 2;
+const VALUE: u128 = tVFY / 10;
+
+type TrustedSiblings = <VFlowPara as VFlowParaPallet>::TrustedSiblings;
+
+/// The sibling paying, with its sovereign account, for an `EthereumXcm::transact` of `VALUE` to
+/// BOB.
+fn ethereum_transact_message() -> Xcm<()> {
+    let call = RuntimeCall::from(pallet_ethereum_xcm::Call::<Runtime>::transact {
+        xcm_transaction: EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+            gas_limit: U256::from(21000),
+            action: ethereum::TransactionAction::Call(BOB.into()),
+            value: U256::from(VALUE),
+            input:
+                BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::default(
+                ),
+            access_list: None,
+        }),
+    });
+    Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), FEE).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), FEE).into(),
+            weight_limit: Unlimited,
+        },
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            fallback_max_weight: None,
+            call: call.encode().into(),
+        },
+    ])
+}
+
+/// Sends `message` from the sibling to VFlow over their HRMP channel.
+fn send_from_sibling(message: Xcm<()>) {
+    VFlowSiblingPara::execute_with(|| {
+        assert_ok!(send_xcm::<XcmRouter>(
+            Location::new(1, [Parachain(VFLOW_ID)]),
+            message
+        ));
+    });
+}
+
+fn trust_sibling() {
+    VFlowPara::execute_with(|| {
+        assert_ok!(TrustedSiblings::trust_sibling(
+            <VFlowPara as Chain>::RuntimeOrigin::root(),
+            SIBLING_ID.into()
+        ));
+    });
+}
+
+fn processed_successfully() -> bool {
+    VFlowPara::events().iter().any(|event| {
+        matches!(
+            event,
+            RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+                success: true,
+                ..
+            })
+        )
+    })
+}
+
+fn processing_failed() -> bool {
+    VFlowPara::events().iter().any(|event| {
+        matches!(
+            event,
+            RuntimeEvent::MessageQueue(pallet_message_queue::Event::ProcessingFailed { .. })
+        )
+    })
+}
+
+fn bob_balance() -> u128 {
+    VFlowPara::account_data_of(BOB.into()).free
+}
+
+#[test]
+fn untrusted_sibling_cannot_execute() {
+    let bob_before = bob_balance();
+
+    send_from_sibling(ethereum_transact_message());
+
+    VFlowPara::execute_with(|| {
+        assert!(processing_failed());
+        assert!(!processed_successfully());
+    });
+    assert_eq!(bob_balance(), bob_before);
+}
+
+#[test]
+fn trusted_sibling_can_execute_ethereum_transact() {
+    let bob_before = bob_balance();
+    trust_sibling();
+
+    send_from_sibling(ethereum_transact_message());
+
+    VFlowPara::execute_with(|| {
+        assert!(processed_successfully());
+    });
+    assert_eq!(bob_balance(), bob_before + VALUE);
+}
+
+#[test]
+fn trusted_sibling_cannot_execute_unpaid() {
+    trust_sibling();
+
+    send_from_sibling(Xcm(vec![ClearOrigin]));
+
+    VFlowPara::execute_with(|| {
+        assert!(processing_failed());
+        assert!(!processed_successfully());
+    });
+}
+
+#[test]
+fn untrusted_sibling_cannot_execute_anymore() {
+    let bob_before = bob_balance();
+    trust_sibling();
+    VFlowPara::execute_with(|| {
+        assert_ok!(TrustedSiblings::untrust_sibling(
+            <VFlowPara as Chain>::RuntimeOrigin::root(),
+            SIBLING_ID.into()
+        ));
+    });
+
+    send_from_sibling(ethereum_transact_message());
+
+    VFlowPara::execute_with(|| {
+        assert!(processing_failed());
+        assert!(!processed_successfully());
+    });
+    assert_eq!(bob_balance(), bob_before);
+}
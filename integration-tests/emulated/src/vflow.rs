@@ -0,0 +1,114 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Two VFlow parachains of the emulated network: `VFlow` and the sibling sending it XCM.
+
+use cumulus_primitives_core::AggregateMessageOrigin;
+use frame_support::{
+    sp_runtime::{BuildStorage, Storage},
+    traits::OnInitialize,
+};
+use vflow_runtime::{
+    configs::xcm::LocationToAccountId, constants::currency::tVFY, AccountId, Runtime,
+};
+use xcm::prelude::*;
+use xcm_emulator::decl_test_parachains;
+use xcm_executor::traits::ConvertLocation;
+
+pub const VFLOW_ID: u32 = 1;
+pub const SIBLING_ID: u32 = 2000;
+
+pub const ALICE: [u8; 20] = [4u8; 20];
+pub const BOB: [u8; 20] = [5u8; 20];
+
+const ENDOWMENT: u128 = 1_000 * tVFY;
+
+/// The account of the sibling `para_id` on a VFlow parachain.
+pub fn sibling_sovereign_account(para_id: u32) -> AccountId {
+    LocationToAccountId::convert_location(&Location::new(1, [Parachain(para_id)])).unwrap()
+}
+
+/// Genesis of the VFlow parachain `para_id`, endowing ALICE, BOB and the sovereign account of
+/// `sibling`.
+pub fn genesis(para_id: u32, sibling: u32) -> Storage {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![
+            (ALICE.into(), ENDOWMENT),
+            (BOB.into(), ENDOWMENT),
+            (sibling_sovereign_account(sibling), ENDOWMENT),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    parachain_info::GenesisConfig::<Runtime> {
+        parachain_id: para_id.into(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    pallet_xcm::GenesisConfig::<Runtime> {
+        safe_xcm_version: Some(XCM_VERSION),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    t
+}
+
+decl_test_parachains! {
+    pub struct VFlow {
+        genesis = genesis(VFLOW_ID, SIBLING_ID),
+        // `FixedVelocityConsensusHook` reads the slot info written by `AuraExt`, whose hooks the
+        // emulator doesn't run.
+        on_init = {
+            vflow_runtime::AuraExt::on_initialize(1);
+        },
+        runtime = vflow_runtime,
+        core = {
+            XcmpMessageHandler: vflow_runtime::XcmpQueue,
+            LocationToAccountId: vflow_runtime::configs::xcm::LocationToAccountId,
+            ParachainInfo: vflow_runtime::ParachainInfo,
+            MessageOrigin: AggregateMessageOrigin,
+        },
+        pallets = {
+            Balances: vflow_runtime::Balances,
+            TrustedSiblings: vflow_runtime::TrustedSiblings,
+        }
+    },
+    pub struct VFlowSibling {
+        genesis = genesis(SIBLING_ID, VFLOW_ID),
+        on_init = {
+            vflow_runtime::AuraExt::on_initialize(1);
+        },
+        runtime = vflow_runtime,
+        core = {
+            XcmpMessageHandler: vflow_runtime::XcmpQueue,
+            LocationToAccountId: vflow_runtime::configs::xcm::LocationToAccountId,
+            ParachainInfo: vflow_runtime::ParachainInfo,
+            MessageOrigin: AggregateMessageOrigin,
+        },
+        pallets = {
+            Balances: vflow_runtime::Balances,
+            TrustedSiblings: vflow_runtime::TrustedSiblings,
+        }
+    }
+}
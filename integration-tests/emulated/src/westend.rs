@@ -0,0 +1,107 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Westend relay chain of the emulated network.
+
+use emulated_integration_tests_common::{accounts, get_host_config, validators};
+use frame_support::sp_runtime::{BuildStorage, Perbill, Storage};
+use westend_runtime::{
+    BabeConfig, BalancesConfig, ConfigurationConfig, RuntimeGenesisConfig, SessionConfig,
+    SessionKeys, StakingConfig, BABE_GENESIS_EPOCH_CONFIG,
+};
+use westend_runtime_constants::currency::UNITS as WND;
+use xcm_emulator::decl_test_relay_chains;
+
+const ENDOWMENT: u128 = 1_000_000 * WND;
+const STASH: u128 = 100 * WND;
+
+/// A single validator with the relay host configuration that opens the HRMP channels the
+/// emulator routes through.
+pub fn genesis() -> Storage {
+    let authorities = validators::initial_authorities();
+    RuntimeGenesisConfig {
+        balances: BalancesConfig {
+            balances: accounts::init_balances()
+                .into_iter()
+                .map(|account| (account, ENDOWMENT))
+                .collect(),
+        },
+        session: SessionConfig {
+            keys: authorities
+                .iter()
+                .map(|x| {
+                    (
+                        x.0.clone(),
+                        x.0.clone(),
+                        SessionKeys {
+                            babe: x.2.clone(),
+                            grandpa: x.3.clone(),
+                            para_validator: x.4.clone(),
+                            para_assignment: x.5.clone(),
+                            authority_discovery: x.6.clone(),
+                            beefy: x.7.clone(),
+                        },
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        },
+        staking: StakingConfig {
+            validator_count: authorities.len() as u32,
+            minimum_validator_count: 1,
+            stakers: authorities
+                .iter()
+                .map(|x| {
+                    (
+                        x.0.clone(),
+                        x.1.clone(),
+                        STASH,
+                        pallet_staking::StakerStatus::Validator,
+                    )
+                })
+                .collect(),
+            invulnerables: authorities.iter().map(|x| x.0.clone()).collect(),
+            force_era: pallet_staking::Forcing::ForceNone,
+            slash_reward_fraction: Perbill::from_percent(10),
+            ..Default::default()
+        },
+        babe: BabeConfig {
+            epoch_config: BABE_GENESIS_EPOCH_CONFIG,
+            ..Default::default()
+        },
+        configuration: ConfigurationConfig {
+            config: get_host_config(),
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+}
+
+decl_test_relay_chains! {
+    #[api_version(12)]
+    pub struct Westend {
+        genesis = genesis(),
+        on_init = (),
+        runtime = westend_runtime,
+        core = {
+            SovereignAccountOf: westend_runtime::xcm_config::LocationConverter,
+        },
+        pallets = {
+            XcmPallet: westend_runtime::XcmPallet,
+            Balances: westend_runtime::Balances,
+        }
+    }
+}
@@ -38,7 +38,7 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::H256;
 use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
-use vflow_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use vflow_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
 pub use self::eth::EthDeps;
 use crate::rpc::eth::create_eth;
@@ -94,6 +94,7 @@ where
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+    C::Api: pallet_xcm_tracing_rpc::XcmTracingRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: sp_consensus_aura::AuraApi<Block, AuraId>,
     C::Api: BlockBuilder<Block>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
@@ -104,6 +105,7 @@ where
     BE: Backend<Block> + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use pallet_xcm_tracing_rpc::{XcmTracing, XcmTracingApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcExtension::new(());
@@ -115,7 +117,8 @@ where
     } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(XcmTracing::new(client).into_rpc())?;
 
     let mut module = create_eth::<_, _, _, _, _, _, DefaultEthConfig<C, BE>>(
         module,
@@ -0,0 +1,126 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+    fungible::{Inspect, Mutate},
+    EnsureOrigin,
+};
+use sp_runtime::traits::Convert;
+
+/// Returns the relay chain account of `origin`, a successful `RelayAccountOrigin`.
+fn relay_account_of<T: Config>(origin: &T::RuntimeOrigin) -> AccountId32 {
+    let location = T::RelayAccountOrigin::ensure_origin(origin.clone())
+        .expect("The successful origin must be a relay chain account");
+    match location.unpack() {
+        (1, [Junction::AccountId32 { id, .. }]) => (*id).into(),
+        _ => panic!("The successful origin must be a relay chain account"),
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+    use frame_system::RawOrigin;
+
+    #[benchmark]
+    fn link_account() {
+        let caller: T::AccountId = whitelisted_caller();
+        let relay_account = T::BenchmarkHelper::relay_account();
+        // Worst case: the signature of the wrapped payload is only accepted by the second check.
+        let payload = Pallet::<T>::link_payload(&relay_account, &caller);
+        let signature =
+            T::BenchmarkHelper::relay_sign(&[BYTES_PREFIX, &payload[..], BYTES_SUFFIX].concat());
+
+        #[extrinsic_call]
+        link_account(
+            RawOrigin::Signed(caller.clone()),
+            relay_account.clone(),
+            signature,
+        );
+
+        assert_eq!(LinkedAccounts::<T>::get(relay_account), Some(caller));
+    }
+
+    #[benchmark]
+    fn link_account_from_relay() -> Result<(), BenchmarkError> {
+        let origin = T::RelayAccountOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let relay_account = relay_account_of::<T>(&origin);
+        let account = T::BenchmarkHelper::account();
+        let signature =
+            T::BenchmarkHelper::sign(&Pallet::<T>::link_payload(&relay_account, &account));
+
+        #[extrinsic_call]
+        link_account_from_relay(origin as T::RuntimeOrigin, account.clone(), signature);
+
+        assert_eq!(LinkedAccounts::<T>::get(relay_account), Some(account));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unlink_account() {
+        let caller: T::AccountId = whitelisted_caller();
+        let relay_account = T::BenchmarkHelper::relay_account();
+        LinkedAccounts::<T>::insert(&relay_account, &caller);
+        RelayAccounts::<T>::insert(&caller, &relay_account);
+
+        #[extrinsic_call]
+        unlink_account(RawOrigin::Signed(caller.clone()));
+
+        assert!(LinkedAccounts::<T>::get(relay_account).is_none());
+        assert!(RelayAccounts::<T>::get(caller).is_none());
+    }
+
+    #[benchmark]
+    fn unlink_account_from_relay() -> Result<(), BenchmarkError> {
+        let origin = T::RelayAccountOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let relay_account = relay_account_of::<T>(&origin);
+        let account = T::BenchmarkHelper::account();
+        LinkedAccounts::<T>::insert(&relay_account, &account);
+        RelayAccounts::<T>::insert(&account, &relay_account);
+
+        #[extrinsic_call]
+        unlink_account_from_relay(origin as T::RuntimeOrigin);
+
+        assert!(LinkedAccounts::<T>::get(relay_account).is_none());
+        assert!(RelayAccounts::<T>::get(account).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_legacy_account() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let relay_account = T::BenchmarkHelper::relay_account();
+        LinkedAccounts::<T>::insert(&relay_account, &caller);
+        RelayAccounts::<T>::insert(&caller, &relay_account);
+        let legacy_account =
+            T::LegacyAccount::convert(relay_account).ok_or(BenchmarkError::Weightless)?;
+        let amount = T::Currency::minimum_balance() + 1_000_000u32.into();
+        T::Currency::set_balance(&legacy_account, amount);
+
+        #[extrinsic_call]
+        claim_legacy_account(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(T::Currency::balance(&caller), amount);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
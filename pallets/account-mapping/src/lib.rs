@@ -0,0 +1,339 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Links the accounts of the relay chain to the accounts of this chain, so that the assets sent
+//! to a relay chain account over XCM land on an account its owner controls here.
+//!
+//! A link needs the consent of both the accounts: the one dispatching the call proves its
+//! ownership through the origin, while the other one signs the payload returned by
+//! [`Pallet::link_payload`]. A relay chain account dispatches its calls with an XCM `Transact`.
+//! Its signature is accepted both of the payload and of the payload wrapped in
+//! `<Bytes>…</Bytes>`, which is what the relay chain wallets sign when asked to sign raw bytes.
+//!
+//! Before the links, the assets sent to a relay chain account could land on an account of this
+//! chain nobody controls, given by `Config::LegacyAccount`. Once linked, the account of this chain
+//! moves the balance left there to itself with [`Pallet::claim_legacy_account`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+use alloc::vec::Vec;
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use parity_scale_codec::Encode;
+use sp_runtime::{
+    traits::{Verify, Zero},
+    AccountId32,
+};
+use xcm::latest::{Junction, Location};
+
+/// The context signed, together with the genesis hash, the accounts and the nonce, to consent to
+/// a link.
+pub const LINK_CONTEXT: &[u8] = b"vflow:link-account";
+
+/// The prefix and the suffix the relay chain wallets add to the raw bytes they sign.
+const BYTES_PREFIX: &[u8] = b"<Bytes>";
+const BYTES_SUFFIX: &[u8] = b"</Bytes>";
+
+/// Provides the signatures needed to benchmark the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+    /// Returns an account of this chain able to sign through `sign`.
+    fn account() -> AccountId;
+    /// Signs `message` with the key of the account returned by `account`.
+    fn sign(message: &[u8]) -> Signature;
+    /// Returns a relay chain account able to sign through `relay_sign`.
+    fn relay_account() -> AccountId32;
+    /// Signs `message` with the key of the account returned by `relay_account`.
+    fn relay_sign(message: &[u8]) -> sp_runtime::MultiSignature;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        dispatch::DispatchResult,
+        pallet_prelude::*,
+        traits::{
+            fungible,
+            tokens::{Fortitude, Preservation},
+        },
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{Convert, IdentifyAccount},
+        MultiSignature,
+    };
+
+    pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The signature of the accounts of this chain.
+        type Signature: Parameter + Verify<Signer = Self::Signer>;
+        /// The signer of the accounts of this chain.
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+        /// The origin of the relay chain accounts, yielding their location.
+        type RelayAccountOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
+        /// The currency of the accounts of this chain.
+        type Currency: fungible::Mutate<Self::AccountId>;
+        /// The account of this chain which received the assets sent to each relay chain account
+        /// before the links, if any.
+        type LegacyAccount: Convert<AccountId32, Option<Self::AccountId>>;
+        type WeightInfo: WeightInfo;
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::Signature>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A relay chain account has been linked to an account of this chain.
+        AccountLinked {
+            /// The relay chain account.
+            relay_account: AccountId32,
+            /// The account of this chain.
+            account: T::AccountId,
+        },
+        /// A relay chain account has been unlinked from an account of this chain.
+        AccountUnlinked {
+            /// The relay chain account.
+            relay_account: AccountId32,
+            /// The account of this chain.
+            account: T::AccountId,
+        },
+        /// The balance of the legacy account of a relay chain account has been moved to the
+        /// account of this chain linked to it.
+        LegacyAccountClaimed {
+            /// The relay chain account.
+            relay_account: AccountId32,
+            /// The legacy account of the relay chain account.
+            legacy_account: T::AccountId,
+            /// The account of this chain.
+            account: T::AccountId,
+            /// The balance moved.
+            amount: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The signature does not match the link payload.
+        InvalidSignature,
+        /// The origin is not a relay chain account.
+        NotRelayAccount,
+        /// The relay chain account is already linked.
+        RelayAccountAlreadyLinked,
+        /// The account of this chain is already linked.
+        AccountAlreadyLinked,
+        /// The account is not linked.
+        NotLinked,
+        /// The relay chain account has no legacy account.
+        NoLegacyAccount,
+    }
+
+    /// The account of this chain linked to each relay chain account.
+    #[pallet::storage]
+    pub type LinkedAccounts<T> =
+        StorageMap<_, Blake2_128Concat, AccountId32, T::AccountId, OptionQuery>;
+
+    /// The relay chain account linked to each account of this chain.
+    #[pallet::storage]
+    pub type RelayAccounts<T> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, AccountId32, OptionQuery>;
+
+    /// The number of links made by each relay chain account, which makes every signed link
+    /// payload usable only once.
+    #[pallet::storage]
+    pub type LinkNonces<T> = StorageMap<_, Blake2_128Concat, AccountId32, u32, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Links the signing account to `relay_account`, which consents by signing the link
+        /// payload with `signature`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::link_account())]
+        pub fn link_account(
+            origin: OriginFor<T>,
+            relay_account: AccountId32,
+            signature: MultiSignature,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            let payload = Self::link_payload(&relay_account, &account);
+            ensure!(
+                Self::verify_relay_signature(&signature, &payload, &relay_account),
+                Error::<T>::InvalidSignature
+            );
+            Self::link(relay_account, account)
+        }
+
+        /// Links the relay chain account dispatching the call to `account`, which consents by
+        /// signing the link payload with `signature`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::link_account_from_relay())]
+        pub fn link_account_from_relay(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            signature: T::Signature,
+        ) -> DispatchResult {
+            let relay_account = Self::ensure_relay_account(origin)?;
+            let payload = Self::link_payload(&relay_account, &account);
+            ensure!(
+                signature.verify(&payload[..], &account),
+                Error::<T>::InvalidSignature
+            );
+            Self::link(relay_account, account)
+        }
+
+        /// Unlinks the signing account from its relay chain account.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::unlink_account())]
+        pub fn unlink_account(origin: OriginFor<T>) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            let relay_account = RelayAccounts::<T>::get(&account).ok_or(Error::<T>::NotLinked)?;
+            Self::unlink(relay_account, account)
+        }
+
+        /// Unlinks the relay chain account dispatching the call from its account of this chain.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::unlink_account_from_relay())]
+        pub fn unlink_account_from_relay(origin: OriginFor<T>) -> DispatchResult {
+            let relay_account = Self::ensure_relay_account(origin)?;
+            let account = LinkedAccounts::<T>::get(&relay_account).ok_or(Error::<T>::NotLinked)?;
+            Self::unlink(relay_account, account)
+        }
+
+        /// Moves the whole balance of the legacy account of the relay chain account linked to the
+        /// signing account to the signing account.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::claim_legacy_account())]
+        pub fn claim_legacy_account(origin: OriginFor<T>) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            let relay_account = RelayAccounts::<T>::get(&account).ok_or(Error::<T>::NotLinked)?;
+            let legacy_account = T::LegacyAccount::convert(relay_account.clone())
+                .ok_or(Error::<T>::NoLegacyAccount)?;
+
+            let amount = <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(
+                &legacy_account,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            <T::Currency as fungible::Mutate<T::AccountId>>::transfer(
+                &legacy_account,
+                &account,
+                amount,
+                Preservation::Expendable,
+            )?;
+            Self::deposit_event(Event::<T>::LegacyAccountClaimed {
+                relay_account,
+                legacy_account,
+                account,
+                amount,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether `signature` is the signature by `relay_account` of `payload`, either as is or
+        /// wrapped in `<Bytes>…</Bytes>`.
+        fn verify_relay_signature(
+            signature: &MultiSignature,
+            payload: &[u8],
+            relay_account: &AccountId32,
+        ) -> bool {
+            signature.verify(payload, relay_account)
+                || signature.verify(
+                    &[BYTES_PREFIX, payload, BYTES_SUFFIX].concat()[..],
+                    relay_account,
+                )
+        }
+
+        fn ensure_relay_account(origin: OriginFor<T>) -> Result<AccountId32, DispatchError> {
+            let location = T::RelayAccountOrigin::ensure_origin(origin)?;
+            match location.unpack() {
+                (1, [Junction::AccountId32 { id, .. }]) => Ok((*id).into()),
+                _ => Err(Error::<T>::NotRelayAccount.into()),
+            }
+        }
+
+        fn link(relay_account: AccountId32, account: T::AccountId) -> DispatchResult {
+            ensure!(
+                !LinkedAccounts::<T>::contains_key(&relay_account),
+                Error::<T>::RelayAccountAlreadyLinked
+            );
+            ensure!(
+                !RelayAccounts::<T>::contains_key(&account),
+                Error::<T>::AccountAlreadyLinked
+            );
+
+            LinkedAccounts::<T>::insert(&relay_account, &account);
+            RelayAccounts::<T>::insert(&account, &relay_account);
+            LinkNonces::<T>::mutate(&relay_account, |nonce| *nonce = nonce.saturating_add(1));
+            Self::deposit_event(Event::<T>::AccountLinked {
+                relay_account,
+                account,
+            });
+            Ok(())
+        }
+
+        fn unlink(relay_account: AccountId32, account: T::AccountId) -> DispatchResult {
+            LinkedAccounts::<T>::remove(&relay_account);
+            RelayAccounts::<T>::remove(&account);
+            Self::deposit_event(Event::<T>::AccountUnlinked {
+                relay_account,
+                account,
+            });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the payload to sign to consent to linking `relay_account` to `account`. It includes
+    /// the genesis hash, so that it cannot be replayed on another chain.
+    pub fn link_payload(relay_account: &AccountId32, account: &T::AccountId) -> Vec<u8> {
+        (
+            LINK_CONTEXT,
+            frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+            relay_account,
+            account,
+            LinkNonces::<T>::get(relay_account),
+        )
+            .encode()
+    }
+
+    /// Returns the account of this chain linked to `relay_account`, if any.
+    pub fn linked_account(relay_account: &AccountId32) -> Option<T::AccountId> {
+        LinkedAccounts::<T>::get(relay_account)
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_account_mapping;
+use fp_account::{AccountId20, EthereumSignature, EthereumSigner};
+use frame_support::{derive_impl, parameter_types};
+use frame_system::EnsureRootWithSuccess;
+use sp_core::{ecdsa, keccak_256, sr25519, Pair};
+use sp_runtime::{
+    traits::{Convert, IdentifyAccount},
+    AccountId32, BuildStorage, MultiSignature,
+};
+use xcm::latest::{Junction, Location};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type PalletAccountMapping = pallet_account_mapping::Pallet<Test>;
+}
+
+// System pallet configuration
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = AccountId20;
+    type Lookup = sp_runtime::traits::IdentityLookup<AccountId20>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+/// The legacy account of a relay chain account is made of the last 20 bytes of its identifier.
+pub struct TruncatedAccount;
+impl Convert<AccountId32, Option<AccountId20>> for TruncatedAccount {
+    fn convert(relay_account: AccountId32) -> Option<AccountId20> {
+        legacy_account_of(&relay_account)
+    }
+}
+
+parameter_types! {
+    /// The location yielded by the root origin, standing for the relay chain account origin.
+    pub static RelayOrigin: Location = relay_location(&relay_pair());
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SignaturesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<AccountId20, EthereumSignature> for SignaturesBenchmarkHelper {
+    fn account() -> AccountId20 {
+        account_of(&pair())
+    }
+
+    fn sign(message: &[u8]) -> EthereumSignature {
+        ethereum_sign(&pair(), message)
+    }
+
+    fn relay_account() -> AccountId32 {
+        relay_account_of(&relay_pair())
+    }
+
+    fn relay_sign(message: &[u8]) -> MultiSignature {
+        relay_sign(&relay_pair(), message)
+    }
+}
+
+impl pallet_account_mapping::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Signature = EthereumSignature;
+    type Signer = EthereumSigner;
+    type RelayAccountOrigin = EnsureRootWithSuccess<AccountId20, RelayOrigin>;
+    type Currency = Balances;
+    type LegacyAccount = TruncatedAccount;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = SignaturesBenchmarkHelper;
+}
+
+/// The key of an account of this chain.
+pub fn pair() -> ecdsa::Pair {
+    ecdsa::Pair::from_seed(&[1; 32])
+}
+
+/// The key of a relay chain account.
+pub fn relay_pair() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[2; 32])
+}
+
+pub fn account_of(pair: &ecdsa::Pair) -> AccountId20 {
+    EthereumSigner::from(pair.public()).into_account()
+}
+
+pub fn relay_account_of(pair: &sr25519::Pair) -> AccountId32 {
+    pair.public().into()
+}
+
+pub fn legacy_account_of(relay_account: &AccountId32) -> Option<AccountId20> {
+    let id: &[u8; 32] = relay_account.as_ref();
+    Some(AccountId20(id[12..].try_into().ok()?))
+}
+
+pub fn relay_location(pair: &sr25519::Pair) -> Location {
+    Location::new(
+        1,
+        [Junction::AccountId32 {
+            network: None,
+            id: pair.public().0,
+        }],
+    )
+}
+
+pub fn ethereum_sign(pair: &ecdsa::Pair, message: &[u8]) -> EthereumSignature {
+    EthereumSignature::new(pair.sign_prehashed(&keccak_256(message)))
+}
+
+pub fn relay_sign(pair: &sr25519::Pair, message: &[u8]) -> MultiSignature {
+    pair.sign(message).into()
+}
+
+// Test externalities initialization
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap()
+        .into()
+}
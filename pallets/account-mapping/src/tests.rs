@@ -0,0 +1,519 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use fp_account::AccountId20;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_core::{ecdsa, sr25519, Pair};
+use sp_runtime::DispatchError;
+
+/// Links the account of `pair` to the relay chain account of `relay_pair`, as the former.
+fn link(pair: &ecdsa::Pair, relay_pair: &sr25519::Pair) -> DispatchResult {
+    let account = account_of(pair);
+    let relay_account = relay_account_of(relay_pair);
+    let payload = PalletAccountMapping::link_payload(&relay_account, &account);
+    PalletAccountMapping::link_account(
+        RuntimeOrigin::signed(account),
+        relay_account,
+        relay_sign(relay_pair, &payload),
+    )
+}
+
+/// Links the relay chain account of `relay_pair`, as the `RelayAccountOrigin`, to the account of
+/// `pair`.
+fn link_from_relay(relay_pair: &sr25519::Pair, pair: &ecdsa::Pair) -> DispatchResult {
+    RelayOrigin::set(relay_location(relay_pair));
+    let account = account_of(pair);
+    let payload = PalletAccountMapping::link_payload(&relay_account_of(relay_pair), &account);
+    PalletAccountMapping::link_account_from_relay(
+        RuntimeOrigin::root(),
+        account,
+        ethereum_sign(pair, &payload),
+    )
+}
+
+fn other_pair() -> ecdsa::Pair {
+    ecdsa::Pair::from_seed(&[3; 32])
+}
+
+fn other_relay_pair() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[4; 32])
+}
+
+fn assert_linked(relay_pair: &sr25519::Pair, pair: &ecdsa::Pair) {
+    assert_eq!(
+        LinkedAccounts::<Test>::get(relay_account_of(relay_pair)),
+        Some(account_of(pair))
+    );
+    assert_eq!(
+        RelayAccounts::<Test>::get(account_of(pair)),
+        Some(relay_account_of(relay_pair))
+    );
+}
+
+mod link_account {
+    use super::*;
+
+    #[test]
+    fn sets_storage_keys() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_linked(&relay_pair(), &pair());
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            System::assert_last_event(
+                Event::AccountLinked {
+                    relay_account: relay_account_of(&relay_pair()),
+                    account: account_of(&pair()),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_signed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let relay_account = relay_account_of(&relay_pair());
+            let payload = PalletAccountMapping::link_payload(&relay_account, &account_of(&pair()));
+            assert_noop!(
+                PalletAccountMapping::link_account(
+                    RuntimeOrigin::root(),
+                    relay_account,
+                    relay_sign(&relay_pair(), &payload)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_signed_by_another_relay_account() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let relay_account = relay_account_of(&relay_pair());
+            let payload = PalletAccountMapping::link_payload(&relay_account, &account_of(&pair()));
+            assert_noop!(
+                PalletAccountMapping::link_account(
+                    RuntimeOrigin::signed(account_of(&pair())),
+                    relay_account,
+                    relay_sign(&other_relay_pair(), &payload)
+                ),
+                Error::<Test>::InvalidSignature
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_signed_for_another_account() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let relay_account = relay_account_of(&relay_pair());
+            let payload =
+                PalletAccountMapping::link_payload(&relay_account, &account_of(&other_pair()));
+            assert_noop!(
+                PalletAccountMapping::link_account(
+                    RuntimeOrigin::signed(account_of(&pair())),
+                    relay_account,
+                    relay_sign(&relay_pair(), &payload)
+                ),
+                Error::<Test>::InvalidSignature
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_signature_is_replayed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let account = account_of(&pair());
+            let relay_account = relay_account_of(&relay_pair());
+            let signature = relay_sign(
+                &relay_pair(),
+                &PalletAccountMapping::link_payload(&relay_account, &account),
+            );
+            assert_ok!(PalletAccountMapping::link_account(
+                RuntimeOrigin::signed(account),
+                relay_account.clone(),
+                signature.clone()
+            ));
+            assert_ok!(PalletAccountMapping::unlink_account(RuntimeOrigin::signed(
+                account
+            )));
+            assert_noop!(
+                PalletAccountMapping::link_account(
+                    RuntimeOrigin::signed(account),
+                    relay_account,
+                    signature
+                ),
+                Error::<Test>::InvalidSignature
+            );
+        })
+    }
+
+    #[test]
+    fn accepts_signature_of_wrapped_payload() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let account = account_of(&pair());
+            let relay_account = relay_account_of(&relay_pair());
+            let payload = PalletAccountMapping::link_payload(&relay_account, &account);
+            let wrapped = [&b"<Bytes>"[..], &payload[..], &b"</Bytes>"[..]].concat();
+            assert_ok!(PalletAccountMapping::link_account(
+                RuntimeOrigin::signed(account),
+                relay_account,
+                relay_sign(&relay_pair(), &wrapped)
+            ));
+            assert_linked(&relay_pair(), &pair());
+        })
+    }
+
+    #[test]
+    fn errors_if_signed_for_another_chain() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let account = account_of(&pair());
+            let relay_account = relay_account_of(&relay_pair());
+            let payload = PalletAccountMapping::link_payload(&relay_account, &account);
+            frame_system::BlockHash::<Test>::insert(0, sp_core::H256::repeat_byte(1));
+            assert_noop!(
+                PalletAccountMapping::link_account(
+                    RuntimeOrigin::signed(account),
+                    relay_account,
+                    relay_sign(&relay_pair(), &payload)
+                ),
+                Error::<Test>::InvalidSignature
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_relay_account_already_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_noop!(
+                link(&other_pair(), &relay_pair()),
+                Error::<Test>::RelayAccountAlreadyLinked
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_account_already_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_noop!(
+                link(&pair(), &other_relay_pair()),
+                Error::<Test>::AccountAlreadyLinked
+            );
+        })
+    }
+}
+
+mod link_account_from_relay {
+    use super::*;
+
+    #[test]
+    fn sets_storage_keys() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link_from_relay(&relay_pair(), &pair()));
+            assert_linked(&relay_pair(), &pair());
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link_from_relay(&relay_pair(), &pair()));
+            System::assert_last_event(
+                Event::AccountLinked {
+                    relay_account: relay_account_of(&relay_pair()),
+                    account: account_of(&pair()),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_relay_account_origin() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let account = account_of(&pair());
+            let payload =
+                PalletAccountMapping::link_payload(&relay_account_of(&relay_pair()), &account);
+            assert_noop!(
+                PalletAccountMapping::link_account_from_relay(
+                    RuntimeOrigin::signed(account),
+                    account,
+                    ethereum_sign(&pair(), &payload)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_origin_is_not_a_relay_account() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            RelayOrigin::set(xcm::latest::Location::parent());
+            assert_noop!(
+                PalletAccountMapping::link_account_from_relay(
+                    RuntimeOrigin::root(),
+                    account_of(&pair()),
+                    ethereum_sign(&pair(), b"")
+                ),
+                Error::<Test>::NotRelayAccount
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_signed_by_another_account() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let account = account_of(&pair());
+            let payload =
+                PalletAccountMapping::link_payload(&relay_account_of(&relay_pair()), &account);
+            assert_noop!(
+                PalletAccountMapping::link_account_from_relay(
+                    RuntimeOrigin::root(),
+                    account,
+                    ethereum_sign(&other_pair(), &payload)
+                ),
+                Error::<Test>::InvalidSignature
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_relay_account_already_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_noop!(
+                link_from_relay(&relay_pair(), &other_pair()),
+                Error::<Test>::RelayAccountAlreadyLinked
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_account_already_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_noop!(
+                link_from_relay(&other_relay_pair(), &pair()),
+                Error::<Test>::AccountAlreadyLinked
+            );
+        })
+    }
+}
+
+mod unlink_account {
+    use super::*;
+
+    #[test]
+    fn clears_storage_keys() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_ok!(PalletAccountMapping::unlink_account(RuntimeOrigin::signed(
+                account_of(&pair())
+            )));
+            assert!(!LinkedAccounts::<Test>::contains_key(relay_account_of(
+                &relay_pair()
+            )));
+            assert!(!RelayAccounts::<Test>::contains_key(account_of(&pair())));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_ok!(PalletAccountMapping::unlink_account(RuntimeOrigin::signed(
+                account_of(&pair())
+            )));
+            System::assert_last_event(
+                Event::AccountUnlinked {
+                    relay_account: relay_account_of(&relay_pair()),
+                    account: account_of(&pair()),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_not_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletAccountMapping::unlink_account(RuntimeOrigin::signed(AccountId20::from(
+                    [1u8; 20]
+                ))),
+                Error::<Test>::NotLinked
+            );
+        })
+    }
+}
+
+mod unlink_account_from_relay {
+    use super::*;
+
+    #[test]
+    fn clears_storage_keys() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link_from_relay(&relay_pair(), &pair()));
+            assert_ok!(PalletAccountMapping::unlink_account_from_relay(
+                RuntimeOrigin::root()
+            ));
+            assert!(!LinkedAccounts::<Test>::contains_key(relay_account_of(
+                &relay_pair()
+            )));
+            assert!(!RelayAccounts::<Test>::contains_key(account_of(&pair())));
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_relay_account_origin() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(link_from_relay(&relay_pair(), &pair()));
+            assert_noop!(
+                PalletAccountMapping::unlink_account_from_relay(RuntimeOrigin::signed(account_of(
+                    &pair()
+                ))),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_not_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            RelayOrigin::set(relay_location(&other_relay_pair()));
+            assert_noop!(
+                PalletAccountMapping::unlink_account_from_relay(RuntimeOrigin::root()),
+                Error::<Test>::NotLinked
+            );
+        })
+    }
+}
+
+mod claim_legacy_account {
+    use super::*;
+    use frame_support::traits::fungible::{Inspect, Mutate};
+
+    const AMOUNT: u64 = 1000;
+
+    fn legacy_account() -> AccountId20 {
+        legacy_account_of(&relay_account_of(&relay_pair())).unwrap()
+    }
+
+    #[test]
+    fn moves_the_legacy_balance() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Balances::set_balance(&legacy_account(), AMOUNT);
+            assert_ok!(link(&pair(), &relay_pair()));
+
+            assert_ok!(PalletAccountMapping::claim_legacy_account(
+                RuntimeOrigin::signed(account_of(&pair()))
+            ));
+
+            assert_eq!(Balances::balance(&legacy_account()), 0);
+            assert_eq!(Balances::balance(&account_of(&pair())), AMOUNT);
+        })
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Balances::set_balance(&legacy_account(), AMOUNT);
+            assert_ok!(link(&pair(), &relay_pair()));
+
+            assert_ok!(PalletAccountMapping::claim_legacy_account(
+                RuntimeOrigin::signed(account_of(&pair()))
+            ));
+            System::assert_last_event(
+                Event::LegacyAccountClaimed {
+                    relay_account: relay_account_of(&relay_pair()),
+                    legacy_account: legacy_account(),
+                    account: account_of(&pair()),
+                    amount: AMOUNT,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_not_linked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Balances::set_balance(&legacy_account(), AMOUNT);
+            assert_noop!(
+                PalletAccountMapping::claim_legacy_account(RuntimeOrigin::signed(account_of(
+                    &pair()
+                ))),
+                Error::<Test>::NotLinked
+            );
+        })
+    }
+}
+
+mod linked_account {
+    use super::*;
+
+    #[test]
+    fn follows_the_links() {
+        new_test_ext().execute_with(|| {
+            let relay_account = relay_account_of(&relay_pair());
+            assert_eq!(PalletAccountMapping::linked_account(&relay_account), None);
+
+            assert_ok!(link(&pair(), &relay_pair()));
+            assert_eq!(
+                PalletAccountMapping::linked_account(&relay_account),
+                Some(account_of(&pair()))
+            );
+
+            assert_ok!(PalletAccountMapping::unlink_account(RuntimeOrigin::signed(
+                account_of(&pair())
+            )));
+            assert_eq!(PalletAccountMapping::linked_account(&relay_account), None);
+        })
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+
+    #[benchmark]
+    fn store_aggregation_root() -> Result<(), BenchmarkError> {
+        let origin =
+            T::StoreOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let root = H256::repeat_byte(42);
+
+        #[extrinsic_call]
+        store_aggregation_root(origin as T::RuntimeOrigin, 1, 1, root);
+
+        assert_eq!(AggregationRoots::<T>::get(1, 1), Some(root));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,129 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Stores the roots of the zkVerify proof aggregations, as delivered by the relay chain, and
+//! checks the inclusion of a statement in an aggregation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+pub use pallet::*;
+use sp_core::H256;
+use sp_runtime::traits::Keccak256;
+
+/// Identifier of an aggregation domain on zkVerify.
+pub type DomainId = u32;
+
+/// Identifier of an aggregation inside its domain.
+pub type AggregationId = u64;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The origin allowed to store the aggregation roots.
+        type StoreOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The root of an aggregation has been stored.
+        AggregationRootStored {
+            /// The domain of the aggregation.
+            domain_id: DomainId,
+            /// The aggregation identifier in its domain.
+            aggregation_id: AggregationId,
+            /// The Merkle root of the aggregated statements.
+            root: H256,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Tried to store the root of an aggregation which already has one.
+        AggregationRootAlreadyStored,
+    }
+
+    #[pallet::storage]
+    pub type AggregationRoots<T> =
+        StorageDoubleMap<_, Twox64Concat, DomainId, Twox64Concat, AggregationId, H256, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::store_aggregation_root())]
+        pub fn store_aggregation_root(
+            origin: OriginFor<T>,
+            domain_id: DomainId,
+            aggregation_id: AggregationId,
+            root: H256,
+        ) -> DispatchResult {
+            T::StoreOrigin::ensure_origin(origin)?;
+            if !AggregationRoots::<T>::contains_key(domain_id, aggregation_id) {
+                AggregationRoots::<T>::insert(domain_id, aggregation_id, root);
+                Self::deposit_event(Event::<T>::AggregationRootStored {
+                    domain_id,
+                    aggregation_id,
+                    root,
+                });
+                Ok(())
+            } else {
+                Err(Error::<T>::AggregationRootAlreadyStored)?
+            }
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns `true` if `leaf` is the `index`-th of the `leaf_count` statements of the given
+    /// aggregation. The tree is built as zkVerify does: leaves and nodes are hashed with Keccak256.
+    pub fn verify_proof_aggregation(
+        domain_id: DomainId,
+        aggregation_id: AggregationId,
+        leaf: H256,
+        merkle_path: impl IntoIterator<Item = H256>,
+        leaf_count: u32,
+        index: u32,
+    ) -> bool {
+        AggregationRoots::<T>::get(domain_id, aggregation_id).is_some_and(|root| {
+            binary_merkle_tree::verify_proof::<Keccak256, _, _>(
+                &root,
+                merkle_path,
+                leaf_count,
+                index,
+                &leaf,
+            )
+        })
+    }
+}
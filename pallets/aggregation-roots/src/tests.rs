@@ -0,0 +1,162 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const DOMAIN_ID: DomainId = 1;
+const AGGREGATION_ID: AggregationId = 42;
+
+fn statements() -> Vec<H256> {
+    (0..5u8).map(H256::repeat_byte).collect()
+}
+
+mod store_aggregation_root {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let root = H256::repeat_byte(42);
+            assert_ok!(PalletAggregationRoots::store_aggregation_root(
+                RuntimeOrigin::root(),
+                DOMAIN_ID,
+                AGGREGATION_ID,
+                root
+            ));
+            assert_eq!(
+                AggregationRoots::<Test>::get(DOMAIN_ID, AGGREGATION_ID),
+                Some(root)
+            );
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let root = H256::repeat_byte(42);
+            assert_ok!(PalletAggregationRoots::store_aggregation_root(
+                RuntimeOrigin::root(),
+                DOMAIN_ID,
+                AGGREGATION_ID,
+                root
+            ));
+            System::assert_last_event(
+                Event::AggregationRootStored {
+                    domain_id: DOMAIN_ID,
+                    aggregation_id: AGGREGATION_ID,
+                    root,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_store_origin() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            assert_noop!(
+                PalletAggregationRoots::store_aggregation_root(
+                    RuntimeOrigin::signed(caller),
+                    DOMAIN_ID,
+                    AGGREGATION_ID,
+                    H256::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_root_already_stored() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            AggregationRoots::<Test>::insert(DOMAIN_ID, AGGREGATION_ID, H256::repeat_byte(42));
+            assert_noop!(
+                PalletAggregationRoots::store_aggregation_root(
+                    RuntimeOrigin::root(),
+                    DOMAIN_ID,
+                    AGGREGATION_ID,
+                    H256::repeat_byte(43)
+                ),
+                Error::<Test>::AggregationRootAlreadyStored
+            );
+        })
+    }
+}
+
+mod verify_proof_aggregation {
+    use super::*;
+
+    fn store_statements_root() {
+        AggregationRoots::<Test>::insert(
+            DOMAIN_ID,
+            AGGREGATION_ID,
+            binary_merkle_tree::merkle_root::<Keccak256, _>(statements()),
+        );
+    }
+
+    fn verify(leaf: H256, index: u32, aggregation_id: AggregationId) -> bool {
+        let proof = binary_merkle_tree::merkle_proof::<Keccak256, _, _>(statements(), index);
+        PalletAggregationRoots::verify_proof_aggregation(
+            DOMAIN_ID,
+            aggregation_id,
+            leaf,
+            proof.proof,
+            proof.number_of_leaves,
+            index,
+        )
+    }
+
+    #[test]
+    fn accepts_included_statements() {
+        new_test_ext().execute_with(|| {
+            store_statements_root();
+            for (index, statement) in statements().into_iter().enumerate() {
+                assert!(verify(statement, index as u32, AGGREGATION_ID));
+            }
+        })
+    }
+
+    #[test]
+    fn rejects_statement_at_wrong_index() {
+        new_test_ext().execute_with(|| {
+            store_statements_root();
+            assert!(!verify(statements()[1], 2, AGGREGATION_ID));
+        })
+    }
+
+    #[test]
+    fn rejects_unknown_statement() {
+        new_test_ext().execute_with(|| {
+            store_statements_root();
+            assert!(!verify(H256::repeat_byte(42), 0, AGGREGATION_ID));
+        })
+    }
+
+    #[test]
+    fn rejects_unknown_aggregation() {
+        new_test_ext().execute_with(|| {
+            store_statements_root();
+            assert!(!verify(statements()[0], 0, AGGREGATION_ID + 1));
+        })
+    }
+}
@@ -0,0 +1,31 @@
+
+//! Hand-written weights for `pallet_aggregation_roots`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_aggregation_roots`.
+pub trait WeightInfo {
+    fn store_aggregation_root() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `AggregationRoots::AggregationRoots` (r:1 w:1)
+    /// Proof: `AggregationRoots::AggregationRoots` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn store_aggregation_root() -> Weight {
+        Weight::from_parts(0, 3525)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use xcm::latest::prelude::*;
+
+fn foreign_location() -> Location {
+    Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+    use frame_system::RawOrigin;
+
+    #[benchmark]
+    fn register_foreign_asset() {
+        let asset_id = T::BenchmarkHelper::create_asset();
+
+        #[extrinsic_call]
+        register_foreign_asset(
+            RawOrigin::Root,
+            asset_id.clone(),
+            Box::new(foreign_location().into()),
+        );
+
+        assert_eq!(
+            LocationToAssetId::<T>::get(foreign_location()),
+            Some(asset_id)
+        );
+    }
+
+    #[benchmark]
+    fn unregister_foreign_asset() {
+        let asset_id = T::BenchmarkHelper::create_asset();
+        AssetIdToLocation::<T>::insert(&asset_id, foreign_location());
+        LocationToAssetId::<T>::insert(foreign_location(), &asset_id);
+
+        #[extrinsic_call]
+        unregister_foreign_asset(RawOrigin::Root, asset_id.clone());
+
+        assert!(AssetIdToLocation::<T>::get(asset_id).is_none());
+        assert!(LocationToAssetId::<T>::get(foreign_location()).is_none());
+    }
+
+    #[benchmark]
+    fn set_fee_rate() -> Result<(), BenchmarkError> {
+        let origin =
+            T::FeeRateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let asset_id = T::BenchmarkHelper::create_asset();
+        AssetIdToLocation::<T>::insert(&asset_id, foreign_location());
+        LocationToAssetId::<T>::insert(foreign_location(), &asset_id);
+
+        #[extrinsic_call]
+        set_fee_rate(
+            origin as T::RuntimeOrigin,
+            asset_id.clone(),
+            FixedU128::from_u32(2),
+        );
+
+        assert_eq!(FeeRates::<T>::get(asset_id), Some(FixedU128::from_u32(2)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_fee_rate() -> Result<(), BenchmarkError> {
+        let origin =
+            T::FeeRateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let asset_id = T::BenchmarkHelper::create_asset();
+        FeeRates::<T>::insert(&asset_id, FixedU128::from_u32(2));
+
+        #[extrinsic_call]
+        remove_fee_rate(origin as T::RuntimeOrigin, asset_id.clone());
+
+        assert!(FeeRates::<T>::get(asset_id).is_none());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,250 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keeps the registry of the foreign assets known to this chain, mapping each of them from its
+//! XCM location to the local asset identifier used to hold it. Registered assets may also be
+//! given a fee rate, which lets them pay for XCM execution in place of the native token.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+use alloc::{boxed::Box, vec::Vec};
+use frame_support::traits::fungibles;
+pub use pallet::*;
+use sp_runtime::{traits::MaybeEquivalence, FixedU128};
+use xcm::{latest::Location, VersionedLocation};
+
+/// The identifier of an asset of the underlying assets pallet.
+pub type AssetIdOf<T> =
+    <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+/// Provides the assets needed to benchmark the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+    /// Creates a new asset and returns its identifier.
+    fn create_asset() -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::Zero;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The assets holding the balances of the registered foreign assets.
+        type Assets: fungibles::Inspect<Self::AccountId>;
+        /// The origin allowed to set the fee rates, e.g. governance or a price oracle.
+        type FeeRateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        type WeightInfo: WeightInfo;
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A foreign asset has been registered.
+        ForeignAssetRegistered {
+            /// The identifier of the local asset.
+            asset_id: AssetIdOf<T>,
+            /// The location of the foreign asset.
+            location: Location,
+        },
+        /// A foreign asset has been unregistered.
+        ForeignAssetUnregistered {
+            /// The identifier of the local asset.
+            asset_id: AssetIdOf<T>,
+            /// The location of the foreign asset.
+            location: Location,
+        },
+        /// The fee rate of a foreign asset has been set.
+        FeeRateSet {
+            /// The identifier of the local asset.
+            asset_id: AssetIdOf<T>,
+            /// The units of the asset charged for each unit of native token.
+            rate: FixedU128,
+        },
+        /// The fee rate of a foreign asset has been removed.
+        FeeRateRemoved {
+            /// The identifier of the local asset.
+            asset_id: AssetIdOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The location cannot be converted to the latest XCM version.
+        BadLocation,
+        /// The local asset does not exist.
+        AssetNotFound,
+        /// Tried to register an asset which is already registered.
+        AssetAlreadyRegistered,
+        /// Tried to register a location which is already registered.
+        LocationAlreadyRegistered,
+        /// Tried to unregister, or to set the fee rate of, an asset which is not registered.
+        AssetNotRegistered,
+        /// Tried to set a zero fee rate.
+        ZeroFeeRate,
+        /// Tried to remove the fee rate of an asset which has none.
+        FeeRateNotSet,
+    }
+
+    #[pallet::storage]
+    pub type AssetIdToLocation<T> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Location, OptionQuery>;
+
+    #[pallet::storage]
+    pub type LocationToAssetId<T> =
+        StorageMap<_, Blake2_128Concat, Location, AssetIdOf<T>, OptionQuery>;
+
+    /// The units of each foreign asset charged in place of one unit of native token when paying
+    /// for XCM execution.
+    #[pallet::storage]
+    pub type FeeRates<T> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, FixedU128, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Registers the existing local asset `asset_id` as the representation of the foreign
+        /// asset at `location`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_foreign_asset())]
+        pub fn register_foreign_asset(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let location: Location = (*location)
+                .try_into()
+                .map_err(|_| Error::<T>::BadLocation)?;
+            ensure!(
+                <T::Assets as fungibles::Inspect<T::AccountId>>::asset_exists(asset_id.clone()),
+                Error::<T>::AssetNotFound
+            );
+            ensure!(
+                !AssetIdToLocation::<T>::contains_key(&asset_id),
+                Error::<T>::AssetAlreadyRegistered
+            );
+            ensure!(
+                !LocationToAssetId::<T>::contains_key(&location),
+                Error::<T>::LocationAlreadyRegistered
+            );
+
+            AssetIdToLocation::<T>::insert(&asset_id, &location);
+            LocationToAssetId::<T>::insert(&location, &asset_id);
+            Self::deposit_event(Event::<T>::ForeignAssetRegistered { asset_id, location });
+            Ok(())
+        }
+
+        /// Unregisters the foreign asset represented by the local asset `asset_id`, together
+        /// with its fee rate. The local asset and its balances are left untouched.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unregister_foreign_asset())]
+        pub fn unregister_foreign_asset(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            if let Some(location) = AssetIdToLocation::<T>::take(&asset_id) {
+                LocationToAssetId::<T>::remove(&location);
+                FeeRates::<T>::remove(&asset_id);
+                Self::deposit_event(Event::<T>::ForeignAssetUnregistered { asset_id, location });
+                Ok(())
+            } else {
+                Err(Error::<T>::AssetNotRegistered)?
+            }
+        }
+
+        /// Lets the registered foreign asset `asset_id` pay for XCM execution, charging `rate`
+        /// units of it for each unit of native token the execution would cost.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_fee_rate())]
+        pub fn set_fee_rate(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            rate: FixedU128,
+        ) -> DispatchResult {
+            T::FeeRateOrigin::ensure_origin(origin)?;
+            ensure!(!rate.is_zero(), Error::<T>::ZeroFeeRate);
+            ensure!(
+                AssetIdToLocation::<T>::contains_key(&asset_id),
+                Error::<T>::AssetNotRegistered
+            );
+
+            FeeRates::<T>::insert(&asset_id, rate);
+            Self::deposit_event(Event::<T>::FeeRateSet { asset_id, rate });
+            Ok(())
+        }
+
+        /// Stops accepting the foreign asset `asset_id` as payment for XCM execution.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::remove_fee_rate())]
+        pub fn remove_fee_rate(origin: OriginFor<T>, asset_id: AssetIdOf<T>) -> DispatchResult {
+            T::FeeRateOrigin::ensure_origin(origin)?;
+            if FeeRates::<T>::take(&asset_id).is_some() {
+                Self::deposit_event(Event::<T>::FeeRateRemoved { asset_id });
+                Ok(())
+            } else {
+                Err(Error::<T>::FeeRateNotSet)?
+            }
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the local identifier and the fee rate of the foreign asset at `location`, if it
+    /// can pay for XCM execution.
+    pub fn fee_rate(location: &Location) -> Option<(AssetIdOf<T>, FixedU128)> {
+        let asset_id = LocationToAssetId::<T>::get(location)?;
+        let rate = FeeRates::<T>::get(&asset_id)?;
+        Some((asset_id, rate))
+    }
+
+    /// Returns the locations of all the foreign assets which can pay for XCM execution.
+    pub fn fee_payment_locations() -> Vec<Location> {
+        FeeRates::<T>::iter_keys()
+            .filter_map(AssetIdToLocation::<T>::get)
+            .collect()
+    }
+}
+
+/// Converts the location of a registered foreign asset to its local identifier and back.
+impl<T: Config> MaybeEquivalence<Location, AssetIdOf<T>> for Pallet<T> {
+    fn convert(location: &Location) -> Option<AssetIdOf<T>> {
+        LocationToAssetId::<T>::get(location)
+    }
+
+    fn convert_back(asset_id: &AssetIdOf<T>) -> Option<Location> {
+        AssetIdToLocation::<T>::get(asset_id)
+    }
+}
@@ -0,0 +1,413 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_runtime::{DispatchError, FixedU128};
+use xcm::latest::prelude::*;
+
+fn foreign_location() -> Location {
+    Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+}
+
+fn register(asset_id: u32, location: Location) -> DispatchResult {
+    PalletAssetRegistry::register_foreign_asset(
+        RuntimeOrigin::root(),
+        asset_id,
+        Box::new(location.into()),
+    )
+}
+
+mod register_foreign_asset {
+    use super::*;
+
+    #[test]
+    fn sets_storage_keys() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_eq!(
+                AssetIdToLocation::<Test>::get(ASSET_ID),
+                Some(foreign_location())
+            );
+            assert_eq!(
+                LocationToAssetId::<Test>::get(foreign_location()),
+                Some(ASSET_ID)
+            );
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            System::assert_last_event(
+                Event::ForeignAssetRegistered {
+                    asset_id: ASSET_ID,
+                    location: foreign_location(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_noop!(
+                PalletAssetRegistry::register_foreign_asset(
+                    RuntimeOrigin::signed(1),
+                    ASSET_ID,
+                    Box::new(foreign_location().into())
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_asset_does_not_exist() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                register(ASSET_ID, foreign_location()),
+                Error::<Test>::AssetNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_asset_already_registered() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_noop!(
+                register(ASSET_ID, Location::new(1, [Parachain(2000)])),
+                Error::<Test>::AssetAlreadyRegistered
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_location_already_registered() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            create_asset(ASSET_ID + 1);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_noop!(
+                register(ASSET_ID + 1, foreign_location()),
+                Error::<Test>::LocationAlreadyRegistered
+            );
+        })
+    }
+}
+
+mod unregister_foreign_asset {
+    use super::*;
+
+    #[test]
+    fn clears_storage_keys() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_ok!(PalletAssetRegistry::unregister_foreign_asset(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+            assert!(!AssetIdToLocation::<Test>::contains_key(ASSET_ID));
+            assert!(!LocationToAssetId::<Test>::contains_key(foreign_location()));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_ok!(PalletAssetRegistry::unregister_foreign_asset(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+            System::assert_last_event(
+                Event::ForeignAssetUnregistered {
+                    asset_id: ASSET_ID,
+                    location: foreign_location(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_noop!(
+                PalletAssetRegistry::unregister_foreign_asset(RuntimeOrigin::signed(1), ASSET_ID),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_asset_not_registered() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletAssetRegistry::unregister_foreign_asset(RuntimeOrigin::root(), ASSET_ID),
+                Error::<Test>::AssetNotRegistered
+            );
+        })
+    }
+}
+
+fn rate() -> FixedU128 {
+    FixedU128::from_rational(3, 2)
+}
+
+mod set_fee_rate {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_ok!(PalletAssetRegistry::set_fee_rate(
+                RuntimeOrigin::root(),
+                ASSET_ID,
+                rate()
+            ));
+            assert_eq!(FeeRates::<Test>::get(ASSET_ID), Some(rate()));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_ok!(PalletAssetRegistry::set_fee_rate(
+                RuntimeOrigin::root(),
+                ASSET_ID,
+                rate()
+            ));
+            System::assert_last_event(
+                Event::FeeRateSet {
+                    asset_id: ASSET_ID,
+                    rate: rate(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_fee_rate_origin() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_noop!(
+                PalletAssetRegistry::set_fee_rate(RuntimeOrigin::signed(1), ASSET_ID, rate()),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_asset_not_registered() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_noop!(
+                PalletAssetRegistry::set_fee_rate(RuntimeOrigin::root(), ASSET_ID, rate()),
+                Error::<Test>::AssetNotRegistered
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_rate_is_zero() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_noop!(
+                PalletAssetRegistry::set_fee_rate(
+                    RuntimeOrigin::root(),
+                    ASSET_ID,
+                    FixedU128::from_u32(0)
+                ),
+                Error::<Test>::ZeroFeeRate
+            );
+        })
+    }
+}
+
+mod remove_fee_rate {
+    use super::*;
+
+    fn register_with_rate() {
+        create_asset(ASSET_ID);
+        assert_ok!(register(ASSET_ID, foreign_location()));
+        assert_ok!(PalletAssetRegistry::set_fee_rate(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            rate()
+        ));
+    }
+
+    #[test]
+    fn clears_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            register_with_rate();
+            assert_ok!(PalletAssetRegistry::remove_fee_rate(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+            assert!(!FeeRates::<Test>::contains_key(ASSET_ID));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            register_with_rate();
+            assert_ok!(PalletAssetRegistry::remove_fee_rate(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+            System::assert_last_event(Event::FeeRateRemoved { asset_id: ASSET_ID }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_fee_rate_origin() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            register_with_rate();
+            assert_noop!(
+                PalletAssetRegistry::remove_fee_rate(RuntimeOrigin::signed(1), ASSET_ID),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_rate_not_set() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletAssetRegistry::remove_fee_rate(RuntimeOrigin::root(), ASSET_ID),
+                Error::<Test>::FeeRateNotSet
+            );
+        })
+    }
+
+    #[test]
+    fn is_implied_by_unregistering_the_asset() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            register_with_rate();
+            assert_ok!(PalletAssetRegistry::unregister_foreign_asset(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+            assert!(!FeeRates::<Test>::contains_key(ASSET_ID));
+        });
+    }
+}
+
+mod fee_rate {
+    use super::*;
+
+    #[test]
+    fn is_returned_for_priced_assets_only() {
+        new_test_ext().execute_with(|| {
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+            assert_eq!(PalletAssetRegistry::fee_rate(&foreign_location()), None);
+            assert!(PalletAssetRegistry::fee_payment_locations().is_empty());
+
+            assert_ok!(PalletAssetRegistry::set_fee_rate(
+                RuntimeOrigin::root(),
+                ASSET_ID,
+                rate()
+            ));
+            assert_eq!(
+                PalletAssetRegistry::fee_rate(&foreign_location()),
+                Some((ASSET_ID, rate()))
+            );
+            assert_eq!(
+                PalletAssetRegistry::fee_payment_locations(),
+                vec![foreign_location()]
+            );
+        })
+    }
+}
+
+mod maybe_equivalence {
+    use super::*;
+
+    #[test]
+    fn converts_registered_assets() {
+        new_test_ext().execute_with(|| {
+            create_asset(ASSET_ID);
+            assert_ok!(register(ASSET_ID, foreign_location()));
+
+            assert_eq!(
+                <PalletAssetRegistry as MaybeEquivalence<_, _>>::convert(&foreign_location()),
+                Some(ASSET_ID)
+            );
+            assert_eq!(
+                <PalletAssetRegistry as MaybeEquivalence<_, _>>::convert_back(&ASSET_ID),
+                Some(foreign_location())
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_convert_unregistered_assets() {
+        new_test_ext().execute_with(|| {
+            create_asset(ASSET_ID);
+
+            assert_eq!(
+                <PalletAssetRegistry as MaybeEquivalence<_, _>>::convert(&foreign_location()),
+                None
+            );
+            assert_eq!(
+                <PalletAssetRegistry as MaybeEquivalence<_, _>>::convert_back(&ASSET_ID),
+                None
+            );
+        })
+    }
+}
@@ -0,0 +1,65 @@
+
+//! Hand-written weights for `pallet_asset_registry`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_asset_registry`.
+pub trait WeightInfo {
+    fn register_foreign_asset() -> Weight;
+    fn unregister_foreign_asset() -> Weight;
+    fn set_fee_rate() -> Weight;
+    fn remove_fee_rate() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `Assets::Asset` (r:1 w:0)
+    /// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::AssetIdToLocation` (r:1 w:1)
+    /// Proof: `AssetRegistry::AssetIdToLocation` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:1)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(648), added: 3123, mode: `MaxEncodedLen`)
+    fn register_foreign_asset() -> Weight {
+        Weight::from_parts(0, 9905)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `AssetRegistry::AssetIdToLocation` (r:1 w:1)
+    /// Proof: `AssetRegistry::AssetIdToLocation` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:0 w:1)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(648), added: 3123, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::FeeRates` (r:0 w:1)
+    /// Proof: `AssetRegistry::FeeRates` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn unregister_foreign_asset() -> Weight {
+        Weight::from_parts(0, 4097)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `AssetRegistry::AssetIdToLocation` (r:1 w:0)
+    /// Proof: `AssetRegistry::AssetIdToLocation` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::FeeRates` (r:0 w:1)
+    /// Proof: `AssetRegistry::FeeRates` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn set_fee_rate() -> Weight {
+        Weight::from_parts(0, 4097)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `AssetRegistry::FeeRates` (r:1 w:1)
+    /// Proof: `AssetRegistry::FeeRates` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn remove_fee_rate() -> Weight {
+        Weight::from_parts(0, 3501)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Get, Hooks};
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+    use frame_system::RawOrigin;
+
+    #[benchmark]
+    fn disable_precompile() {
+        let address = H160::from_low_u64_be(2060);
+
+        #[extrinsic_call]
+        disable_precompile(RawOrigin::Root, address);
+
+        assert!(Pallet::<T>::is_disabled(&address));
+    }
+
+    #[benchmark]
+    fn enable_precompile() {
+        let address = H160::from_low_u64_be(2060);
+        Pallet::<T>::disable_precompile(RawOrigin::Root.into(), address).unwrap();
+
+        #[extrinsic_call]
+        enable_precompile(RawOrigin::Root, address);
+
+        assert!(!Pallet::<T>::is_disabled(&address));
+    }
+
+    #[benchmark]
+    fn on_initialize() {
+        // The disabled precompiles are full
+        for index in 0..T::MaxDisabledPrecompiles::get() {
+            let address = H160::from_low_u64_be(index.into());
+            Pallet::<T>::disable_precompile(RawOrigin::Root.into(), address).unwrap();
+        }
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(1u32.into());
+        }
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,127 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+pub mod precompile_set;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+pub use pallet::*;
+use sp_core::H160;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*, BoundedBTreeSet};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The maximum number of precompiles which can be disabled at the same time.
+        #[pallet::constant]
+        type MaxDisabledPrecompiles: Get<u32>;
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A precompile has been disabled.
+        PrecompileDisabled {
+            /// The address of the disabled precompile.
+            address: H160,
+        },
+        /// A previously disabled precompile has been enabled again.
+        PrecompileEnabled {
+            /// The address of the enabled precompile.
+            address: H160,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Tried to disable a precompile which is already disabled.
+        PrecompileAlreadyDisabled,
+        /// Tried to enable a precompile which is not disabled.
+        PrecompileNotDisabled,
+        /// Tried to disable more than `MaxDisabledPrecompiles` precompiles.
+        TooManyDisabledPrecompiles,
+    }
+
+    /// The precompiles disabled by governance. They are kept in a single whitelisted value which
+    /// is read in `on_initialize`, so that the precompile calls check it without paying for it.
+    #[pallet::storage]
+    #[pallet::whitelist_storage]
+    pub type DisabledPrecompiles<T: Config> =
+        StorageValue<_, BoundedBTreeSet<H160, T::MaxDisabledPrecompiles>, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            // Every block pays for bringing the disabled precompiles into its proof
+            let _ = DisabledPrecompiles::<T>::get();
+            T::WeightInfo::on_initialize()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::disable_precompile())]
+        pub fn disable_precompile(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            ensure_root(origin)?;
+            DisabledPrecompiles::<T>::try_mutate(|disabled| match disabled.try_insert(address) {
+                Ok(true) => Ok(()),
+                Ok(false) => Err(Error::<T>::PrecompileAlreadyDisabled),
+                Err(_) => Err(Error::<T>::TooManyDisabledPrecompiles),
+            })?;
+            Self::deposit_event(Event::<T>::PrecompileDisabled { address });
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::enable_precompile())]
+        pub fn enable_precompile(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            ensure_root(origin)?;
+            DisabledPrecompiles::<T>::try_mutate(|disabled| {
+                disabled
+                    .remove(&address)
+                    .then_some(())
+                    .ok_or(Error::<T>::PrecompileNotDisabled)
+            })?;
+            Self::deposit_event(Event::<T>::PrecompileEnabled { address });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns `true` if the precompile at `address` has been disabled by governance.
+    pub fn is_disabled(address: &H160) -> bool {
+        DisabledPrecompiles::<T>::get().contains(address)
+    }
+}
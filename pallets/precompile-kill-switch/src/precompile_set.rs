@@ -0,0 +1,113 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use core::marker::PhantomData;
+use fp_evm::{
+    ExitError, ExitRevert, IsPrecompileResult, PrecompileFailure, PrecompileHandle,
+    PrecompileResult, PrecompileSet,
+};
+use precompile_utils::precompile_set::IsActivePrecompile;
+use sp_core::H160;
+
+use crate::{Config, Pallet};
+
+/// Output of the calls to a disabled precompile.
+pub const DISABLED_PRECOMPILE_OUTPUT: &[u8] = b"precompile disabled";
+
+/// A `PrecompileSet` that forwards to `P`, except for the precompiles disabled by governance,
+/// whose calls revert. Checking whether a precompile is disabled costs no gas: the disabled
+/// precompiles are read once per block by the pallet, so the precompiles cost what `P` charges.
+pub struct KillSwitchPrecompiles<T, P> {
+    inner: P,
+    _marker: PhantomData<T>,
+}
+
+impl<T, P> KillSwitchPrecompiles<T, P> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+impl<T, P> PrecompileSet for KillSwitchPrecompiles<T, P>
+where
+    T: Config,
+    P: PrecompileSet,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        let address = handle.code_address();
+        // The precompiles pay what the inner set spent to recognize them (e.g. the storage read
+        // of a dynamic precompile set).
+        let extra_cost = match self.inner.is_precompile(address, handle.remaining_gas()) {
+            IsPrecompileResult::Answer {
+                is_precompile: true,
+                extra_cost,
+            } => extra_cost,
+            IsPrecompileResult::Answer {
+                is_precompile: false,
+                ..
+            } => return self.inner.execute(handle),
+            IsPrecompileResult::OutOfGas => {
+                return Some(Err(PrecompileFailure::Error {
+                    exit_status: ExitError::OutOfGas,
+                }))
+            }
+        };
+
+        if let Err(exit_status) = handle.record_cost(extra_cost) {
+            return Some(Err(PrecompileFailure::Error { exit_status }));
+        }
+
+        if Pallet::<T>::is_disabled(&address) {
+            return Some(Err(PrecompileFailure::Revert {
+                exit_status: ExitRevert::Reverted,
+                output: DISABLED_PRECOMPILE_OUTPUT.into(),
+            }));
+        }
+
+        self.inner.execute(handle)
+    }
+
+    fn is_precompile(&self, address: H160, remaining_gas: u64) -> IsPrecompileResult {
+        // A disabled precompile is still a precompile: its calls must revert rather than
+        // being handled as calls to an account without code.
+        self.inner.is_precompile(address, remaining_gas)
+    }
+}
+
+impl<T, P> IsActivePrecompile for KillSwitchPrecompiles<T, P>
+where
+    T: Config,
+    P: IsActivePrecompile,
+{
+    fn is_active_precompile(&self, address: H160, remaining_gas: u64) -> IsPrecompileResult {
+        match self.inner.is_active_precompile(address, remaining_gas) {
+            IsPrecompileResult::Answer {
+                is_precompile: true,
+                extra_cost,
+            } => IsPrecompileResult::Answer {
+                is_precompile: !Pallet::<T>::is_disabled(&address),
+                extra_cost,
+            },
+            answer => answer,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn disable(address: H160) {
+    DisabledPrecompiles::<Test>::mutate(|disabled| disabled.try_insert(address).unwrap());
+}
+
+mod disable_precompile {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::from_low_u64_be(2060);
+            assert!(!PrecompileKillSwitch::is_disabled(&address));
+            assert_ok!(PrecompileKillSwitch::disable_precompile(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(PrecompileKillSwitch::is_disabled(&address));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::from_low_u64_be(2060);
+            assert_ok!(PrecompileKillSwitch::disable_precompile(
+                RuntimeOrigin::root(),
+                address
+            ));
+            System::assert_last_event(Event::PrecompileDisabled { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let address = H160::from_low_u64_be(2060);
+            assert_noop!(
+                PrecompileKillSwitch::disable_precompile(RuntimeOrigin::signed(caller), address),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_precompile_already_disabled() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::from_low_u64_be(2060);
+            disable(address);
+            assert_noop!(
+                PrecompileKillSwitch::disable_precompile(RuntimeOrigin::root(), address),
+                Error::<Test>::PrecompileAlreadyDisabled
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_too_many_precompiles_disabled() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            disable(H160::from_low_u64_be(2060));
+            disable(H160::from_low_u64_be(2061));
+            assert_noop!(
+                PrecompileKillSwitch::disable_precompile(
+                    RuntimeOrigin::root(),
+                    H160::from_low_u64_be(2062)
+                ),
+                Error::<Test>::TooManyDisabledPrecompiles
+            );
+        })
+    }
+}
+
+mod enable_precompile {
+    use super::*;
+
+    #[test]
+    fn clears_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::from_low_u64_be(2060);
+            disable(address);
+            assert_ok!(PrecompileKillSwitch::enable_precompile(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!PrecompileKillSwitch::is_disabled(&address));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::from_low_u64_be(2060);
+            disable(address);
+            assert_ok!(PrecompileKillSwitch::enable_precompile(
+                RuntimeOrigin::root(),
+                address
+            ));
+            System::assert_last_event(Event::PrecompileEnabled { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let address = H160::from_low_u64_be(2060);
+            disable(address);
+            assert_noop!(
+                PrecompileKillSwitch::enable_precompile(RuntimeOrigin::signed(caller), address),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_precompile_not_disabled() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::from_low_u64_be(2060);
+            assert_noop!(
+                PrecompileKillSwitch::enable_precompile(RuntimeOrigin::root(), address),
+                Error::<Test>::PrecompileNotDisabled
+            );
+        })
+    }
+}
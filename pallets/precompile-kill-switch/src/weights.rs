@@ -0,0 +1,46 @@
+
+//! Hand-written weights for `pallet_precompile_kill_switch`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_precompile_kill_switch`.
+pub trait WeightInfo {
+    fn disable_precompile() -> Weight;
+    fn enable_precompile() -> Weight;
+    fn on_initialize() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `PrecompileKillSwitch::DisabledPrecompiles` (r:1 w:1)
+    /// Proof: `PrecompileKillSwitch::DisabledPrecompiles` (`max_values`: Some(1), `max_size`: Some(2562), added: 3057, mode: `MaxEncodedLen`)
+    fn disable_precompile() -> Weight {
+        Weight::from_parts(0, 4047)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `PrecompileKillSwitch::DisabledPrecompiles` (r:1 w:1)
+    /// Proof: `PrecompileKillSwitch::DisabledPrecompiles` (`max_values`: Some(1), `max_size`: Some(2562), added: 3057, mode: `MaxEncodedLen`)
+    fn enable_precompile() -> Weight {
+        Weight::from_parts(0, 4047)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `PrecompileKillSwitch::DisabledPrecompiles` (r:1 w:0)
+    /// Proof: `PrecompileKillSwitch::DisabledPrecompiles` (`max_values`: Some(1), `max_size`: Some(2562), added: 3057, mode: `MaxEncodedLen`)
+    fn on_initialize() -> Weight {
+        Weight::from_parts(0, 4047)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Get, Hooks};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Bounded, One};
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+
+    fn funded_requester<T: Config>() -> T::AccountId {
+        let requester: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&requester, BalanceOf::<T>::max_value() / 2u32.into());
+        requester
+    }
+
+    #[benchmark]
+    fn request_randomness() {
+        let requester = funded_requester::<T>();
+        let delay = T::MinBlockDelay::get();
+
+        #[extrinsic_call]
+        request_randomness(RawOrigin::Signed(requester), H256::repeat_byte(1), delay);
+
+        assert!(Requests::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn fulfil_randomness() {
+        let requester = funded_requester::<T>();
+        let delay = T::MinBlockDelay::get();
+        Pallet::<T>::request_randomness(
+            RawOrigin::Signed(requester.clone()).into(),
+            H256::repeat_byte(1),
+            delay,
+        )
+        .unwrap();
+        // Worst case: the result is removed with the request.
+        let fulfil_at = frame_system::Pallet::<T>::block_number() + delay;
+        RandomnessResults::<T>::insert(
+            fulfil_at,
+            RandomnessResult {
+                randomness: Some(H256::repeat_byte(7)),
+                request_count: 1,
+            },
+        );
+
+        #[extrinsic_call]
+        fulfil_randomness(RawOrigin::Signed(requester), 0);
+
+        assert!(!Requests::<T>::contains_key(0));
+        assert!(!RandomnessResults::<T>::contains_key(fulfil_at));
+    }
+
+    #[benchmark]
+    fn purge_expired_request() {
+        let requester = funded_requester::<T>();
+        let delay = T::MinBlockDelay::get();
+        Pallet::<T>::request_randomness(
+            RawOrigin::Signed(requester.clone()).into(),
+            H256::repeat_byte(1),
+            delay,
+        )
+        .unwrap();
+        // Worst case: the result is removed with the request.
+        let fulfil_at = frame_system::Pallet::<T>::block_number() + delay;
+        frame_system::Pallet::<T>::set_block_number(
+            fulfil_at + T::ExpirationDelay::get() + One::one(),
+        );
+
+        #[extrinsic_call]
+        purge_expired_request(RawOrigin::Signed(requester), 0);
+
+        assert!(!Requests::<T>::contains_key(0));
+        assert!(!RandomnessResults::<T>::contains_key(fulfil_at));
+    }
+
+    #[benchmark]
+    fn on_finalize() {
+        let now: BlockNumberFor<T> = One::one();
+        frame_system::Pallet::<T>::set_block_number(now);
+        RandomnessResults::<T>::insert(now, RandomnessResult::default());
+        // Worst case: the randomness is read from the relay state proof.
+        T::BenchmarkHelper::set_relay_randomness(H256::repeat_byte(7));
+
+        #[block]
+        {
+            Pallet::<T>::on_finalize(now);
+        }
+
+        assert_eq!(
+            RandomnessResults::<T>::get(now).and_then(|result| result.randomness),
+            Some(H256::repeat_byte(7))
+        );
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
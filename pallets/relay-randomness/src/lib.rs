@@ -0,0 +1,303 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Randomness for contracts, derived from the BABE randomness of the relay chain.
+//!
+//! Randomness is requested for a future block: once that block is finalized, its randomness is
+//! derived from the relay chain block randomness of its relay parent, read from the validated
+//! relay state proof, and the salt and the identifier of the request. The requester holds a
+//! deposit until it fulfils the request.
+//!
+//! If the relay randomness of that block is missing, e.g. because it couldn't be read from the
+//! relay state proof, the request can never be fulfilled: `ExpirationDelay` blocks after the
+//! block it was requested for, any account can purge it, which releases the deposit.
+//!
+//! The randomness can be biased by the collator which builds the block it was requested for:
+//! it picks the relay parent, and so the relay chain randomness used, among the recent relay
+//! chain blocks, and it can withhold the block if it doesn't like the outcome, leaving it to
+//! another collator. Don't rely on it where that collator could gain more than the value of
+//! producing the block.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::traits::{Currency, ReservableCurrency};
+pub use pallet::*;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+pub use weights::WeightInfo;
+
+/// Identifier of a randomness request.
+pub type RequestId = u64;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A pending randomness request.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, Debug)]
+pub struct Request<AccountId, Balance, BlockNumber> {
+    /// The account which requested the randomness, and which holds the deposit.
+    pub requester: AccountId,
+    /// Salt mixed in the randomness, to get different values in the same block.
+    pub salt: H256,
+    /// The block whose randomness is used.
+    pub fulfil_at: BlockNumber,
+    /// The deposit held until the request is fulfilled.
+    pub deposit: Balance,
+}
+
+/// The relay chain randomness of a block, shared by all the requests fulfilled at that block.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, Debug, Default)]
+pub struct RandomnessResult {
+    /// Available once the block is finalized.
+    pub randomness: Option<H256>,
+    /// The number of requests still using this result: it is removed when it drops to zero.
+    pub request_count: u32,
+}
+
+/// Provides the relay chain randomness needed to benchmark the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// Makes `RelayRandomness` return `randomness`, reading it the way it does in a block.
+    fn set_relay_randomness(randomness: H256);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+
+    pub type RequestOf<T> =
+        Request<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The currency of the requests deposit.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// The deposit held for each pending request.
+        #[pallet::constant]
+        type RequestDeposit: Get<BalanceOf<Self>>;
+        /// The minimum number of blocks between a request and its fulfilment.
+        #[pallet::constant]
+        type MinBlockDelay: Get<BlockNumberFor<Self>>;
+        /// The maximum number of blocks between a request and its fulfilment.
+        #[pallet::constant]
+        type MaxBlockDelay: Get<BlockNumberFor<Self>>;
+        /// The number of blocks after the requested one after which a request can be purged.
+        #[pallet::constant]
+        type ExpirationDelay: Get<BlockNumberFor<Self>>;
+        /// The block randomness of the relay parent of the current block, if available.
+        type RelayRandomness: Get<Option<H256>>;
+        type WeightInfo: WeightInfo;
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Randomness has been requested.
+        RandomnessRequested {
+            /// The identifier of the request.
+            request_id: RequestId,
+            /// The account which requested the randomness.
+            requester: T::AccountId,
+            /// The block whose randomness will be used.
+            fulfil_at: BlockNumberFor<T>,
+        },
+        /// A randomness request has been fulfilled.
+        RandomnessFulfilled {
+            /// The identifier of the request.
+            request_id: RequestId,
+            /// The randomness of the request.
+            randomness: H256,
+        },
+        /// An expired randomness request has been purged and its deposit released.
+        RequestPurged {
+            /// The identifier of the request.
+            request_id: RequestId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The block delay of the request is out of the `MinBlockDelay..=MaxBlockDelay` range.
+        InvalidBlockDelay,
+        /// There is no request with the given identifier.
+        RequestNotFound,
+        /// The request belongs to another account.
+        NotRequester,
+        /// The randomness of the request is not available yet.
+        RandomnessNotReady,
+        /// The request can't be purged before `ExpirationDelay` blocks after the requested one.
+        RequestNotExpired,
+    }
+
+    #[pallet::storage]
+    pub type NextRequestId<T> = StorageValue<_, RequestId, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Requests<T> = StorageMap<_, Twox64Concat, RequestId, RequestOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type RandomnessResults<T> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, RandomnessResult, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            T::WeightInfo::on_finalize()
+        }
+
+        fn on_finalize(n: BlockNumberFor<T>) {
+            // The relay state proof is only available after the validation data inherent.
+            if let Some(mut result) = RandomnessResults::<T>::get(n) {
+                result.randomness = T::RelayRandomness::get();
+                RandomnessResults::<T>::insert(n, result);
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Requests the randomness of the block `delay` blocks from now, holding the
+        /// `RequestDeposit` of the caller until the request is fulfilled.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::request_randomness())]
+        pub fn request_randomness(
+            origin: OriginFor<T>,
+            salt: H256,
+            delay: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            ensure!(
+                (T::MinBlockDelay::get()..=T::MaxBlockDelay::get()).contains(&delay),
+                Error::<T>::InvalidBlockDelay
+            );
+
+            let deposit = T::RequestDeposit::get();
+            T::Currency::reserve(&requester, deposit)?;
+
+            let request_id = NextRequestId::<T>::mutate(|id| {
+                let request_id = *id;
+                *id = id.wrapping_add(1);
+                request_id
+            });
+            let fulfil_at = frame_system::Pallet::<T>::block_number() + delay;
+            RandomnessResults::<T>::mutate(fulfil_at, |result| {
+                result.get_or_insert_with(Default::default).request_count += 1
+            });
+            Requests::<T>::insert(
+                request_id,
+                Request {
+                    requester: requester.clone(),
+                    salt,
+                    fulfil_at,
+                    deposit,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::RandomnessRequested {
+                request_id,
+                requester,
+                fulfil_at,
+            });
+            Ok(())
+        }
+
+        /// Fulfils a request whose randomness is available, releasing its deposit.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::fulfil_randomness())]
+        pub fn fulfil_randomness(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+            ensure!(request.requester == who, Error::<T>::NotRequester);
+            let randomness =
+                Self::randomness_of(request_id).ok_or(Error::<T>::RandomnessNotReady)?;
+
+            Self::remove_request(request_id, request);
+
+            Self::deposit_event(Event::<T>::RandomnessFulfilled {
+                request_id,
+                randomness,
+            });
+            Ok(())
+        }
+
+        /// Purges a request `ExpirationDelay` blocks after the block it was requested for,
+        /// whether its randomness is available or not, releasing the deposit of the requester.
+        /// Any account can purge an expired request.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::purge_expired_request())]
+        pub fn purge_expired_request(
+            origin: OriginFor<T>,
+            request_id: RequestId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number()
+                    > request.fulfil_at + T::ExpirationDelay::get(),
+                Error::<T>::RequestNotExpired
+            );
+
+            Self::remove_request(request_id, request);
+
+            Self::deposit_event(Event::<T>::RequestPurged { request_id });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Removes a request, together with the randomness of its block when no other request uses
+    /// it, and releases its deposit.
+    fn remove_request(request_id: RequestId, request: RequestOf<T>) {
+        Requests::<T>::remove(request_id);
+        RandomnessResults::<T>::mutate_exists(request.fulfil_at, |maybe_result| {
+            *maybe_result = maybe_result.take().and_then(|mut result| {
+                result.request_count = result.request_count.saturating_sub(1);
+                (result.request_count > 0).then_some(result)
+            });
+        });
+        T::Currency::unreserve(&request.requester, request.deposit);
+    }
+
+    /// The randomness of a pending request, once the block it was requested for is finalized.
+    pub fn randomness_of(request_id: RequestId) -> Option<H256> {
+        let request = Requests::<T>::get(request_id)?;
+        let relay_randomness = RandomnessResults::<T>::get(request.fulfil_at)?.randomness?;
+        Some(BlakeTwo256::hash_of(&(
+            relay_randomness,
+            request.salt,
+            request_id,
+        )))
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_relay_randomness;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU64, Hooks},
+};
+use sp_core::H256;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const DEPOSIT: u64 = 100;
+pub const MIN_DELAY: u64 = 2;
+pub const MAX_DELAY: u64 = 10;
+pub const EXPIRATION_DELAY: u64 = 5;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type PalletRelayRandomness = pallet_relay_randomness::Pallet<Test>;
+}
+
+// System pallet configuration
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub storage MockRelayRandomness: Option<H256> = Some(H256::repeat_byte(7));
+}
+
+impl pallet_relay_randomness::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RequestDeposit = ConstU64<DEPOSIT>;
+    type MinBlockDelay = ConstU64<MIN_DELAY>;
+    type MaxBlockDelay = ConstU64<MAX_DELAY>;
+    type ExpirationDelay = ConstU64<EXPIRATION_DELAY>;
+    type RelayRandomness = MockRelayRandomness;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockRelayRandomnessHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockRelayRandomnessHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_relay_randomness::BenchmarkHelper for MockRelayRandomnessHelper {
+    fn set_relay_randomness(randomness: H256) {
+        MockRelayRandomness::set(&Some(randomness));
+    }
+}
+
+// Test externalities initialization
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 1_000), (BOB, 1_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Finalizes the current block and initializes the next one.
+pub fn next_block() {
+    PalletRelayRandomness::on_finalize(System::block_number());
+    System::set_block_number(System::block_number() + 1);
+}
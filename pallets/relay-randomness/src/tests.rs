@@ -0,0 +1,431 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const SALT: H256 = H256::repeat_byte(1);
+
+fn request(who: u64, delay: u64) -> RequestId {
+    let request_id = NextRequestId::<Test>::get();
+    assert_ok!(PalletRelayRandomness::request_randomness(
+        RuntimeOrigin::signed(who),
+        SALT,
+        delay
+    ));
+    request_id
+}
+
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        next_block();
+    }
+}
+
+mod request_randomness {
+    use super::*;
+
+    #[test]
+    fn sets_storage_keys() {
+        new_test_ext().execute_with(|| {
+            let request_id = request(ALICE, MIN_DELAY);
+
+            assert_eq!(
+                Requests::<Test>::get(request_id),
+                Some(Request {
+                    requester: ALICE,
+                    salt: SALT,
+                    fulfil_at: 1 + MIN_DELAY,
+                    deposit: DEPOSIT,
+                })
+            );
+            assert_eq!(
+                RandomnessResults::<Test>::get(1 + MIN_DELAY),
+                Some(RandomnessResult {
+                    randomness: None,
+                    request_count: 1
+                })
+            );
+            assert_eq!(NextRequestId::<Test>::get(), request_id + 1);
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            let request_id = request(ALICE, MIN_DELAY);
+
+            System::assert_last_event(
+                Event::RandomnessRequested {
+                    request_id,
+                    requester: ALICE,
+                    fulfil_at: 1 + MIN_DELAY,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn holds_deposit() {
+        new_test_ext().execute_with(|| {
+            request(ALICE, MIN_DELAY);
+
+            assert_eq!(Balances::reserved_balance(ALICE), DEPOSIT);
+        });
+    }
+
+    #[test]
+    fn requests_for_the_same_block_share_the_result() {
+        new_test_ext().execute_with(|| {
+            request(ALICE, MIN_DELAY);
+            request(BOB, MIN_DELAY);
+
+            assert_eq!(
+                RandomnessResults::<Test>::get(1 + MIN_DELAY)
+                    .unwrap()
+                    .request_count,
+                2
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_signed() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletRelayRandomness::request_randomness(RuntimeOrigin::root(), SALT, MIN_DELAY),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_delay_is_out_of_range() {
+        new_test_ext().execute_with(|| {
+            for delay in [MIN_DELAY - 1, MAX_DELAY + 1] {
+                assert_noop!(
+                    PalletRelayRandomness::request_randomness(
+                        RuntimeOrigin::signed(ALICE),
+                        SALT,
+                        delay
+                    ),
+                    Error::<Test>::InvalidBlockDelay
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn errors_if_deposit_cannot_be_held() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletRelayRandomness::request_randomness(
+                    RuntimeOrigin::signed(3),
+                    SALT,
+                    MIN_DELAY
+                ),
+                pallet_balances::Error::<Test>::InsufficientBalance
+            );
+        });
+    }
+}
+
+mod on_finalize {
+    use super::*;
+
+    #[test]
+    fn stores_relay_randomness_of_requested_blocks_only() {
+        new_test_ext().execute_with(|| {
+            let request_id = request(ALICE, MIN_DELAY);
+
+            run_to_block(MIN_DELAY);
+            assert_eq!(RandomnessResults::<Test>::get(MIN_DELAY), None);
+            assert_eq!(PalletRelayRandomness::randomness_of(request_id), None);
+
+            run_to_block(MIN_DELAY + 2);
+            assert_eq!(
+                RandomnessResults::<Test>::get(1 + MIN_DELAY)
+                    .unwrap()
+                    .randomness,
+                MockRelayRandomness::get()
+            );
+        });
+    }
+
+    #[test]
+    fn derives_randomness_from_salt_and_request() {
+        new_test_ext().execute_with(|| {
+            let first = request(ALICE, MIN_DELAY);
+            let second = request(ALICE, MIN_DELAY);
+
+            run_to_block(MIN_DELAY + 2);
+
+            let first_randomness = PalletRelayRandomness::randomness_of(first).unwrap();
+            assert_eq!(
+                first_randomness,
+                BlakeTwo256::hash_of(&(H256::repeat_byte(7), SALT, first))
+            );
+            assert_ne!(
+                first_randomness,
+                PalletRelayRandomness::randomness_of(second).unwrap()
+            );
+        });
+    }
+}
+
+mod fulfil_randomness {
+    use super::*;
+
+    fn ready_request() -> RequestId {
+        let request_id = request(ALICE, MIN_DELAY);
+        run_to_block(MIN_DELAY + 2);
+        request_id
+    }
+
+    #[test]
+    fn removes_request_and_releases_deposit() {
+        new_test_ext().execute_with(|| {
+            let request_id = ready_request();
+
+            assert_ok!(PalletRelayRandomness::fulfil_randomness(
+                RuntimeOrigin::signed(ALICE),
+                request_id
+            ));
+
+            assert_eq!(Requests::<Test>::get(request_id), None);
+            assert_eq!(RandomnessResults::<Test>::get(1 + MIN_DELAY), None);
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+        });
+    }
+
+    #[test]
+    fn keeps_result_shared_with_other_requests() {
+        new_test_ext().execute_with(|| {
+            let request_id = request(ALICE, MIN_DELAY);
+            request(BOB, MIN_DELAY);
+            run_to_block(MIN_DELAY + 2);
+
+            assert_ok!(PalletRelayRandomness::fulfil_randomness(
+                RuntimeOrigin::signed(ALICE),
+                request_id
+            ));
+
+            assert_eq!(
+                RandomnessResults::<Test>::get(1 + MIN_DELAY)
+                    .unwrap()
+                    .request_count,
+                1
+            );
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            let request_id = ready_request();
+            let randomness = PalletRelayRandomness::randomness_of(request_id).unwrap();
+
+            assert_ok!(PalletRelayRandomness::fulfil_randomness(
+                RuntimeOrigin::signed(ALICE),
+                request_id
+            ));
+
+            System::assert_last_event(
+                Event::RandomnessFulfilled {
+                    request_id,
+                    randomness,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_not_requester() {
+        new_test_ext().execute_with(|| {
+            let request_id = ready_request();
+
+            assert_noop!(
+                PalletRelayRandomness::fulfil_randomness(RuntimeOrigin::signed(BOB), request_id),
+                Error::<Test>::NotRequester
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_not_ready() {
+        new_test_ext().execute_with(|| {
+            let request_id = request(ALICE, MIN_DELAY);
+            run_to_block(1 + MIN_DELAY);
+
+            assert_noop!(
+                PalletRelayRandomness::fulfil_randomness(RuntimeOrigin::signed(ALICE), request_id),
+                Error::<Test>::RandomnessNotReady
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_request_does_not_exist() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletRelayRandomness::fulfil_randomness(RuntimeOrigin::signed(ALICE), 42),
+                Error::<Test>::RequestNotFound
+            );
+        });
+    }
+}
+
+mod purge_expired_request {
+    use super::*;
+
+    /// A request whose randomness was missing when its block was finalized.
+    fn stuck_request() -> RequestId {
+        let request_id = request(ALICE, MIN_DELAY);
+        MockRelayRandomness::set(&None);
+        run_to_block(MIN_DELAY + 2);
+        MockRelayRandomness::set(&Some(H256::repeat_byte(7)));
+        request_id
+    }
+
+    fn expire() {
+        run_to_block(1 + MIN_DELAY + EXPIRATION_DELAY + 1);
+    }
+
+    #[test]
+    fn stuck_request_can_never_be_fulfilled() {
+        new_test_ext().execute_with(|| {
+            let request_id = stuck_request();
+            expire();
+
+            assert_noop!(
+                PalletRelayRandomness::fulfil_randomness(RuntimeOrigin::signed(ALICE), request_id),
+                Error::<Test>::RandomnessNotReady
+            );
+        });
+    }
+
+    #[test]
+    fn removes_request_and_releases_deposit() {
+        new_test_ext().execute_with(|| {
+            let request_id = stuck_request();
+            expire();
+
+            assert_ok!(PalletRelayRandomness::purge_expired_request(
+                RuntimeOrigin::signed(ALICE),
+                request_id
+            ));
+
+            assert_eq!(Requests::<Test>::get(request_id), None);
+            assert_eq!(RandomnessResults::<Test>::get(1 + MIN_DELAY), None);
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+        });
+    }
+
+    #[test]
+    fn any_account_can_purge() {
+        new_test_ext().execute_with(|| {
+            let request_id = stuck_request();
+            expire();
+
+            assert_ok!(PalletRelayRandomness::purge_expired_request(
+                RuntimeOrigin::signed(BOB),
+                request_id
+            ));
+
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert_eq!(Balances::free_balance(ALICE), 1_000);
+        });
+    }
+
+    #[test]
+    fn keeps_result_shared_with_other_requests() {
+        new_test_ext().execute_with(|| {
+            let request_id = request(ALICE, MIN_DELAY);
+            request(BOB, MIN_DELAY);
+            MockRelayRandomness::set(&None);
+            expire();
+
+            assert_ok!(PalletRelayRandomness::purge_expired_request(
+                RuntimeOrigin::signed(ALICE),
+                request_id
+            ));
+
+            assert_eq!(
+                RandomnessResults::<Test>::get(1 + MIN_DELAY)
+                    .unwrap()
+                    .request_count,
+                1
+            );
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            let request_id = stuck_request();
+            expire();
+
+            assert_ok!(PalletRelayRandomness::purge_expired_request(
+                RuntimeOrigin::signed(ALICE),
+                request_id
+            ));
+
+            System::assert_last_event(Event::RequestPurged { request_id }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_signed() {
+        new_test_ext().execute_with(|| {
+            let request_id = stuck_request();
+            expire();
+
+            assert_noop!(
+                PalletRelayRandomness::purge_expired_request(RuntimeOrigin::none(), request_id),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_not_expired() {
+        new_test_ext().execute_with(|| {
+            let request_id = stuck_request();
+            run_to_block(1 + MIN_DELAY + EXPIRATION_DELAY);
+
+            assert_noop!(
+                PalletRelayRandomness::purge_expired_request(
+                    RuntimeOrigin::signed(ALICE),
+                    request_id
+                ),
+                Error::<Test>::RequestNotExpired
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_request_does_not_exist() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletRelayRandomness::purge_expired_request(RuntimeOrigin::signed(ALICE), 42),
+                Error::<Test>::RequestNotFound
+            );
+        });
+    }
+}
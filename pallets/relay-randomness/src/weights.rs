@@ -0,0 +1,71 @@
+
+//! Hand-written weights for `pallet_relay_randomness`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+//!
+//! The execution time of `on_finalize`, which is charged in every block, is the time of reading
+//! the randomness from a 19 kB relay state proof (100 inbound and 100 outbound HRMP channels):
+//! 113µs measured natively, multiplied by 4 for the execution in WASM.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+/// Weight functions needed for `pallet_relay_randomness`.
+pub trait WeightInfo {
+    fn request_randomness() -> Weight;
+    fn fulfil_randomness() -> Weight;
+    fn purge_expired_request() -> Weight;
+    fn on_finalize() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::NextRequestId` (r:1 w:1)
+    /// Proof: `RelayRandomness::NextRequestId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::Requests` (r:0 w:1)
+    /// Proof: `RelayRandomness::Requests` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    fn request_randomness() -> Weight {
+        Weight::from_parts(0, 6620)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `RelayRandomness::Requests` (r:1 w:1)
+    /// Proof: `RelayRandomness::Requests` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn fulfil_randomness() -> Weight {
+        Weight::from_parts(0, 8692)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RelayRandomness::Requests` (r:1 w:1)
+    /// Proof: `RelayRandomness::Requests` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn purge_expired_request() -> Weight {
+        Weight::from_parts(0, 8692)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    fn on_finalize() -> Weight {
+        Weight::from_parts(452_000_000, 3514)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use cumulus_pallet_parachain_system::RelayChainState;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Get, Hooks};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+
+    #[benchmark]
+    fn on_finalize() {
+        // Worst case: the oldest root is removed.
+        let max = T::MaxStorageRoots::get();
+        for number in 0..max {
+            Pallet::<T>::store_relay_storage_root(number, H256::repeat_byte(1));
+        }
+        T::RelaychainStateProvider::set_current_relay_chain_state(RelayChainState {
+            number: max,
+            state_root: H256::repeat_byte(2),
+        });
+
+        #[block]
+        {
+            Pallet::<T>::on_finalize(BlockNumberFor::<T>::default());
+        }
+
+        assert_eq!(RelayStorageRoots::<T>::get(max), Some(H256::repeat_byte(2)));
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,129 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keeps the storage roots of the last relay chain blocks, as validated by
+//! `cumulus_pallet_parachain_system`, and reads relay chain storage entries from proofs checked
+//! against them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use cumulus_pallet_parachain_system::RelaychainStateProvider;
+pub use cumulus_primitives_core::relay_chain::BlockNumber as RelayBlockNumber;
+pub use pallet::*;
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+use sp_std::vec::Vec;
+use sp_trie::{LayoutV1, StorageProof};
+pub use weights::WeightInfo;
+
+/// Why a relay chain storage entry could not be read.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReadEntryError {
+    /// The storage root of the relay chain block is not known, or not kept anymore.
+    UnknownRelayBlock,
+    /// The proof does not contain the trie nodes leading to the key.
+    InvalidProof,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Provides the number and the storage root of the relay parent of the current block.
+        type RelaychainStateProvider: RelaychainStateProvider;
+        /// The number of relay chain storage roots kept: the oldest one is removed when a new one
+        /// is stored.
+        #[pallet::constant]
+        type MaxStorageRoots: Get<u32>;
+        type WeightInfo: WeightInfo;
+    }
+
+    /// The storage roots of the kept relay chain blocks.
+    #[pallet::storage]
+    pub type RelayStorageRoots<T> =
+        StorageMap<_, Twox64Concat, RelayBlockNumber, H256, OptionQuery>;
+
+    /// The numbers of the kept relay chain blocks, from the oldest to the latest.
+    #[pallet::storage]
+    pub type RelayBlockNumbers<T: Config> =
+        StorageValue<_, BoundedVec<RelayBlockNumber, T::MaxStorageRoots>, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            T::WeightInfo::on_finalize()
+        }
+
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            // The relay chain state is only available after the validation data inherent.
+            let state = T::RelaychainStateProvider::current_relay_chain_state();
+            Self::store_relay_storage_root(state.number, state.state_root);
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The number of the latest relay chain block whose storage root is known.
+    pub fn latest_relay_block_number() -> Option<RelayBlockNumber> {
+        RelayBlockNumbers::<T>::get().last().copied()
+    }
+
+    /// Reads the value of `key` in the storage of the relay chain block `relay_block_number`
+    /// from `proof`, the trie nodes as returned by the `state_getReadProof` RPC of the relay
+    /// chain. Returns `None` if the proof shows that there is no such key.
+    pub fn read_entry(
+        relay_block_number: RelayBlockNumber,
+        proof: impl IntoIterator<Item = Vec<u8>>,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, ReadEntryError> {
+        let root = RelayStorageRoots::<T>::get(relay_block_number)
+            .ok_or(ReadEntryError::UnknownRelayBlock)?;
+        let db = StorageProof::new(proof).into_memory_db::<BlakeTwo256>();
+        sp_trie::read_trie_value::<LayoutV1<BlakeTwo256>, _>(&db, &root, key, None, None)
+            .map_err(|_| ReadEntryError::InvalidProof)
+    }
+
+    /// Several parachain blocks can share the same relay parent: its root is stored only once.
+    fn store_relay_storage_root(relay_block_number: RelayBlockNumber, root: H256) {
+        if RelayStorageRoots::<T>::contains_key(relay_block_number) {
+            return;
+        }
+        RelayBlockNumbers::<T>::mutate(|numbers| {
+            if numbers.is_full() && !numbers.is_empty() {
+                RelayStorageRoots::<T>::remove(numbers.remove(0));
+            }
+            // Cannot fail: there is room for at least one more number, unless nothing is kept.
+            if numbers.try_push(relay_block_number).is_ok() {
+                RelayStorageRoots::<T>::insert(relay_block_number, root);
+            }
+        });
+    }
+}
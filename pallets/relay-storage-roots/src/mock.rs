@@ -0,0 +1,91 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_relay_storage_roots;
+use cumulus_pallet_parachain_system::{RelayChainState, RelaychainStateProvider};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
+use pallet_relay_storage_roots::RelayBlockNumber;
+use sp_core::H256;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type PalletRelayStorageRoots = pallet_relay_storage_roots::Pallet<Test>;
+}
+
+pub const MAX_STORAGE_ROOTS: u32 = 3;
+
+// System pallet configuration
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub storage MockRelayState: (RelayBlockNumber, H256) = (10, H256::repeat_byte(10));
+}
+
+pub struct MockRelaychainStateProvider;
+
+impl RelaychainStateProvider for MockRelaychainStateProvider {
+    fn current_relay_chain_state() -> RelayChainState {
+        let (number, state_root) = MockRelayState::get();
+        RelayChainState { number, state_root }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_relay_chain_state(state: RelayChainState) {
+        MockRelayState::set(&(state.number, state.state_root));
+    }
+}
+
+/// Sets the relay parent of the current block.
+pub fn set_relay_parent(number: RelayBlockNumber, state_root: H256) {
+    MockRelayState::set(&(number, state_root));
+}
+
+impl pallet_relay_storage_roots::Config for Test {
+    type RelaychainStateProvider = MockRelaychainStateProvider;
+    type MaxStorageRoots = ConstU32<MAX_STORAGE_ROOTS>;
+    type WeightInfo = ();
+}
+
+// Test externalities initialization
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap()
+        .into()
+}
@@ -0,0 +1,158 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::traits::Hooks;
+use sp_trie::{MemoryDB, TrieDBMutBuilder, TrieMut};
+
+fn finalize_block() {
+    PalletRelayStorageRoots::on_finalize(System::block_number());
+}
+
+/// Builds the trie of `entries`, returning its root and a proof containing all of its nodes.
+fn trie(entries: &[(&[u8], &[u8])]) -> (H256, Vec<Vec<u8>>) {
+    let mut db = MemoryDB::<BlakeTwo256>::default();
+    let mut root = H256::zero();
+    {
+        let mut trie = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+        for (key, value) in entries {
+            trie.insert(key, value).unwrap();
+        }
+    }
+    let proof = db
+        .drain()
+        .into_values()
+        .filter(|(_, rc)| *rc > 0)
+        .map(|(node, _)| node)
+        .collect();
+    (root, proof)
+}
+
+mod on_finalize {
+    use super::*;
+
+    #[test]
+    fn stores_relay_parent_root() {
+        new_test_ext().execute_with(|| {
+            set_relay_parent(10, H256::repeat_byte(10));
+
+            finalize_block();
+
+            assert_eq!(
+                RelayStorageRoots::<Test>::get(10),
+                Some(H256::repeat_byte(10))
+            );
+            assert_eq!(
+                PalletRelayStorageRoots::latest_relay_block_number(),
+                Some(10)
+            );
+        });
+    }
+
+    #[test]
+    fn stores_root_once_per_relay_block() {
+        new_test_ext().execute_with(|| {
+            set_relay_parent(10, H256::repeat_byte(10));
+            finalize_block();
+            finalize_block();
+
+            assert_eq!(RelayBlockNumbers::<Test>::get().into_inner(), vec![10]);
+        });
+    }
+
+    #[test]
+    fn removes_oldest_root_when_full() {
+        new_test_ext().execute_with(|| {
+            for number in 1..=MAX_STORAGE_ROOTS + 1 {
+                set_relay_parent(number, H256::repeat_byte(number as u8));
+                finalize_block();
+            }
+
+            assert_eq!(RelayStorageRoots::<Test>::get(1), None);
+            assert_eq!(
+                RelayBlockNumbers::<Test>::get().into_inner(),
+                (2..=MAX_STORAGE_ROOTS + 1).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                PalletRelayStorageRoots::latest_relay_block_number(),
+                Some(MAX_STORAGE_ROOTS + 1)
+            );
+        });
+    }
+}
+
+mod read_entry {
+    use super::*;
+
+    const KEY: &[u8] = b"key";
+    const VALUE: &[u8] = b"value";
+
+    fn store_trie() -> Vec<Vec<u8>> {
+        let (root, proof) = trie(&[(KEY, VALUE), (b"other key", b"other value")]);
+        set_relay_parent(10, root);
+        finalize_block();
+        proof
+    }
+
+    #[test]
+    fn reads_value() {
+        new_test_ext().execute_with(|| {
+            let proof = store_trie();
+
+            assert_eq!(
+                PalletRelayStorageRoots::read_entry(10, proof, KEY),
+                Ok(Some(VALUE.to_vec()))
+            );
+        });
+    }
+
+    #[test]
+    fn reads_missing_key_as_none() {
+        new_test_ext().execute_with(|| {
+            let proof = store_trie();
+
+            assert_eq!(
+                PalletRelayStorageRoots::read_entry(10, proof, b"missing key"),
+                Ok(None)
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_unknown_relay_block() {
+        new_test_ext().execute_with(|| {
+            let proof = store_trie();
+
+            assert_eq!(
+                PalletRelayStorageRoots::read_entry(11, proof, KEY),
+                Err(ReadEntryError::UnknownRelayBlock)
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_proof_of_another_trie() {
+        new_test_ext().execute_with(|| {
+            store_trie();
+            let (_, proof) = trie(&[(KEY, b"forged value")]);
+
+            assert_eq!(
+                PalletRelayStorageRoots::read_entry(10, proof, KEY),
+                Err(ReadEntryError::InvalidProof)
+            );
+        });
+    }
+}
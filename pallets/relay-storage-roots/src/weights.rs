@@ -0,0 +1,33 @@
+
+//! Hand-written weights for `pallet_relay_storage_roots`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_relay_storage_roots`.
+pub trait WeightInfo {
+    fn on_finalize() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `RelayStorageRoots::RelayStorageRoots` (r:1 w:2)
+    /// Proof: `RelayStorageRoots::RelayStorageRoots` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `RelayStorageRoots::RelayBlockNumbers` (r:1 w:1)
+    /// Proof: `RelayStorageRoots::RelayBlockNumbers` (`max_values`: Some(1), `max_size`: Some(121), added: 616, mode: `MaxEncodedLen`)
+    fn on_finalize() -> Weight {
+        Weight::from_parts(0, 4125)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keeps the chains trusted to alias the origin of their accounts into the accounts of this
+//! chain, through the XCM `AliasOrigin` instruction.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+use alloc::boxed::Box;
+use frame_support::traits::Contains;
+pub use pallet::*;
+use xcm::{latest::Location, VersionedLocation};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A chain has been trusted to alias the origin of its accounts.
+        AliaserTrusted {
+            /// The location of the chain.
+            location: Location,
+        },
+        /// A chain is not trusted to alias the origin of its accounts anymore.
+        AliaserUntrusted {
+            /// The location of the chain.
+            location: Location,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The location cannot be converted to the latest XCM version.
+        BadLocation,
+        /// Tried to trust a chain which is already trusted.
+        AliaserAlreadyTrusted,
+        /// Tried to untrust a chain which is not trusted.
+        AliaserNotTrusted,
+    }
+
+    #[pallet::storage]
+    pub type TrustedAliasers<T> = StorageMap<_, Blake2_128Concat, Location, (), OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::trust_aliaser())]
+        pub fn trust_aliaser(
+            origin: OriginFor<T>,
+            location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let location = Self::latest(*location)?;
+            if !TrustedAliasers::<T>::contains_key(&location) {
+                TrustedAliasers::<T>::insert(&location, ());
+                Self::deposit_event(Event::<T>::AliaserTrusted { location });
+                Ok(())
+            } else {
+                Err(Error::<T>::AliaserAlreadyTrusted)?
+            }
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::untrust_aliaser())]
+        pub fn untrust_aliaser(
+            origin: OriginFor<T>,
+            location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let location = Self::latest(*location)?;
+            if TrustedAliasers::<T>::contains_key(&location) {
+                TrustedAliasers::<T>::remove(&location);
+                Self::deposit_event(Event::<T>::AliaserUntrusted { location });
+                Ok(())
+            } else {
+                Err(Error::<T>::AliaserNotTrusted)?
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn latest(location: VersionedLocation) -> Result<Location, Error<T>> {
+            location.try_into().map_err(|_| Error::<T>::BadLocation)
+        }
+    }
+}
+
+/// Matches the trusted aliaser chains. Their accounts are not matched: the aliasing rules decide
+/// which of them may alias which account.
+impl<T: Config> Contains<Location> for Pallet<T> {
+    fn contains(location: &Location) -> bool {
+        TrustedAliasers::<T>::contains_key(location)
+    }
+}
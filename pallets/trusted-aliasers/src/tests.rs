@@ -0,0 +1,153 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+
+fn aliaser() -> Location {
+    Location::new(1, [Parachain(2000)])
+}
+
+fn trust(origin: RuntimeOrigin) -> DispatchResult {
+    PalletTrustedAliasers::trust_aliaser(origin, Box::new(aliaser().into()))
+}
+
+fn untrust(origin: RuntimeOrigin) -> DispatchResult {
+    PalletTrustedAliasers::untrust_aliaser(origin, Box::new(aliaser().into()))
+}
+
+mod trust_aliaser {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert!(!TrustedAliasers::<Test>::contains_key(aliaser()));
+            assert_ok!(trust(RuntimeOrigin::root()));
+            assert!(TrustedAliasers::<Test>::contains_key(aliaser()));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(trust(RuntimeOrigin::root()));
+            System::assert_last_event(
+                Event::AliaserTrusted {
+                    location: aliaser(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(trust(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+        })
+    }
+
+    #[test]
+    fn errors_if_aliaser_already_trusted() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedAliasers::<Test>::insert(aliaser(), ());
+            assert_noop!(
+                trust(RuntimeOrigin::root()),
+                Error::<Test>::AliaserAlreadyTrusted
+            );
+        })
+    }
+}
+
+mod untrust_aliaser {
+    use super::*;
+
+    #[test]
+    fn clears_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedAliasers::<Test>::insert(aliaser(), ());
+            assert_ok!(untrust(RuntimeOrigin::root()));
+            assert!(!TrustedAliasers::<Test>::contains_key(aliaser()));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedAliasers::<Test>::insert(aliaser(), ());
+            assert_ok!(untrust(RuntimeOrigin::root()));
+            System::assert_last_event(
+                Event::AliaserUntrusted {
+                    location: aliaser(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedAliasers::<Test>::insert(aliaser(), ());
+            assert_noop!(untrust(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+        })
+    }
+
+    #[test]
+    fn errors_if_aliaser_not_trusted() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                untrust(RuntimeOrigin::root()),
+                Error::<Test>::AliaserNotTrusted
+            );
+        })
+    }
+}
+
+mod contains {
+    use super::*;
+
+    #[test]
+    fn matches_trusted_aliaser_only() {
+        new_test_ext().execute_with(|| {
+            TrustedAliasers::<Test>::insert(aliaser(), ());
+
+            assert!(PalletTrustedAliasers::contains(&aliaser()));
+            assert!(!PalletTrustedAliasers::contains(&Location::new(
+                1,
+                [
+                    Parachain(2000),
+                    AccountKey20 {
+                        network: None,
+                        key: [1; 20]
+                    }
+                ]
+            )));
+            assert!(!PalletTrustedAliasers::contains(&Location::parent()));
+        })
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keeps the sibling parachains trusted to execute paid XCM on this chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+use alloc::vec::Vec;
+use cumulus_primitives_core::ParaId;
+use frame_support::traits::Contains;
+pub use pallet::*;
+use xcm::latest::{Junction::Parachain, Location};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*, DefaultNoBound};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A sibling parachain has been trusted.
+        SiblingTrusted {
+            /// The identifier of the sibling parachain.
+            para_id: ParaId,
+        },
+        /// A sibling parachain is not trusted anymore.
+        SiblingUntrusted {
+            /// The identifier of the sibling parachain.
+            para_id: ParaId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Tried to trust a sibling parachain which is already trusted.
+        SiblingAlreadyTrusted,
+        /// Tried to untrust a sibling parachain which is not trusted.
+        SiblingNotTrusted,
+    }
+
+    #[pallet::storage]
+    pub type TrustedSiblings<T> = StorageMap<_, Twox64Concat, ParaId, (), OptionQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        pub trusted_siblings: Vec<ParaId>,
+        _phantom: PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            self.trusted_siblings.iter().for_each(|para_id| {
+                TrustedSiblings::<T>::insert(para_id, ());
+            });
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::trust_sibling())]
+        pub fn trust_sibling(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+            ensure_root(origin)?;
+            if !TrustedSiblings::<T>::contains_key(para_id) {
+                TrustedSiblings::<T>::insert(para_id, ());
+                Self::deposit_event(Event::<T>::SiblingTrusted { para_id });
+                Ok(())
+            } else {
+                Err(Error::<T>::SiblingAlreadyTrusted)?
+            }
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::untrust_sibling())]
+        pub fn untrust_sibling(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+            ensure_root(origin)?;
+            if TrustedSiblings::<T>::contains_key(para_id) {
+                TrustedSiblings::<T>::remove(para_id);
+                Self::deposit_event(Event::<T>::SiblingUntrusted { para_id });
+                Ok(())
+            } else {
+                Err(Error::<T>::SiblingNotTrusted)?
+            }
+        }
+    }
+}
+
+/// Matches the trusted sibling parachains and any location inside them, e.g. their accounts.
+impl<T: Config> Contains<Location> for Pallet<T> {
+    fn contains(location: &Location) -> bool {
+        match location.unpack() {
+            (1, [Parachain(id), ..]) => TrustedSiblings::<T>::contains_key(ParaId::from(*id)),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+
+const PARA_ID: u32 = 2000;
+
+mod trust_sibling {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert!(!TrustedSiblings::<Test>::contains_key(ParaId::from(
+                PARA_ID
+            )));
+            assert_ok!(PalletTrustedSiblings::trust_sibling(
+                RuntimeOrigin::root(),
+                PARA_ID.into()
+            ));
+            assert!(TrustedSiblings::<Test>::contains_key(ParaId::from(PARA_ID)));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletTrustedSiblings::trust_sibling(
+                RuntimeOrigin::root(),
+                PARA_ID.into()
+            ));
+            System::assert_last_event(
+                Event::SiblingTrusted {
+                    para_id: PARA_ID.into(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletTrustedSiblings::trust_sibling(RuntimeOrigin::signed(1), PARA_ID.into()),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_sibling_already_trusted() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedSiblings::<Test>::insert(ParaId::from(PARA_ID), ());
+            assert_noop!(
+                PalletTrustedSiblings::trust_sibling(RuntimeOrigin::root(), PARA_ID.into()),
+                Error::<Test>::SiblingAlreadyTrusted
+            );
+        })
+    }
+}
+
+mod untrust_sibling {
+    use super::*;
+
+    #[test]
+    fn clears_storage_key() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedSiblings::<Test>::insert(ParaId::from(PARA_ID), ());
+            assert_ok!(PalletTrustedSiblings::untrust_sibling(
+                RuntimeOrigin::root(),
+                PARA_ID.into()
+            ));
+            assert!(!TrustedSiblings::<Test>::contains_key(ParaId::from(
+                PARA_ID
+            )));
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedSiblings::<Test>::insert(ParaId::from(PARA_ID), ());
+            assert_ok!(PalletTrustedSiblings::untrust_sibling(
+                RuntimeOrigin::root(),
+                PARA_ID.into()
+            ));
+            System::assert_last_event(
+                Event::SiblingUntrusted {
+                    para_id: PARA_ID.into(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            TrustedSiblings::<Test>::insert(ParaId::from(PARA_ID), ());
+            assert_noop!(
+                PalletTrustedSiblings::untrust_sibling(RuntimeOrigin::signed(1), PARA_ID.into()),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_sibling_not_trusted() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletTrustedSiblings::untrust_sibling(RuntimeOrigin::root(), PARA_ID.into()),
+                Error::<Test>::SiblingNotTrusted
+            );
+        })
+    }
+}
+
+mod contains {
+    use super::*;
+
+    #[test]
+    fn matches_trusted_sibling_and_its_locations() {
+        new_test_ext().execute_with(|| {
+            TrustedSiblings::<Test>::insert(ParaId::from(PARA_ID), ());
+
+            assert!(PalletTrustedSiblings::contains(&Location::new(
+                1,
+                [Parachain(PARA_ID)]
+            )));
+            assert!(PalletTrustedSiblings::contains(&Location::new(
+                1,
+                [
+                    Parachain(PARA_ID),
+                    AccountKey20 {
+                        network: None,
+                        key: [1; 20]
+                    }
+                ]
+            )));
+        })
+    }
+
+    #[test]
+    fn does_not_match_other_locations() {
+        new_test_ext().execute_with(|| {
+            TrustedSiblings::<Test>::insert(ParaId::from(PARA_ID), ());
+
+            assert!(!PalletTrustedSiblings::contains(&Location::new(
+                1,
+                [Parachain(PARA_ID + 1)]
+            )));
+            assert!(!PalletTrustedSiblings::contains(&Location::parent()));
+            assert!(!PalletTrustedSiblings::contains(&Location::new(
+                0,
+                [Parachain(PARA_ID)]
+            )));
+        })
+    }
+}
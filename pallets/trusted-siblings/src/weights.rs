@@ -0,0 +1,39 @@
+
+//! Hand-written weights for `pallet_trusted_siblings`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_trusted_siblings`.
+pub trait WeightInfo {
+    fn trust_sibling() -> Weight;
+    fn untrust_sibling() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `TrustedSiblings::TrustedSiblings` (r:1 w:1)
+    /// Proof: `TrustedSiblings::TrustedSiblings` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+    fn trust_sibling() -> Weight {
+        Weight::from_parts(0, 3477)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `TrustedSiblings::TrustedSiblings` (r:1 w:1)
+    /// Proof: `TrustedSiblings::TrustedSiblings` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+    fn untrust_sibling() -> Weight {
+        Weight::from_parts(0, 3477)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
\ No newline at end of file
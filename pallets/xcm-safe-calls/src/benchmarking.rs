@@ -0,0 +1,55 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+
+/// Fills the safe calls up to `len` calls, none of them being `(u8::MAX, u8::MAX)`.
+fn fill_safe_calls<T: Config>(len: u32) {
+    let safe_calls = (0..len)
+        .map(|i| ((i / 256) as u8, (i % 256) as u8))
+        .collect::<BTreeSet<_>>();
+    SafeCalls::<T>::put(BoundedBTreeSet::try_from(safe_calls).unwrap());
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+    use frame_system::RawOrigin;
+
+    #[benchmark]
+    fn allow_call() {
+        fill_safe_calls::<T>(T::MaxSafeCalls::get() - 1);
+
+        #[extrinsic_call]
+        allow_call(RawOrigin::Root, u8::MAX, u8::MAX);
+
+        assert!(SafeCalls::<T>::get().contains(&(u8::MAX, u8::MAX)));
+    }
+
+    #[benchmark]
+    fn disallow_call() {
+        fill_safe_calls::<T>(T::MaxSafeCalls::get());
+
+        #[extrinsic_call]
+        disallow_call(RawOrigin::Root, 0, 0);
+
+        assert!(!SafeCalls::<T>::get().contains(&(0, 0)));
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,176 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keeps the calls which the XCM `Transact` instruction is allowed to dispatch, as a set of
+//! (pallet index, call index) pairs editable by root.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::marker::PhantomData;
+use frame_support::{
+    traits::{Contains, Get},
+    BoundedBTreeSet,
+};
+pub use pallet::*;
+
+/// The (pallet index, call index) pair identifying a call of the runtime.
+pub type CallIndex = (u8, u8);
+
+/// The safe calls of a runtime which has never edited them: `T::DefaultSafeCalls`.
+pub struct DefaultSafeCalls<T>(PhantomData<T>);
+impl<T: Config> Get<BoundedBTreeSet<CallIndex, T::MaxSafeCalls>> for DefaultSafeCalls<T> {
+    fn get() -> BoundedBTreeSet<CallIndex, T::MaxSafeCalls> {
+        T::DefaultSafeCalls::get()
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .try_into()
+            .unwrap_or_default()
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The maximum number of safe calls.
+        #[pallet::constant]
+        type MaxSafeCalls: Get<u32>;
+        /// The safe calls until root edits them for the first time.
+        type DefaultSafeCalls: Get<Vec<CallIndex>>;
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A call has been allowed.
+        CallAllowed {
+            /// The index of the pallet of the call.
+            pallet_index: u8,
+            /// The index of the call in its pallet.
+            call_index: u8,
+        },
+        /// A call is not allowed anymore.
+        CallDisallowed {
+            /// The index of the pallet of the call.
+            pallet_index: u8,
+            /// The index of the call in its pallet.
+            call_index: u8,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Tried to allow a call which is already allowed.
+        CallAlreadyAllowed,
+        /// Tried to disallow a call which is not allowed.
+        CallNotAllowed,
+        /// Tried to allow more than `MaxSafeCalls` calls.
+        TooManySafeCalls,
+    }
+
+    #[pallet::storage]
+    pub type SafeCalls<T: Config> = StorageValue<
+        _,
+        BoundedBTreeSet<CallIndex, T::MaxSafeCalls>,
+        ValueQuery,
+        DefaultSafeCalls<T>,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                T::DefaultSafeCalls::get().len() <= T::MaxSafeCalls::get() as usize,
+                "`DefaultSafeCalls` must not exceed `MaxSafeCalls`"
+            );
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::allow_call())]
+        pub fn allow_call(
+            origin: OriginFor<T>,
+            pallet_index: u8,
+            call_index: u8,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let mut safe_calls = SafeCalls::<T>::get();
+            if !safe_calls.contains(&(pallet_index, call_index)) {
+                safe_calls
+                    .try_insert((pallet_index, call_index))
+                    .map_err(|_| Error::<T>::TooManySafeCalls)?;
+                SafeCalls::<T>::put(safe_calls);
+                Self::deposit_event(Event::<T>::CallAllowed {
+                    pallet_index,
+                    call_index,
+                });
+                Ok(())
+            } else {
+                Err(Error::<T>::CallAlreadyAllowed)?
+            }
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::disallow_call())]
+        pub fn disallow_call(
+            origin: OriginFor<T>,
+            pallet_index: u8,
+            call_index: u8,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let mut safe_calls = SafeCalls::<T>::get();
+            if safe_calls.remove(&(pallet_index, call_index)) {
+                SafeCalls::<T>::put(safe_calls);
+                Self::deposit_event(Event::<T>::CallDisallowed {
+                    pallet_index,
+                    call_index,
+                });
+                Ok(())
+            } else {
+                Err(Error::<T>::CallNotAllowed)?
+            }
+        }
+    }
+}
+
+impl<T: Config> Contains<CallIndex> for Pallet<T> {
+    fn contains(call: &CallIndex) -> bool {
+        SafeCalls::<T>::get().contains(call)
+    }
+}
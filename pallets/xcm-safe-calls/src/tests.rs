@@ -0,0 +1,189 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const CALL: CallIndex = (2, 0);
+
+fn safe_calls() -> Vec<CallIndex> {
+    SafeCalls::<Test>::get().into_iter().collect()
+}
+
+mod allow_call {
+    use super::*;
+
+    #[test]
+    fn adds_call() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletXcmSafeCalls::allow_call(
+                RuntimeOrigin::root(),
+                CALL.0,
+                CALL.1
+            ));
+            assert_eq!(safe_calls(), vec![(1, 0), (1, 1), CALL]);
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletXcmSafeCalls::allow_call(
+                RuntimeOrigin::root(),
+                CALL.0,
+                CALL.1
+            ));
+            System::assert_last_event(
+                Event::CallAllowed {
+                    pallet_index: CALL.0,
+                    call_index: CALL.1,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletXcmSafeCalls::allow_call(RuntimeOrigin::signed(1), CALL.0, CALL.1),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_call_already_allowed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletXcmSafeCalls::allow_call(RuntimeOrigin::root(), 1, 0),
+                Error::<Test>::CallAlreadyAllowed
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_too_many_calls() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletXcmSafeCalls::allow_call(
+                RuntimeOrigin::root(),
+                CALL.0,
+                CALL.1
+            ));
+            assert_noop!(
+                PalletXcmSafeCalls::allow_call(RuntimeOrigin::root(), 2, 1),
+                Error::<Test>::TooManySafeCalls
+            );
+        })
+    }
+}
+
+mod disallow_call {
+    use super::*;
+
+    #[test]
+    fn removes_call() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletXcmSafeCalls::disallow_call(
+                RuntimeOrigin::root(),
+                1,
+                0
+            ));
+            assert_eq!(safe_calls(), vec![(1, 1)]);
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletXcmSafeCalls::disallow_call(
+                RuntimeOrigin::root(),
+                1,
+                0
+            ));
+            System::assert_last_event(
+                Event::CallDisallowed {
+                    pallet_index: 1,
+                    call_index: 0,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletXcmSafeCalls::disallow_call(RuntimeOrigin::signed(1), 1, 0),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_call_not_allowed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletXcmSafeCalls::disallow_call(RuntimeOrigin::root(), CALL.0, CALL.1),
+                Error::<Test>::CallNotAllowed
+            );
+        })
+    }
+}
+
+mod contains {
+    use super::*;
+
+    #[test]
+    fn matches_default_calls() {
+        new_test_ext().execute_with(|| {
+            assert!(PalletXcmSafeCalls::contains(&(1, 0)));
+            assert!(PalletXcmSafeCalls::contains(&(1, 1)));
+            assert!(!PalletXcmSafeCalls::contains(&CALL));
+        });
+    }
+
+    #[test]
+    fn matches_edited_calls() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PalletXcmSafeCalls::allow_call(
+                RuntimeOrigin::root(),
+                CALL.0,
+                CALL.1
+            ));
+            assert_ok!(PalletXcmSafeCalls::disallow_call(
+                RuntimeOrigin::root(),
+                1,
+                0
+            ));
+            assert!(!PalletXcmSafeCalls::contains(&(1, 0)));
+            assert!(PalletXcmSafeCalls::contains(&(1, 1)));
+            assert!(PalletXcmSafeCalls::contains(&CALL));
+        });
+    }
+}
@@ -0,0 +1,39 @@
+
+//! Hand-written weights for `pallet_xcm_safe_calls`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_xcm_safe_calls`.
+pub trait WeightInfo {
+    fn allow_call() -> Weight;
+    fn disallow_call() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `XcmSafeCalls::SafeCalls` (r:1 w:1)
+    /// Proof: `XcmSafeCalls::SafeCalls` (`max_values`: Some(1), `max_size`: Some(258), added: 753, mode: `MaxEncodedLen`)
+    fn allow_call() -> Weight {
+        Weight::from_parts(0, 1743)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `XcmSafeCalls::SafeCalls` (r:1 w:1)
+    /// Proof: `XcmSafeCalls::SafeCalls` (`max_values`: Some(1), `max_size`: Some(258), added: 753, mode: `MaxEncodedLen`)
+    fn disallow_call() -> Weight {
+        Weight::from_parts(0, 1743)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
\ No newline at end of file
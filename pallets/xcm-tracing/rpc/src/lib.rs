@@ -0,0 +1,112 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the XCM tracing pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_xcm_tracing_runtime_api::{MessageTrace, XcmTracingApi as XcmTracingRuntimeApi};
+
+#[rpc(client, server)]
+pub trait XcmTracingApi<BlockHash, AccountId, BlockNumber> {
+    /// The kept traces of the XCM messages with `topic`, oldest first.
+    #[method(name = "xcmTracing_tracesByTopic")]
+    fn traces_by_topic(
+        &self,
+        topic: H256,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MessageTrace<AccountId, BlockNumber>>>;
+
+    /// The kept traces of the inbound XCM messages with the origin of `account`, oldest first.
+    #[method(name = "xcmTracing_tracesByAccount")]
+    fn traces_by_account(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MessageTrace<AccountId, BlockNumber>>>;
+}
+
+/// Provides the RPC methods to query the XCM message traces.
+pub struct XcmTracing<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> XcmTracing<C, B> {
+    /// Creates a new instance of the XCM tracing RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error code for the failures of the runtime API.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query the XCM message traces.",
+        Some(error.to_string()),
+    )
+}
+
+impl<C, Block, AccountId, BlockNumber>
+    XcmTracingApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for XcmTracing<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: XcmTracingRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+    BlockNumber: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+{
+    fn traces_by_topic(
+        &self,
+        topic: H256,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<MessageTrace<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .traces_by_topic(at, topic.into())
+            .map_err(runtime_error)
+    }
+
+    fn traces_by_account(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<MessageTrace<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .traces_by_account(at, account)
+            .map_err(runtime_error)
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the XCM tracing pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+
+pub use pallet_xcm_tracing::{Direction, MessageTrace, TraceOutcome};
+
+sp_api::decl_runtime_apis! {
+    pub trait XcmTracingApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// The kept traces of the messages with `topic`, oldest first.
+        fn traces_by_topic(topic: [u8; 32]) -> Vec<MessageTrace<AccountId, BlockNumber>>;
+
+        /// The kept traces of the inbound messages with the origin of `account`, oldest first.
+        fn traces_by_account(account: AccountId) -> Vec<MessageTrace<AccountId, BlockNumber>>;
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use xcm::latest::prelude::*;
+
+fn trace<T: Config>(account: T::AccountId) -> MessageTraceOf<T> {
+    MessageTrace {
+        topic: [1; 32],
+        direction: Direction::Inbound,
+        block_number: frame_system::Pallet::<T>::block_number(),
+        location: Location::new(
+            1,
+            [
+                Parachain(2000),
+                AccountId32 {
+                    network: None,
+                    id: [1; 32],
+                },
+            ],
+        ),
+        account: Some(account),
+        outcome: TraceOutcome::Complete,
+        weight_used: Weight::from_parts(1_000_000_000, 10_000),
+        fees: Some((Location::parent(), 1_000_000_000)),
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    #[cfg(test)]
+    use crate::pallet::Pallet as Template;
+
+    #[benchmark]
+    fn record_trace() {
+        // The window is over full, as after lowering `MaxTraces`: the two oldest traces are
+        // dropped
+        let max_traces = u64::from(T::MaxTraces::get());
+        for index in 0..MAX_DROPPED_TRACES {
+            Pallet::<T>::record(trace::<T>(account("oldest", index, 0)));
+        }
+        NextTraceId::<T>::put(max_traces + 1);
+        let trace = trace::<T>(account("newest", 0, 0));
+
+        #[block]
+        {
+            // The origin is converted to its account as it is for the traced messages
+            let _ = Pallet::<T>::account_of(&trace.location);
+            Pallet::<T>::record(trace.clone());
+        }
+
+        assert!(Traces::<T>::get(0).is_none());
+        assert!(Traces::<T>::get(1).is_none());
+        assert_eq!(Traces::<T>::get(max_traces + 1), Some(trace));
+    }
+
+    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+}
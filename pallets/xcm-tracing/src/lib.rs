@@ -0,0 +1,236 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keeps, for a bounded window of the latest messages, a trace of the XCM messages received and
+//! sent by this chain: their outcome, the weight they used, the fees they paid and their origin
+//! or destination. The traces are keyed by the topic of the messages, which is the same for a
+//! message and the messages it makes this chain send, and by the account of their origin.
+//!
+//! The messages are traced by wrapping the message processor of `MessageQueue`
+//! ([`tracers::TracedMessageProcessor`]), the `XcmRecorder` of the XCM executor
+//! ([`tracers::TracingRecorder`]) and the XCM router ([`tracers::TracedRouter`]).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_crate_dependencies)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod tracers;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use xcm::latest::{Location, XcmHash};
+
+/// The maximum number of traces dropped when recording a trace: more than one, so that the kept
+/// traces shrink back to `MaxTraces` after it is lowered.
+pub const MAX_DROPPED_TRACES: u32 = 2;
+
+/// The direction of a traced message.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    MaxEncodedLen,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Direction {
+    /// Received by this chain.
+    Inbound,
+    /// Sent by this chain.
+    Outbound,
+}
+
+/// The outcome of a traced message.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    MaxEncodedLen,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum TraceOutcome {
+    /// The inbound message has been executed completely.
+    Complete,
+    /// The inbound message has been executed only up to an error.
+    Incomplete,
+    /// The outbound message has been delivered to the transport.
+    Sent,
+}
+
+/// The trace of an XCM message.
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    MaxEncodedLen,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct MessageTrace<AccountId, BlockNumber> {
+    /// The topic of the message.
+    pub topic: XcmHash,
+    /// Whether the message has been received or sent.
+    pub direction: Direction,
+    /// The block in which the message has been processed or sent.
+    pub block_number: BlockNumber,
+    /// The origin of an inbound message, after its leading `DescendOrigin` instructions, or the
+    /// destination of an outbound message.
+    pub location: Location,
+    /// The account of this chain of the origin of an inbound message, if it has one.
+    pub account: Option<AccountId>,
+    /// The outcome of the message.
+    pub outcome: TraceOutcome,
+    /// The weight used to execute an inbound message.
+    pub weight_used: Weight,
+    /// The fees offered to execute an inbound message or paid to deliver an outbound message,
+    /// as the location of the asset and its amount.
+    pub fees: Option<(Location, u128)>,
+}
+
+/// The trace of a message.
+pub type MessageTraceOf<T> =
+    MessageTrace<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// The trace of the inbound message being processed, until its outcome is known.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingTrace {
+    /// The origin of the message.
+    pub origin: Location,
+    /// The fees offered to execute the message.
+    pub fees: Option<(Location, u128)>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use xcm_executor::traits::ConvertLocation;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Converts the origin of the inbound messages to the accounts of this chain.
+        type LocationToAccountId: ConvertLocation<Self::AccountId>;
+        /// The number of the latest messages whose traces are kept.
+        #[pallet::constant]
+        type MaxTraces: Get<u32>;
+        type WeightInfo: WeightInfo;
+    }
+
+    /// The id of the next trace: the traces are numbered in the order they are recorded.
+    #[pallet::storage]
+    pub type NextTraceId<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// The id of the oldest kept trace, the next one to drop.
+    #[pallet::storage]
+    pub type OldestTraceId<T> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Traces<T: Config> = StorageMap<_, Twox64Concat, u64, MessageTraceOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type TopicTraces<T> =
+        StorageDoubleMap<_, Blake2_128Concat, XcmHash, Twox64Concat, u64, (), OptionQuery>;
+
+    #[pallet::storage]
+    pub type AccountTraces<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u64, (), OptionQuery>;
+
+    /// The trace of the inbound message being processed. It never outlives the processing of the
+    /// message.
+    #[pallet::storage]
+    #[pallet::whitelist_storage]
+    pub type Pending<T> = StorageValue<_, PendingTrace, OptionQuery>;
+
+    impl<T: Config> Pallet<T> {
+        /// Records `trace`, dropping the oldest ones, up to `MAX_DROPPED_TRACES`, while
+        /// `MaxTraces` traces or more are kept.
+        pub fn record(trace: MessageTraceOf<T>) {
+            let id = NextTraceId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            let max_traces = u64::from(T::MaxTraces::get());
+            let mut oldest = OldestTraceId::<T>::get();
+            for _ in 0..MAX_DROPPED_TRACES {
+                let kept = id.saturating_sub(oldest);
+                if kept == 0 || kept < max_traces {
+                    break;
+                }
+                if let Some(oldest_trace) = Traces::<T>::take(oldest) {
+                    TopicTraces::<T>::remove(oldest_trace.topic, oldest);
+                    if let Some(account) = oldest_trace.account {
+                        AccountTraces::<T>::remove(account, oldest);
+                    }
+                }
+                oldest = oldest.saturating_add(1);
+            }
+            OldestTraceId::<T>::put(oldest);
+            TopicTraces::<T>::insert(trace.topic, id, ());
+            if let Some(account) = &trace.account {
+                AccountTraces::<T>::insert(account, id, ());
+            }
+            Traces::<T>::insert(id, trace);
+        }
+
+        /// The kept traces of the messages with `topic`, oldest first.
+        pub fn traces_by_topic(topic: XcmHash) -> Vec<MessageTraceOf<T>> {
+            Self::traces(TopicTraces::<T>::iter_key_prefix(topic))
+        }
+
+        /// The kept traces of the inbound messages with the origin of `account`, oldest first.
+        pub fn traces_by_account(account: T::AccountId) -> Vec<MessageTraceOf<T>> {
+            Self::traces(AccountTraces::<T>::iter_key_prefix(account))
+        }
+
+        fn traces(ids: impl Iterator<Item = u64>) -> Vec<MessageTraceOf<T>> {
+            let mut ids = ids.collect::<Vec<_>>();
+            ids.sort_unstable();
+            ids.into_iter().filter_map(Traces::<T>::get).collect()
+        }
+
+        pub(crate) fn account_of(origin: &Location) -> Option<T::AccountId> {
+            T::LocationToAccountId::convert_location(origin)
+        }
+    }
+}
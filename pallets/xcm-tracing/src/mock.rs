@@ -0,0 +1,137 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_xcm_tracing;
+use crate::tracers::TracingRecorder;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ProcessMessage, ProcessMessageError},
+    weights::{Weight, WeightMeter},
+};
+use parity_scale_codec::Decode;
+use sp_runtime::BuildStorage;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{ConvertLocation, RecordXcm};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type PalletXcmTracing = pallet_xcm_tracing::Pallet<Test>;
+}
+
+// System pallet configuration
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+/// Converts the locations ending with an `AccountIndex64` to that index.
+pub struct AccountIndexToAccountId;
+impl ConvertLocation<u64> for AccountIndexToAccountId {
+    fn convert_location(location: &Location) -> Option<u64> {
+        match location.last() {
+            Some(AccountIndex64 { index, .. }) => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+impl pallet_xcm_tracing::Config for Test {
+    type LocationToAccountId = AccountIndexToAccountId;
+    type MaxTraces = MaxTraces;
+    type WeightInfo = ();
+}
+
+pub const TOPIC: XcmHash = [7; 32];
+pub const MESSAGE_WEIGHT: Weight = Weight::from_parts(1_000, 100);
+pub const DELIVERY_FEE: u128 = 10;
+
+parameter_types! {
+    pub static MaxTraces: u32 = 3;
+    pub static ProcessResult: Result<bool, ProcessMessageError> = Ok(true);
+    pub static DeliverResult: Result<XcmHash, SendError> = Ok(TOPIC);
+}
+
+/// Processes the messages as the XCM executor does with `TracingRecorder`: it records them and
+/// uses `MESSAGE_WEIGHT`, and sets the message id to `TOPIC`.
+pub struct MockMessageProcessor;
+impl ProcessMessage for MockMessageProcessor {
+    type Origin = Location;
+
+    fn process_message(
+        mut message: &[u8],
+        _origin: Self::Origin,
+        meter: &mut WeightMeter,
+        id: &mut [u8; 32],
+    ) -> Result<bool, ProcessMessageError> {
+        let xcm = Xcm::<()>::decode(&mut message).map_err(|_| ProcessMessageError::Corrupt)?;
+        if TracingRecorder::<Test, ()>::should_record() {
+            TracingRecorder::<Test, ()>::record(xcm);
+        }
+        if !meter.can_consume(MESSAGE_WEIGHT) {
+            return Err(ProcessMessageError::Overweight(MESSAGE_WEIGHT));
+        }
+        meter.consume(MESSAGE_WEIGHT);
+        *id = TOPIC;
+        ProcessResult::get()
+    }
+}
+
+/// Charges `DELIVERY_FEE` of the relay chain token to deliver the messages.
+pub struct MockRouter;
+impl SendXcm for MockRouter {
+    type Ticket = ();
+
+    fn validate(
+        destination: &mut Option<Location>,
+        _message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        destination.take().ok_or(SendError::MissingArgument)?;
+        Ok(((), (Parent, DELIVERY_FEE).into()))
+    }
+
+    fn deliver(_ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        DeliverResult::get()
+    }
+}
+
+// Test externalities initialization
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap()
+        .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,323 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use crate::tracers::{TracedMessageProcessor, TracedRouter, TracingRecorder};
+use alloc::collections::BTreeSet;
+use frame_support::{
+    assert_ok,
+    dispatch::DispatchClass,
+    traits::{ProcessMessage, ProcessMessageError},
+    weights::WeightMeter,
+};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::RecordXcm;
+
+const ACCOUNT: u64 = 42;
+const FEES: u128 = 1_000;
+
+fn sibling() -> Location {
+    Location::new(1, [Parachain(2000)])
+}
+
+fn sibling_account() -> Location {
+    Location::new(
+        1,
+        [
+            Parachain(2000),
+            AccountIndex64 {
+                network: None,
+                index: ACCOUNT,
+            },
+        ],
+    )
+}
+
+fn trace(topic: XcmHash, account: Option<u64>) -> MessageTraceOf<Test> {
+    MessageTrace {
+        topic,
+        direction: Direction::Inbound,
+        block_number: 1,
+        location: sibling_account(),
+        account,
+        outcome: TraceOutcome::Complete,
+        weight_used: MESSAGE_WEIGHT,
+        fees: None,
+    }
+}
+
+mod record {
+    use super::*;
+
+    #[test]
+    fn keeps_traces_by_topic_and_account() {
+        new_test_ext().execute_with(|| {
+            PalletXcmTracing::record(trace([1; 32], Some(ACCOUNT)));
+            PalletXcmTracing::record(trace([2; 32], None));
+            PalletXcmTracing::record(trace([1; 32], None));
+
+            assert_eq!(
+                PalletXcmTracing::traces_by_topic([1; 32]),
+                vec![trace([1; 32], Some(ACCOUNT)), trace([1; 32], None)]
+            );
+            assert_eq!(
+                PalletXcmTracing::traces_by_topic([2; 32]),
+                vec![trace([2; 32], None)]
+            );
+            assert_eq!(
+                PalletXcmTracing::traces_by_account(ACCOUNT),
+                vec![trace([1; 32], Some(ACCOUNT))]
+            );
+        });
+    }
+
+    #[test]
+    fn drops_oldest_trace_when_window_is_full() {
+        new_test_ext().execute_with(|| {
+            PalletXcmTracing::record(trace([1; 32], Some(ACCOUNT)));
+            for topic in 2..=4 {
+                PalletXcmTracing::record(trace([topic; 32], None));
+            }
+
+            assert!(Traces::<Test>::get(0).is_none());
+            assert_eq!(Traces::<Test>::iter().count(), 3);
+            assert!(PalletXcmTracing::traces_by_topic([1; 32]).is_empty());
+            assert!(PalletXcmTracing::traces_by_account(ACCOUNT).is_empty());
+            assert_eq!(
+                PalletXcmTracing::traces_by_topic([4; 32]),
+                vec![trace([4; 32], None)]
+            );
+        });
+    }
+
+    #[test]
+    fn drops_traces_until_window_is_back_to_max_traces() {
+        new_test_ext().execute_with(|| {
+            for topic in 1..=3 {
+                PalletXcmTracing::record(trace([topic; 32], None));
+            }
+            MaxTraces::set(1);
+
+            PalletXcmTracing::record(trace([4; 32], None));
+            assert_eq!(
+                Traces::<Test>::iter_keys().collect::<BTreeSet<_>>(),
+                [2, 3].into()
+            );
+
+            PalletXcmTracing::record(trace([5; 32], None));
+            assert_eq!(
+                Traces::<Test>::iter_keys().collect::<BTreeSet<_>>(),
+                [4].into()
+            );
+            assert_eq!(OldestTraceId::<Test>::get(), 4);
+            assert!(PalletXcmTracing::traces_by_topic([3; 32]).is_empty());
+        });
+    }
+}
+
+mod traced_message_processor {
+    use super::*;
+
+    fn message() -> Xcm<()> {
+        Xcm(vec![
+            DescendOrigin(
+                AccountIndex64 {
+                    network: None,
+                    index: ACCOUNT,
+                }
+                .into(),
+            ),
+            WithdrawAsset((Parent, FEES).into()),
+            BuyExecution {
+                fees: (Parent, FEES).into(),
+                weight_limit: Unlimited,
+            },
+            ClearOrigin,
+        ])
+    }
+
+    fn process(meter: &mut WeightMeter) -> Result<bool, ProcessMessageError> {
+        let mut id = [0; 32];
+        TracedMessageProcessor::<Test, MockMessageProcessor>::process_message(
+            &message().encode(),
+            sibling(),
+            meter,
+            &mut id,
+        )
+    }
+
+    fn expected_trace(outcome: TraceOutcome) -> MessageTraceOf<Test> {
+        MessageTrace {
+            topic: TOPIC,
+            direction: Direction::Inbound,
+            block_number: 1,
+            location: sibling_account(),
+            account: Some(ACCOUNT),
+            outcome,
+            weight_used: MESSAGE_WEIGHT,
+            fees: Some((Location::parent(), FEES)),
+        }
+    }
+
+    #[test]
+    fn traces_complete_message() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(process(&mut WeightMeter::new()), Ok(true));
+
+            assert_eq!(
+                PalletXcmTracing::traces_by_topic(TOPIC),
+                vec![expected_trace(TraceOutcome::Complete)]
+            );
+            assert_eq!(
+                PalletXcmTracing::traces_by_account(ACCOUNT),
+                vec![expected_trace(TraceOutcome::Complete)]
+            );
+            assert!(Pending::<Test>::get().is_none());
+        });
+    }
+
+    #[test]
+    fn traces_incomplete_message() {
+        new_test_ext().execute_with(|| {
+            ProcessResult::set(Ok(false));
+
+            assert_eq!(process(&mut WeightMeter::new()), Ok(false));
+
+            assert_eq!(
+                PalletXcmTracing::traces_by_topic(TOPIC),
+                vec![expected_trace(TraceOutcome::Incomplete)]
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_trace_failed_message() {
+        new_test_ext().execute_with(|| {
+            ProcessResult::set(Err(ProcessMessageError::Unsupported));
+
+            assert_eq!(
+                process(&mut WeightMeter::new()),
+                Err(ProcessMessageError::Unsupported)
+            );
+
+            assert!(PalletXcmTracing::traces_by_topic(TOPIC).is_empty());
+            assert!(Pending::<Test>::get().is_none());
+        });
+    }
+
+    #[test]
+    fn charges_message_and_trace_weight() {
+        new_test_ext().execute_with(|| {
+            let mut meter = WeightMeter::new();
+
+            assert_ok!(process(&mut meter));
+
+            assert_eq!(
+                meter.consumed(),
+                MESSAGE_WEIGHT + <() as WeightInfo>::record_trace()
+            );
+        });
+    }
+
+    #[test]
+    fn reserves_trace_weight() {
+        new_test_ext().execute_with(|| {
+            let trace_weight = <() as WeightInfo>::record_trace();
+            let mut meter =
+                WeightMeter::with_limit(MESSAGE_WEIGHT + trace_weight - Weight::from_parts(1, 0));
+
+            assert_eq!(
+                process(&mut meter),
+                Err(ProcessMessageError::Overweight(
+                    MESSAGE_WEIGHT + trace_weight
+                ))
+            );
+
+            assert_eq!(meter.consumed(), Weight::zero());
+            assert!(PalletXcmTracing::traces_by_topic(TOPIC).is_empty());
+        });
+    }
+}
+
+mod tracing_recorder {
+    use super::*;
+
+    #[test]
+    fn records_only_while_processing_messages() {
+        new_test_ext().execute_with(|| {
+            assert!(!TracingRecorder::<Test, ()>::should_record());
+
+            TracingRecorder::<Test, ()>::record(Xcm(vec![ClearOrigin]));
+
+            assert!(Pending::<Test>::get().is_none());
+        });
+    }
+}
+
+mod traced_router {
+    use super::*;
+
+    fn send() -> Result<XcmHash, SendError> {
+        send_xcm::<TracedRouter<Test, MockRouter>>(sibling(), Xcm(vec![ClearOrigin]))
+            .map(|(topic, _)| topic)
+    }
+
+    #[test]
+    fn traces_sent_message() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(send(), Ok(TOPIC));
+
+            assert_eq!(
+                PalletXcmTracing::traces_by_topic(TOPIC),
+                vec![MessageTrace {
+                    topic: TOPIC,
+                    direction: Direction::Outbound,
+                    block_number: 1,
+                    location: sibling(),
+                    account: None,
+                    outcome: TraceOutcome::Sent,
+                    weight_used: Weight::zero(),
+                    fees: Some((Location::parent(), DELIVERY_FEE)),
+                }]
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_trace_undelivered_message() {
+        new_test_ext().execute_with(|| {
+            DeliverResult::set(Err(SendError::Transport("closed")));
+
+            assert_eq!(send(), Err(SendError::Transport("closed")));
+
+            assert!(PalletXcmTracing::traces_by_topic(TOPIC).is_empty());
+            assert_eq!(mandatory_weight(), Weight::zero());
+        });
+    }
+
+    #[test]
+    fn registers_trace_weight() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(send(), Ok(TOPIC));
+
+            assert_eq!(mandatory_weight(), <() as WeightInfo>::record_trace());
+        });
+    }
+
+    fn mandatory_weight() -> Weight {
+        *frame_system::Pallet::<Test>::block_weight().get(DispatchClass::Mandatory)
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The wrappers of the XCM configuration which trace the messages.
+
+use crate::{
+    Config, Direction, MessageTrace, Pallet, Pending, PendingTrace, TraceOutcome, WeightInfo,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::DispatchClass,
+    traits::{ProcessMessage, ProcessMessageError},
+    weights::{Weight, WeightMeter},
+};
+use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm};
+use xcm_builder::InspectMessageQueues;
+use xcm_executor::traits::RecordXcm;
+
+/// Traces the messages processed by `P`. The weight of recording the trace is reserved before
+/// processing the message, so that it is always paid for. The messages which fail to be processed
+/// are not traced: `MessageQueue` reverts their processing and reports them with
+/// `ProcessingFailed`.
+pub struct TracedMessageProcessor<T, P>(PhantomData<(T, P)>);
+impl<T, P> ProcessMessage for TracedMessageProcessor<T, P>
+where
+    T: Config,
+    P: ProcessMessage,
+    P::Origin: Into<Location>,
+{
+    type Origin = P::Origin;
+
+    fn process_message(
+        message: &[u8],
+        origin: Self::Origin,
+        meter: &mut WeightMeter,
+        id: &mut [u8; 32],
+    ) -> Result<bool, ProcessMessageError> {
+        let trace_weight = T::WeightInfo::record_trace();
+        if !meter.can_consume(trace_weight) {
+            return Err(ProcessMessageError::Overweight(trace_weight));
+        }
+        let mut message_meter =
+            WeightMeter::with_limit(meter.remaining().saturating_sub(trace_weight));
+
+        Pending::<T>::put(PendingTrace {
+            origin: origin.clone().into(),
+            fees: None,
+        });
+        let result = P::process_message(message, origin, &mut message_meter, id);
+        let pending = Pending::<T>::take();
+        meter.consume(message_meter.consumed());
+
+        let outcome = match result {
+            Ok(true) => TraceOutcome::Complete,
+            Ok(false) => TraceOutcome::Incomplete,
+            Err(ProcessMessageError::Overweight(required)) => {
+                return Err(ProcessMessageError::Overweight(
+                    required.saturating_add(trace_weight),
+                ))
+            }
+            Err(_) => return result,
+        };
+        if let Some(PendingTrace { origin, fees }) = pending {
+            meter.consume(trace_weight);
+            Pallet::<T>::record(MessageTrace {
+                topic: *id,
+                direction: Direction::Inbound,
+                block_number: frame_system::Pallet::<T>::block_number(),
+                account: Pallet::<T>::account_of(&origin),
+                location: origin,
+                outcome,
+                weight_used: message_meter.consumed(),
+                fees,
+            });
+        }
+        result
+    }
+}
+
+/// Records, through the executor, the origin and the fees of the inbound message being processed
+/// by [`TracedMessageProcessor`], and otherwise records as `R`.
+pub struct TracingRecorder<T, R>(PhantomData<(T, R)>);
+impl<T: Config, R: RecordXcm> RecordXcm for TracingRecorder<T, R> {
+    fn should_record() -> bool {
+        R::should_record() || Pending::<T>::exists()
+    }
+
+    fn set_record_xcm(enabled: bool) {
+        R::set_record_xcm(enabled)
+    }
+
+    fn recorded_xcm() -> Option<Xcm<()>> {
+        R::recorded_xcm()
+    }
+
+    fn record(xcm: Xcm<()>) {
+        Pending::<T>::mutate(|pending| {
+            if let Some(pending) = pending {
+                trace_message(pending, &xcm);
+            }
+        });
+        if R::should_record() {
+            R::record(xcm)
+        }
+    }
+}
+
+fn trace_message(pending: &mut PendingTrace, xcm: &Xcm<()>) {
+    let mut instructions = xcm.inner().iter().peekable();
+    while let Some(DescendOrigin(interior)) = instructions.peek() {
+        // An origin too long to descend fails the execution of the message
+        let _ = pending.origin.append_with(interior.clone());
+        instructions.next();
+    }
+    pending.fees = instructions.find_map(|instruction| match instruction {
+        BuyExecution {
+            fees:
+                Asset {
+                    id: AssetId(location),
+                    fun: Fungible(amount),
+                },
+            ..
+        }
+        | PayFees {
+            asset:
+                Asset {
+                    id: AssetId(location),
+                    fun: Fungible(amount),
+                },
+        } => Some((location.clone(), *amount)),
+        _ => None,
+    });
+}
+
+/// Traces the messages sent through `R`. It must wrap the router which sets the topic of the
+/// messages, so that the traces have the topic of the delivered messages. The messages are sent
+/// from many places which do not know about the trace, so the weight of recording it is registered
+/// in the block when the message is delivered.
+pub struct TracedRouter<T, R>(PhantomData<(T, R)>);
+impl<T: Config, R: SendXcm> SendXcm for TracedRouter<T, R> {
+    type Ticket = (Location, Option<(Location, u128)>, R::Ticket);
+
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let location = destination.clone().ok_or(SendError::MissingArgument)?;
+        let (ticket, price) = R::validate(destination, message)?;
+        let fees = price.inner().iter().find_map(|asset| match asset {
+            Asset {
+                id: AssetId(location),
+                fun: Fungible(amount),
+            } => Some((location.clone(), *amount)),
+            _ => None,
+        });
+        Ok(((location, fees, ticket), price))
+    }
+
+    fn deliver((location, fees, ticket): Self::Ticket) -> Result<XcmHash, SendError> {
+        let topic = R::deliver(ticket)?;
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            T::WeightInfo::record_trace(),
+            DispatchClass::Mandatory,
+        );
+        Pallet::<T>::record(MessageTrace {
+            topic,
+            direction: Direction::Outbound,
+            block_number: frame_system::Pallet::<T>::block_number(),
+            location,
+            account: None,
+            outcome: TraceOutcome::Sent,
+            weight_used: Weight::zero(),
+            fees,
+        });
+        Ok(topic)
+    }
+}
+
+impl<T, R: InspectMessageQueues> InspectMessageQueues for TracedRouter<T, R> {
+    fn clear_messages() {
+        R::clear_messages()
+    }
+
+    fn get_messages() -> Vec<(VersionedLocation, Vec<VersionedXcm<()>>)> {
+        R::get_messages()
+    }
+}
@@ -0,0 +1,41 @@
+
+//! Hand-written weights for `pallet_xcm_tracing`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_xcm_tracing`.
+pub trait WeightInfo {
+    fn record_trace() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `XcmTracing::NextTraceId` (r:1 w:1)
+    /// Proof: `XcmTracing::NextTraceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::OldestTraceId` (r:1 w:1)
+    /// Proof: `XcmTracing::OldestTraceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::Traces` (r:2 w:3)
+    /// Proof: `XcmTracing::Traces` (`max_values`: None, `max_size`: Some(1334), added: 3809, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::TopicTraces` (r:0 w:3)
+    /// Proof: `XcmTracing::TopicTraces` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::AccountTraces` (r:0 w:3)
+    /// Proof: `XcmTracing::AccountTraces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkedAccounts` (r:1 w:0)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn record_trace() -> Weight {
+        Weight::from_parts(0, 12157)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+}
\ No newline at end of file
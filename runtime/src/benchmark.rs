@@ -33,6 +33,7 @@ frame_benchmarking::define_benchmarks!(
     [pallet_transaction_payment, TransactionPayment]
 
     [pallet_balances, Balances]
+    [pallet_assets, ForeignAssets]
 
     [pallet_sudo, Sudo]
 
@@ -45,10 +46,21 @@ frame_benchmarking::define_benchmarks!(
 
     [pallet_evm, EVM]
     [pallet_deployment_permissions, DeploymentPermissions]
+    [pallet_precompile_kill_switch, PrecompileKillSwitch]
+    [pallet_aggregation_roots, AggregationRoots]
+    [pallet_relay_randomness, RelayRandomness]
+    [pallet_relay_storage_roots, RelayStorageRoots]
+    [pallet_trusted_siblings, TrustedSiblings]
+    [pallet_asset_registry, AssetRegistry]
+    [pallet_account_mapping, AccountMapping]
+    [pallet_trusted_aliasers, TrustedAliasers]
+    [pallet_xcm_safe_calls, XcmSafeCalls]
+    [pallet_xcm_tracing, XcmTracing]
 
     [pallet_deployment_permissions, DeploymentPermissions]
     [pallet_xcm_benchmarks::generic, xcm::XcmPalletBenchGeneric::<Runtime>]
     [pallet_xcm_benchmarks::fungible, xcm::XcmPalletBenchFungible::<Runtime>]
+    [pallet_xcm_benchmarks::fungible, xcm::XcmPalletBenchForeignFungible::<Runtime>]
 );
 
 /// Trait for setting up any prerequisites for successful execution of benchmarks.
@@ -18,10 +18,12 @@
 use crate::{
     configs::system::{ProxyType, ReservedXcmpWeight},
     types::AccountId,
-    BlockNumber, Runtime, RuntimeEvent,
+    BlockNumber, DeploymentPermissions, Runtime, RuntimeEvent, RuntimeOrigin,
 };
-use frame_support::ensure;
+use frame_support::{ensure, traits::EnsureOrigin};
 use frame_system::EnsureRoot;
+use pallet_deployment_permissions::EnsureCreateOrigin;
+use sp_core::H160;
 use sp_runtime::traits::Zero;
 
 pub struct EthereumXcmEnsureProxy;
@@ -41,6 +43,29 @@ impl xcm_primitives::EnsureProxy<AccountId> for EthereumXcmEnsureProxy {
     }
 }
 
+/// Accepts the `EthereumXcm` transactions of the accounts having deploy permission in
+/// `DeploymentPermissions`: `RemoteEVMCall` dispatches their creations, which `transact` rejects,
+/// as `force_transact_as` of the same account and without a forced contract address.
+pub struct EnsureXcmDeployer;
+impl EnsureOrigin<RuntimeOrigin> for EnsureXcmDeployer {
+    type Success = H160;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<H160, RuntimeOrigin> {
+        let address = pallet_ethereum_xcm::EnsureXcmEthereumTransaction::try_origin(o.clone())?;
+        match DeploymentPermissions::check_create_origin(&address) {
+            Ok(()) => Ok(address),
+            Err(_) => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        let address = H160::repeat_byte(1);
+        pallet_deployment_permissions::Deployers::<Runtime>::insert(address, ());
+        Ok(pallet_ethereum_xcm::Origin::XcmEthereumTransaction(address).into())
+    }
+}
+
 impl pallet_ethereum_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
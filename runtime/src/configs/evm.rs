@@ -18,8 +18,8 @@
 use crate::{
     constants::{MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, WEIGHT_PER_GAS},
     opaque, weights, AccountId, Aura, Balances, CollatorSelection, DeploymentPermissions,
-    EVMChainId, Precompiles, Runtime, RuntimeEvent, Timestamp, TransactionPayment,
-    UncheckedExtrinsic,
+    EVMChainId, Precompiles, Runtime, RuntimeEvent, StaticPrecompiles, Timestamp,
+    TransactionPayment, UncheckedExtrinsic,
 };
 use fp_evm::FeeCalculator;
 use frame_support::{
@@ -52,7 +52,7 @@ parameter_types! {
     pub BlockGasLimit: U256 = U256::from(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT.ref_time() / WEIGHT_PER_GAS);
     pub GasLimitPovSizeRatio: u64 = BlockGasLimit::get().as_u64().saturating_div(cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64);
     pub GasLimitStorageGrowthRatio: u64 = 0; // Disabled
-    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::<_>::new();
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::<_>::new(StaticPrecompiles::<_>::new());
     pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
     pub StakingPot: AccountId = CollatorSelection::account_id();
 }
@@ -62,6 +62,13 @@ impl pallet_deployment_permissions::Config for Runtime {
     type WeightInfo = weights::pallet_deployment_permissions::ZKVEvmWeight<Self>;
 }
 
+impl pallet_precompile_kill_switch::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    // The weights of the pallet assume 128 disabled precompiles at most
+    type MaxDisabledPrecompiles = ConstU32<128>;
+    type WeightInfo = weights::pallet_precompile_kill_switch::ZKVEvmWeight<Self>;
+}
+
 type BaseRunner<T> = pallet_evm::runner::stack::Runner<T>;
 type PermissionedRunner<T> = pallet_deployment_permissions::runner::PermissionedDeploy<
     T,
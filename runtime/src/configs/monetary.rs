@@ -17,10 +17,16 @@
 //! of pallets used for currency management.
 
 use crate::{
-    constants::currency::EXISTENTIAL_DEPOSIT, weights, AccountId, Balance, Balances,
-    CollatorSelection, Runtime, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, System,
+    constants::currency::{deposit, EXISTENTIAL_DEPOSIT},
+    types::AssetId,
+    weights, AccountId, Balance, Balances, CollatorSelection, Runtime, RuntimeEvent,
+    RuntimeFreezeReason, RuntimeHoldReason, System,
 };
-use frame_support::{parameter_types, traits::tokens::imbalance::ResolveTo};
+use frame_support::{
+    parameter_types,
+    traits::{tokens::imbalance::ResolveTo, AsEnsureOriginWithArg, ConstU32},
+};
+use frame_system::{EnsureNever, EnsureRoot};
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use sp_runtime::{traits::One, FixedPointNumber, Perquintill};
 use sp_weights::ConstantMultiplier;
@@ -170,3 +176,42 @@ impl pallet_transaction_payment::Config for Runtime {
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightInfo = weights::pallet_transaction_payment::ZKVEvmWeight<Runtime>;
 }
+
+parameter_types! {
+    pub const AssetsStringLimit: u32 = 50;
+    pub const AssetDeposit: Balance = deposit(1, 190);
+    pub const AssetAccountDeposit: Balance = deposit(1, 16);
+    pub const MetadataDepositBase: Balance = deposit(1, 68);
+    pub const MetadataDepositPerByte: Balance = deposit(0, 1);
+    pub const ApprovalDeposit: Balance = deposit(1, 32);
+}
+
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type AssetIdParameter = AssetId;
+    type Currency = Balances;
+    // Foreign assets are only created by governance through `ForceOrigin`, which takes no
+    // deposit: the deposits below are paid by the accounts touching or approving the assets.
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+    // The `create` benchmark needs an origin able to create assets.
+    #[cfg(feature = "runtime-benchmarks")]
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    // The reference weights of the upstream benchmarks, until the pallet is benchmarked here.
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
@@ -18,26 +18,28 @@
 
 use crate::{
     constants::{
-        currency::deposit, AVERAGE_ON_INITIALIZE_RATIO, MAXIMUM_BLOCK_WEIGHT, MAX_BLOCK_LENGTH,
-        NORMAL_DISPATCH_RATIO, SLOT_DURATION,
+        currency::deposit, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT,
+        MAX_BLOCK_LENGTH, NORMAL_DISPATCH_RATIO, SLOT_DURATION,
     },
-    types::{ConsensusHook, Hash},
+    types::{BlockNumber, ConsensusHook, Hash},
     weights,
     weights::ExtrinsicBaseWeight,
     AccountId, Aura, Balance, Balances, Block, MessageQueue, Nonce, OriginCaller, PalletInfo,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, RuntimeTask, XcmpQueue, VERSION,
+    ParachainInfo, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, RuntimeTask, XcmpQueue,
+    VERSION,
 };
 use core::fmt::Debug;
 use cumulus_pallet_parachain_system::{
-    DefaultCoreSelector, ParachainSetCode, RelayNumberMonotonicallyIncreases,
+    DefaultCoreSelector, ParachainSetCode, RelayChainStateProof, RelayNumberMonotonicallyIncreases,
+    RelaychainDataProvider,
 };
-use cumulus_primitives_core::AggregateMessageOrigin;
+use cumulus_primitives_core::{relay_chain::well_known_keys, AggregateMessageOrigin};
 use frame_support::{
     derive_impl,
     dispatch::DispatchClass,
     pallet_prelude::{ConstU32, Decode, Encode, MaxEncodedLen, TypeInfo},
     parameter_types,
-    traits::{ConstU64, Contains, InstanceFilter},
+    traits::{ConstU64, Contains, Get, InstanceFilter},
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use polkadot_runtime_common::BlockHashCount;
@@ -168,6 +170,83 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 
 impl parachain_info::Config for Runtime {}
 
+/// The BABE randomness of the relay parent block, read from the relay state proof of the
+/// current block.
+pub struct RelayBlockRandomness;
+impl Get<Option<Hash>> for RelayBlockRandomness {
+    fn get() -> Option<Hash> {
+        let validation_data = cumulus_pallet_parachain_system::ValidationData::<Runtime>::get()?;
+        let proof = cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::get()?;
+        RelayChainStateProof::new(
+            ParachainInfo::parachain_id(),
+            validation_data.relay_parent_storage_root,
+            proof,
+        )
+        .ok()?
+        .read_optional_entry::<Option<Hash>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+        .ok()
+        .flatten()
+        .flatten()
+    }
+}
+
+/// Makes `RelayBlockRandomness` read the given randomness, storing the validation data and a
+/// relay state proof holding it as the validation data inherent does.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RelayRandomnessBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_relay_randomness::BenchmarkHelper for RelayRandomnessBenchmarkHelper {
+    fn set_relay_randomness(randomness: Hash) {
+        let (relay_parent_storage_root, proof) =
+            cumulus_test_relay_sproof_builder::RelayStateSproofBuilder {
+                para_id: ParachainInfo::parachain_id(),
+                additional_key_values: vec![(
+                    well_known_keys::CURRENT_BLOCK_RANDOMNESS.to_vec(),
+                    Some(randomness).encode(),
+                )],
+                ..Default::default()
+            }
+            .into_state_root_and_proof();
+
+        cumulus_pallet_parachain_system::ValidationData::<Runtime>::put(
+            cumulus_primitives_core::PersistedValidationData {
+                relay_parent_storage_root,
+                ..Default::default()
+            },
+        );
+        cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::put(proof);
+    }
+}
+
+parameter_types! {
+    // One storage item; key size is 16; value is size 20+32+4+16 bytes = 72 bytes.
+    pub const RandomnessRequestDeposit: Balance = deposit(1, 88);
+    pub const MinRandomnessBlockDelay: BlockNumber = 2;
+    pub const MaxRandomnessBlockDelay: BlockNumber = 2 * HOURS;
+    pub const RandomnessExpirationDelay: BlockNumber = DAYS;
+}
+
+impl pallet_relay_randomness::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RequestDeposit = RandomnessRequestDeposit;
+    type MinBlockDelay = MinRandomnessBlockDelay;
+    type MaxBlockDelay = MaxRandomnessBlockDelay;
+    type ExpirationDelay = RandomnessExpirationDelay;
+    type RelayRandomness = RelayBlockRandomness;
+    type WeightInfo = weights::pallet_relay_randomness::ZKVEvmWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RelayRandomnessBenchmarkHelper;
+}
+
+impl pallet_relay_storage_roots::Config for Runtime {
+    type RelaychainStateProvider = RelaychainDataProvider<Runtime>;
+    // About three minutes of relay chain blocks.
+    type MaxStorageRoots = ConstU32<30>;
+    type WeightInfo = weights::pallet_relay_storage_roots::ZKVEvmWeight<Runtime>;
+}
+
 parameter_types! {
     // One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
     pub const DepositBase: Balance = deposit(1, 88);
@@ -239,7 +318,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
     fn filter(&self, c: &RuntimeCall) -> bool {
         match self {
             ProxyType::Any => true,
-            ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances { .. }),
+            ProxyType::NonTransfer => !matches!(
+                c,
+                RuntimeCall::Balances { .. } | RuntimeCall::ForeignAssets { .. }
+            ),
             ProxyType::CancelProxy => matches!(
                 c,
                 RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. })
@@ -16,47 +16,63 @@
 //! In this module, we provide the configurations about xcm subsystem.
 
 use crate::{
+    configs::ethereum_xcm::EnsureXcmDeployer,
     configs::monetary::TransactionByteFee,
     configs::system::RuntimeBlockWeights,
     constants::currency::{CENTS, MILLIS},
-    types::AccountId,
-    weights, AllPalletsWithSystem, Balances, MessageQueue, ParachainInfo, ParachainSystem, Perbill,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, XcmpQueue, ZKVXcm,
+    types::{AccountId, AssetId as LocalAssetId, Balance, Signature},
+    weights, AccountMapping, AggregationRoots, AllPalletsWithSystem, AssetRegistry, Balances,
+    EthereumXcm, ForeignAssets, MessageQueue, ParachainInfo, ParachainSystem, Perbill, Runtime,
+    RuntimeCall, RuntimeEvent, RuntimeOrigin, System, TrustedAliasers, TrustedSiblings,
+    XcmSafeCalls, XcmpQueue, ZKVXcm,
 };
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
 use frame_support::{
     pallet_prelude::Get,
     parameter_types,
+    traits::fungibles,
     traits::tokens::imbalance::ResolveTo,
     traits::OriginTrait,
     traits::TransformOrigin,
-    traits::{ConstU32, Contains, Equals, Everything, Nothing, PalletInfoAccess},
-    weights::Weight,
+    traits::{
+        ConstU32, Contains, ContainsPair, EitherOfDiverse, EnsureOrigin, Equals, Everything,
+        GetCallIndex, Nothing, PalletInfoAccess,
+    },
+    weights::{Weight, WeightToFee},
 };
 use frame_system::EnsureRoot;
-use pallet_xcm::XcmPassthrough;
+use pallet_ethereum::TransactionAction;
+use pallet_xcm::{EnsureXcm, XcmPassthrough};
+use pallet_xcm_safe_calls::CallIndex;
+#[cfg(not(feature = "runtime-benchmarks"))]
+use pallet_xcm_tracing::tracers::TracedMessageProcessor;
+use pallet_xcm_tracing::tracers::{TracedRouter, TracingRecorder};
 use parachains_common::{
     message_queue::{NarrowOriginToSibling, ParaIdToSibling},
     xcm_config::ConcreteAssetFromSystem,
 };
+use sp_core::H160;
 use sp_runtime::{
-    traits::{PostDispatchInfoOf, TryConvert},
-    DispatchErrorWithPostInfo,
+    traits::{Convert, PostDispatchInfoOf, TryConvert, Verify},
+    DispatchErrorWithPostInfo, FixedPointNumber, FixedU128,
 };
+use sp_std::vec::Vec;
 use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountKey20Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-    AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain, DenyThenTry,
-    DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FrameTransactionalProcessor,
-    FungibleAdapter, HashedDescription, IsConcrete, NativeAsset, ParentIsPreset,
-    RelayChainAsNative, SendXcmFeeToAccount, SiblingParachainAsNative, SignedAccountKey20AsNative,
-    SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-    WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
+    AllowTopLevelPaidExecutionFrom, ConvertedConcreteId, DenyReserveTransferToRelayChain,
+    DenyThenTry, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FrameTransactionalProcessor,
+    FungibleAdapter, FungiblesAdapter, HashedDescription, IsConcrete, NativeAsset, NoChecking,
+    ParentIsPreset, RelayChainAsNative, SendXcmFeeToAccount, SiblingParachainAsNative,
+    SignedAccountKey20AsNative, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId,
+    UsingComponents, WeightInfoBounds, WithComputedOrigin, WithUniqueTopic,
+    XcmFeeManagerFromComponents,
 };
 use xcm_executor::{
-    traits::{CallDispatcher, ConvertLocation},
-    XcmExecutor,
+    traits::{CallDispatcher, ConvertLocation, JustTry, WeightTrader},
+    AssetsInHolding, XcmExecutor,
 };
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV1, EthereumXcmTransactionV2};
 
 use crate::weights::pallet_xcm_benchmarks::ZKVEvmWeight as XcmZKVEvmWeight;
 
@@ -70,6 +86,7 @@ parameter_types! {
     pub BalancesPalletLocation: Location = PalletInstance(<Balances as PalletInfoAccess>::index() as u8).into();
     pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
     pub UniversalLocation: InteriorLocation = [GlobalConsensus(RelayNetwork::get().unwrap()), Parachain(ParachainInfo::parachain_id().into())].into();
+    pub CheckingAccount: AccountId = ZKVXcm::check_account();
 }
 
 /// Type for specifying how a `Location` can be converted into an
@@ -85,27 +102,43 @@ pub type LocationToAccountId = (
     HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
+/// Converts the `AccountId32` locations, both of this chain and of the relay chain, to the account
+/// linked to that relay chain account in `AccountMapping` or, when there is none, to its
+/// sovereign account. Any other location is converted through `LocationToAccountId`.
+///
+/// The `AccountId32` locations of this chain used to be converted to the last 20 bytes of their
+/// identifier, see [`TruncatedAccountId32`]: once linked, the account of this chain recovers the
+/// balance left there with `AccountMapping::claim_legacy_account`.
 pub struct LocationAccountId32ToAccountId;
 impl ConvertLocation<AccountId> for LocationAccountId32ToAccountId {
     fn convert_location(location: &Location) -> Option<AccountId> {
-        use xcm::latest::Junctions::X1;
         match location.unpack() {
-            (0, [AccountId32 { network, id }]) => {
-                LocationToAccountId::convert_location(&Location {
-                    parents: 0,
-                    interior: X1(sp_std::sync::Arc::new([AccountKey20 {
-                        network: *network,
-                        key: id.as_slice()[id.len() - 20..] // take the last 20 bytes
-                            .try_into()
-                            .expect("Cannot convert AccountId32 to AccountKey20"),
-                    }])),
-                })
-            }
+            (0 | 1, [AccountId32 { id, .. }]) => AccountMapping::linked_account(&(*id).into())
+                .or_else(|| {
+                    LocationToAccountId::convert_location(&Location::new(
+                        1,
+                        [AccountId32 {
+                            network: None,
+                            id: *id,
+                        }],
+                    ))
+                }),
             _ => LocationToAccountId::convert_location(location),
         }
     }
 }
 
+/// The account which received the assets sent to the `AccountId32` location of this chain of a
+/// relay chain account, before `LocationAccountId32ToAccountId` followed the links of
+/// `AccountMapping`: it is made of the last 20 bytes of the identifier, and nobody controls it.
+pub struct TruncatedAccountId32;
+impl Convert<sp_runtime::AccountId32, Option<AccountId>> for TruncatedAccountId32 {
+    fn convert(relay_account: sp_runtime::AccountId32) -> Option<AccountId> {
+        let id: &[u8; 32] = relay_account.as_ref();
+        Some(H160::from_slice(&id[12..]).into())
+    }
+}
+
 /// Means for transacting the native currency on this chain.
 pub type FungibleTransactor = FungibleAdapter<
     // Use this currency:
@@ -120,8 +153,24 @@ pub type FungibleTransactor = FungibleAdapter<
     (),
 >;
 
+/// Means for transacting the foreign assets registered in `AssetRegistry` on this chain.
+pub type ForeignFungiblesTransactor = FungiblesAdapter<
+    // Use this fungibles implementation:
+    ForeignAssets,
+    // Use this fungibles implementation when the asset location is registered:
+    ConvertedConcreteId<LocalAssetId, Balance, AssetRegistry, JustTry>,
+    // Convert an XCM `Location` into a local account ID:
+    LocationAccountId32ToAccountId,
+    // Our chain's account ID type (we can't get away without mentioning it explicitly):
+    AccountId,
+    // Foreign assets are never teleported, so we don't track them.
+    NoChecking,
+    // The account used to track teleports, unused here.
+    CheckingAccount,
+>;
+
 /// Means for transacting assets on this chain.
-pub type AssetTransactors = FungibleTransactor;
+pub type AssetTransactors = (FungibleTransactor, ForeignFungiblesTransactor);
 
 /// This is the type we use to convert an (incoming) XCM origin into a local
 /// `Origin` instance, ready for dispatching a transaction with Xcm's
@@ -154,8 +203,60 @@ parameter_types! {
 pub struct ParentRelayChain;
 impl Contains<Location> for ParentRelayChain {
     fn contains(location: &Location) -> bool {
-        // match the relay chain and any account on it
-        matches!(location.unpack(), (1, [..]))
+        // match the relay chain and the accounts on it, but not the sibling parachains
+        matches!(location.unpack(), (1, []) | (1, [AccountId32 { .. }]))
+    }
+}
+
+/// Trusts a sibling parachain as the reserve of the foreign assets registered in `AssetRegistry`
+/// which live inside it, e.g. the stablecoins of an Asset Hub-like chain.
+pub struct ForeignAssetsFromSiblingReserve;
+impl ContainsPair<Asset, Location> for ForeignAssetsFromSiblingReserve {
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        matches!(origin.unpack(), (1, [Parachain(_)]))
+            && asset.id.0.starts_with(origin)
+            && pallet_asset_registry::LocationToAssetId::<Runtime>::contains_key(&asset.id.0)
+    }
+}
+
+/// Lets the accounts of the trusted aliaser chains act as the account of this chain with the same
+/// `AccountKey20`, e.g. across the EVM chains of the zkVerify system.
+pub struct SameAccountKey20;
+impl ContainsPair<Location, Location> for SameAccountKey20 {
+    fn contains(origin: &Location, target: &Location) -> bool {
+        match (origin.split_last_interior(), target.unpack()) {
+            ((chain, Some(AccountKey20 { key, .. })), (0, [AccountKey20 { key: target, .. }])) => {
+                key == *target && TrustedAliasers::contains(&chain)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Lets the relay chain accounts, when the relay chain is a trusted aliaser, act as the account
+/// of this chain linked to them in `AccountMapping`.
+pub struct LinkedRelayAccounts;
+impl ContainsPair<Location, Location> for LinkedRelayAccounts {
+    fn contains(origin: &Location, target: &Location) -> bool {
+        match (origin.unpack(), target.unpack()) {
+            ((1, [AccountId32 { id, .. }]), (0, [AccountKey20 { key, .. }])) => {
+                TrustedAliasers::contains(&RelayLocation::get())
+                    && AccountMapping::linked_account(&(*id).into())
+                        .is_some_and(|account| account == AccountId::from(*key))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Allows reserve transfers of the registered foreign assets only: the native token is teleported.
+pub struct RegisteredForeignAssets;
+impl Contains<(Location, Vec<Asset>)> for RegisteredForeignAssets {
+    fn contains((_, assets): &(Location, Vec<Asset>)) -> bool {
+        !assets.is_empty()
+            && assets.iter().all(|asset| {
+                pallet_asset_registry::LocationToAssetId::<Runtime>::contains_key(&asset.id.0)
+            })
     }
 }
 
@@ -166,11 +267,12 @@ pub type Barrier = TrailingSetTopicAsId<
             TakeWeightCredit,
             AllowKnownQueryResponses<ZKVXcm>,
             WithComputedOrigin<
-                (AllowTopLevelPaidExecutionFrom<ParentRelayChain>,),
+                // Trusted siblings are managed by governance through `pallet_trusted_siblings`.
+                (AllowTopLevelPaidExecutionFrom<(ParentRelayChain, TrustedSiblings)>,),
                 UniversalLocation,
                 ConstU32<8>,
             >,
-            AllowSubscriptionsFrom<ParentRelayChain>,
+            AllowSubscriptionsFrom<(ParentRelayChain, TrustedSiblings)>,
         ),
     >,
 >;
@@ -179,6 +281,12 @@ pub type TrustedTeleporters = ConcreteAssetFromSystem<RelayLocation>;
 
 pub type WaivedLocations = (Equals<RelayLocation>, Equals<RootLocation>);
 
+/// Dispatches the `EthereumXcm` transactions with the origin of the account converted from the
+/// XCM origin. `EthereumXcm::transact` cannot create contracts: the creations of the accounts
+/// that `EnsureXcmDeployer` accepts, i.e. having deploy permission in `DeploymentPermissions`, are
+/// dispatched as `EthereumXcm::force_transact_as` of that same account, without a forced contract
+/// address. This is the only path to `force_transact_as` besides root, its `ForceOrigin`.
+/// `PermissionedDeploy` checks the permission again.
 pub struct RemoteEVMCall;
 impl CallDispatcher<RuntimeCall> for RemoteEVMCall {
     fn dispatch(
@@ -191,33 +299,148 @@ impl CallDispatcher<RuntimeCall> for RemoteEVMCall {
         if let Ok(raw_origin) =
             TryInto::<frame_system::RawOrigin<AccountId>>::try_into(origin.clone().caller)
         {
-            if let (
-                RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact { .. })
-                | RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact_through_proxy {
-                    ..
-                }),
-                frame_system::RawOrigin::Signed(account_id),
-            ) = (call.clone(), raw_origin)
-            {
-                return RuntimeCall::dispatch(
-                    call,
-                    pallet_ethereum_xcm::Origin::XcmEthereumTransaction(account_id.into()).into(),
-                );
+            match (call.clone(), raw_origin) {
+                (
+                    RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact {
+                        xcm_transaction,
+                    }),
+                    frame_system::RawOrigin::Signed(account_id),
+                ) if is_create(&xcm_transaction) => {
+                    return dispatch_create(account_id, xcm_transaction);
+                }
+                (
+                    RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact { .. })
+                    | RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact_through_proxy {
+                        ..
+                    }),
+                    frame_system::RawOrigin::Signed(account_id),
+                ) => {
+                    return RuntimeCall::dispatch(
+                        call,
+                        pallet_ethereum_xcm::Origin::XcmEthereumTransaction(account_id.into())
+                            .into(),
+                    );
+                }
+                _ => {}
             }
         }
         RuntimeCall::dispatch(call, origin)
     }
 }
 
+/// Dispatches the creation `xcm_transaction` of `account_id`, if it has deploy permission, as
+/// `force_transact_as` pinned to that account and without a forced contract address.
+fn dispatch_create(
+    account_id: AccountId,
+    xcm_transaction: EthereumXcmTransaction,
+) -> Result<
+    PostDispatchInfoOf<RuntimeCall>,
+    DispatchErrorWithPostInfo<PostDispatchInfoOf<RuntimeCall>>,
+> {
+    let transact_as = EnsureXcmDeployer::ensure_origin(
+        pallet_ethereum_xcm::Origin::XcmEthereumTransaction(account_id.into()).into(),
+    )?;
+    RuntimeCall::dispatch(
+        pallet_ethereum_xcm::Call::<Runtime>::force_transact_as {
+            transact_as,
+            xcm_transaction,
+            force_create_address: None,
+        }
+        .into(),
+        frame_system::RawOrigin::Root.into(),
+    )
+}
+
+fn is_create(xcm_transaction: &EthereumXcmTransaction) -> bool {
+    let action = match xcm_transaction {
+        EthereumXcmTransaction::V1(EthereumXcmTransactionV1 { action, .. }) => action,
+        EthereumXcmTransaction::V2(EthereumXcmTransactionV2 { action, .. }) => action,
+    };
+    matches!(action, TransactionAction::Create)
+}
+
+/// Lets XCM `Transact` dispatch the calls allowed in `XcmSafeCalls`.
 pub struct SafeCallFilter;
 impl frame_support::traits::Contains<RuntimeCall> for SafeCallFilter {
     fn contains(call: &RuntimeCall) -> bool {
-        matches!(
-            call,
-            RuntimeCall::EthereumXcm(_)
-                // Used for baseline benchmarks
-                | RuntimeCall::System(frame_system::Call::remark_with_event { remark: _ })
-        )
+        call_index(call).is_some_and(|call_index| XcmSafeCalls::contains(&call_index))
+    }
+}
+
+/// The (pallet index, call index) pair of `call`, read without encoding it. The pallets without
+/// calls are not listed: `XcmSafeCalls` cannot allow any call of theirs.
+fn call_index(call: &RuntimeCall) -> Option<CallIndex> {
+    macro_rules! call_index_of {
+        ($($pallet:ident),* $(,)?) => {
+            match call {
+                $(
+                    RuntimeCall::$pallet(call) => {
+                        Some((crate::$pallet::index() as u8, call.get_call_index()))
+                    }
+                )*
+                _ => None,
+            }
+        };
+    }
+
+    call_index_of!(
+        System,
+        ParachainSystem,
+        Timestamp,
+        Proxy,
+        Utility,
+        Multisig,
+        Balances,
+        ForeignAssets,
+        Sudo,
+        CollatorSelection,
+        Session,
+        XcmpQueue,
+        ZKVXcm,
+        MessageQueue,
+        Ethereum,
+        EVM,
+        EthereumXcm,
+        DeploymentPermissions,
+        PrecompileKillSwitch,
+        AggregationRoots,
+        RelayRandomness,
+        TrustedSiblings,
+        AssetRegistry,
+        AccountMapping,
+        TrustedAliasers,
+        XcmSafeCalls,
+    )
+}
+
+/// The calls XCM `Transact` may dispatch until governance edits them: all the calls of
+/// `EthereumXcm`, `AggregationRoots` and `AccountMapping`, and `System::remark_with_event`.
+pub struct DefaultSafeCalls;
+impl Get<Vec<CallIndex>> for DefaultSafeCalls {
+    fn get() -> Vec<CallIndex> {
+        fn all_calls<P: PalletInfoAccess, C: GetCallIndex>() -> impl Iterator<Item = CallIndex> {
+            let pallet_index = P::index() as u8;
+            C::get_call_indices()
+                .iter()
+                .map(move |call_index| (pallet_index, *call_index))
+        }
+
+        all_calls::<EthereumXcm, pallet_ethereum_xcm::Call<Runtime>>()
+            .chain(all_calls::<
+                AggregationRoots,
+                pallet_aggregation_roots::Call<Runtime>,
+            >())
+            .chain(all_calls::<
+                AccountMapping,
+                pallet_account_mapping::Call<Runtime>,
+            >())
+            // Used for baseline benchmarks
+            .chain([(
+                System::index() as u8,
+                frame_system::Call::<Runtime>::remark_with_event { remark: Vec::new() }
+                    .get_call_index(),
+            )])
+            .collect()
     }
 }
 
@@ -228,20 +451,23 @@ impl xcm_executor::Config for XcmConfig {
     // How to withdraw and deposit an asset.
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = NativeAsset;
+    type IsReserve = (NativeAsset, ForeignAssetsFromSiblingReserve);
     type IsTeleporter = TrustedTeleporters;
-    type Aliasers = Nothing;
+    type Aliasers = (SameAccountKey20, LinkedRelayAccounts);
     type UniversalLocation = UniversalLocation;
     type Barrier = Barrier;
     type Weigher = WeightInfoBounds<XcmZKVEvmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
-    // Can only buy weight with the native token
-    type Trader = UsingComponents<
-        <Runtime as pallet_transaction_payment::Config>::WeightToFee,
-        RelayLocation,
-        AccountId,
-        Balances,
-        ResolveTo<StakingPot, Balances>,
-    >;
+    // Buy weight with the native token or with the foreign assets having a fee rate
+    type Trader = (
+        UsingComponents<
+            <Runtime as pallet_transaction_payment::Config>::WeightToFee,
+            RelayLocation,
+            AccountId,
+            Balances,
+            ResolveTo<StakingPot, Balances>,
+        >,
+        ForeignAssetsTrader,
+    );
     type ResponseHandler = ZKVXcm;
     type AssetTrap = ZKVXcm;
     type AssetLocker = ();
@@ -262,7 +488,8 @@ impl xcm_executor::Config for XcmConfig {
     type HrmpNewChannelOpenRequestHandler = ();
     type HrmpChannelAcceptedHandler = ();
     type HrmpChannelClosingHandler = ();
-    type XcmRecorder = ZKVXcm;
+    // Records the inbound messages for `XcmTracing` too
+    type XcmRecorder = TracingRecorder<Runtime, ZKVXcm>;
 }
 
 // Convert a local Origin (i.e., a signed 20 byte account Origin)  to a Multilocation
@@ -308,12 +535,16 @@ pub type PriceForParentDelivery = polkadot_runtime_common::xcm_sender::Exponenti
 
 /// The means for routing XCM messages which are not for local execution into
 /// the right message queues.
-pub type XcmRouter = WithUniqueTopic<(
-    // Two routers - use UMP to communicate with the relay chain:
-    cumulus_primitives_utility::ParentAsUmp<ParachainSystem, ZKVXcm, PriceForParentDelivery>,
-    // ..and XCMP to communicate with the sibling chains.
-    XcmpQueue,
-)>;
+/// The messages are traced by `XcmTracing` with their unique topic.
+pub type XcmRouter = TracedRouter<
+    Runtime,
+    WithUniqueTopic<(
+        // Two routers - use UMP to communicate with the relay chain:
+        cumulus_primitives_utility::ParentAsUmp<ParachainSystem, ZKVXcm, PriceForParentDelivery>,
+        // ..and XCMP to communicate with the sibling chains.
+        XcmpQueue,
+    )>,
+>;
 
 parameter_types! {
     pub const MaxLockers: u32 = 8;
@@ -330,7 +561,7 @@ impl pallet_xcm::Config for Runtime {
     type XcmExecuteFilter = Everything;
     type XcmExecutor = XcmExecutor<XcmConfig>;
     type XcmTeleportFilter = Everything;
-    type XcmReserveTransferFilter = Nothing;
+    type XcmReserveTransferFilter = RegisteredForeignAssets;
     type Weigher = WeightInfoBounds<XcmZKVEvmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
     type UniversalLocation = UniversalLocation;
     type RuntimeOrigin = RuntimeOrigin;
@@ -363,11 +594,14 @@ impl pallet_message_queue::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::pallet_message_queue::ZKVEvmWeight<Runtime>;
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type MessageProcessor = pallet_ethereum_xcm::MessageProcessorWrapper<
-        xcm_builder::ProcessXcmMessage<
-            AggregateMessageOrigin,
-            xcm_executor::XcmExecutor<crate::configs::xcm::XcmConfig>,
-            RuntimeCall,
+    type MessageProcessor = TracedMessageProcessor<
+        Runtime,
+        pallet_ethereum_xcm::MessageProcessorWrapper<
+            xcm_builder::ProcessXcmMessage<
+                AggregateMessageOrigin,
+                xcm_executor::XcmExecutor<crate::configs::xcm::XcmConfig>,
+                RuntimeCall,
+            >,
         >,
     >;
     #[cfg(feature = "runtime-benchmarks")]
@@ -391,7 +625,7 @@ parameter_types! {
 }
 
 /// Price For Sibling Parachain Delivery
-type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
+pub type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
     FeeAssetId,
     ToSiblingBaseDeliveryFee,
     TransactionByteFee,
@@ -415,3 +649,273 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
     type WeightInfo = weights::cumulus_pallet_xcmp_queue::ZKVEvmWeight<Runtime>;
 }
+
+impl pallet_aggregation_roots::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    // Only the relay chain itself can deliver the roots: its accounts cannot.
+    type StoreOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<RelayLocation>>>;
+    type WeightInfo = weights::pallet_aggregation_roots::ZKVEvmWeight<Self>;
+}
+
+impl pallet_trusted_siblings::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = weights::pallet_trusted_siblings::ZKVEvmWeight<Self>;
+}
+
+impl pallet_trusted_aliasers::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = weights::pallet_trusted_aliasers::ZKVEvmWeight<Self>;
+}
+
+parameter_types! {
+    pub const MaxSafeCalls: u32 = 128;
+}
+
+impl pallet_xcm_safe_calls::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxSafeCalls = MaxSafeCalls;
+    type DefaultSafeCalls = DefaultSafeCalls;
+    type WeightInfo = weights::pallet_xcm_safe_calls::ZKVEvmWeight<Self>;
+}
+
+parameter_types! {
+    pub const MaxTraces: u32 = 1024;
+}
+
+impl pallet_xcm_tracing::Config for Runtime {
+    type LocationToAccountId = LocationAccountId32ToAccountId;
+    type MaxTraces = MaxTraces;
+    type WeightInfo = weights::pallet_xcm_tracing::ZKVEvmWeight<Self>;
+}
+
+impl pallet_asset_registry::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = ForeignAssets;
+    type FeeRateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = weights::pallet_asset_registry::ZKVEvmWeight<Self>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ForeignAssetsBenchmarkHelper;
+}
+
+/// The fee paid, in a foreign asset, for the weight bought by a `ForeignAssetsTrader`.
+struct ForeignAssetFee {
+    id: AssetId,
+    asset_id: LocalAssetId,
+    rate: FixedU128,
+    amount: Balance,
+}
+
+/// Buys weight with the registered foreign assets which have a fee rate, charging the fee in
+/// native token converted at that rate. The unused fees are refunded, while the collected ones
+/// are deposited to the `StakingPot` when the trader is dropped: they are never less than the
+/// minimum balance of the asset, so that the pot can always receive them.
+pub struct ForeignAssetsTrader {
+    weight: Weight,
+    fee: Option<ForeignAssetFee>,
+}
+
+impl ForeignAssetsTrader {
+    /// The units of a foreign asset with fee rate `rate` charged for `weight`.
+    pub fn weight_to_fee(weight: &Weight, rate: FixedU128) -> Balance {
+        rate.saturating_mul_int(
+            <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(weight),
+        )
+    }
+
+    /// The smallest fee in `asset_id` which can be deposited to the `StakingPot`: the pot must
+    /// receive at least the minimum balance of the asset while it holds none of it.
+    fn minimum_fee(asset_id: LocalAssetId) -> Balance {
+        if <ForeignAssets as fungibles::Inspect<AccountId>>::balance(asset_id, &StakingPot::get())
+            > 0
+        {
+            0
+        } else {
+            <ForeignAssets as fungibles::Inspect<AccountId>>::minimum_balance(asset_id)
+        }
+    }
+}
+
+impl WeightTrader for ForeignAssetsTrader {
+    fn new() -> Self {
+        Self {
+            weight: Weight::zero(),
+            fee: None,
+        }
+    }
+
+    fn buy_weight(
+        &mut self,
+        weight: Weight,
+        payment: AssetsInHolding,
+        _context: &XcmContext,
+    ) -> Result<AssetsInHolding, XcmError> {
+        let (id, asset_id, rate) = payment
+            .fungible_assets_iter()
+            .find_map(|asset| {
+                AssetRegistry::fee_rate(&asset.id.0)
+                    .map(|(asset_id, rate)| (asset.id, asset_id, rate))
+            })
+            .ok_or(XcmError::AssetNotFound)?;
+        // Weight bought more than once must always be paid with the same asset.
+        if self.fee.as_ref().is_some_and(|fee| fee.id != id) {
+            return Err(XcmError::NotWithdrawable);
+        }
+
+        let collected = self.fee.as_ref().map_or(0, |fee| fee.amount);
+        let amount = Self::weight_to_fee(&weight, rate)
+            .max(Self::minimum_fee(asset_id).saturating_sub(collected));
+        let unused = payment
+            .checked_sub((id.clone(), amount).into())
+            .map_err(|_| XcmError::TooExpensive)?;
+        self.weight = self.weight.saturating_add(weight);
+        let fee = self.fee.get_or_insert(ForeignAssetFee {
+            id,
+            asset_id,
+            rate,
+            amount: 0,
+        });
+        fee.amount = fee.amount.saturating_add(amount);
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<Asset> {
+        let fee = self.fee.as_mut()?;
+        let weight = weight.min(self.weight);
+        self.weight = self.weight.saturating_sub(weight);
+        let refund = if self.weight.is_zero() {
+            fee.amount
+        } else {
+            // Keep enough fees for the `StakingPot` to accept them.
+            Self::weight_to_fee(&weight, fee.rate)
+                .min(fee.amount.saturating_sub(Self::minimum_fee(fee.asset_id)))
+        };
+        fee.amount = fee.amount.saturating_sub(refund);
+        (refund > 0).then(|| (fee.id.clone(), refund).into())
+    }
+}
+
+impl Drop for ForeignAssetsTrader {
+    fn drop(&mut self) {
+        if let Some(fee) = self.fee.take().filter(|fee| fee.amount > 0) {
+            if let Err(error) = <ForeignAssets as fungibles::Mutate<AccountId>>::mint_into(
+                fee.asset_id,
+                &StakingPot::get(),
+                fee.amount,
+            ) {
+                log::error!(
+                    target: "xcm::weight",
+                    "Cannot deposit the fee of {} units of the foreign asset {} to the staking pot: {:?}",
+                    fee.amount,
+                    fee.asset_id,
+                    error,
+                );
+            }
+        }
+    }
+}
+
+/// Accepts the locations of the relay chain accounts.
+pub struct RelayAccounts;
+impl Contains<Location> for RelayAccounts {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, [AccountId32 { .. }]))
+    }
+}
+
+/// Ensures the origin is a relay chain account dispatching through XCM `Transact`.
+pub struct EnsureRelayAccount;
+impl EnsureOrigin<RuntimeOrigin> for EnsureRelayAccount {
+    type Success = Location;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
+        EnsureXcm::<RelayAccounts>::try_origin(o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(pallet_xcm::Origin::Xcm(Location::new(
+            1,
+            [AccountId32 {
+                network: None,
+                id: [1; 32],
+            }],
+        ))
+        .into())
+    }
+}
+
+impl pallet_account_mapping::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Signature = Signature;
+    type Signer = <Signature as Verify>::Signer;
+    type RelayAccountOrigin = EnsureRelayAccount;
+    type Currency = Balances;
+    type LegacyAccount = TruncatedAccountId32;
+    type WeightInfo = weights::pallet_account_mapping::ZKVEvmWeight<Self>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = AccountMappingBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AccountMappingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+const ACCOUNT_MAPPING_KEY_TYPE: sp_core::crypto::KeyTypeId = sp_core::crypto::KeyTypeId(*b"link");
+
+#[cfg(feature = "runtime-benchmarks")]
+impl AccountMappingBenchmarkHelper {
+    fn seed() -> Option<Vec<u8>> {
+        Some(b"//Alice".to_vec())
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_account_mapping::BenchmarkHelper<AccountId, Signature>
+    for AccountMappingBenchmarkHelper
+{
+    fn account() -> AccountId {
+        let public = sp_io::crypto::ecdsa_generate(ACCOUNT_MAPPING_KEY_TYPE, Self::seed());
+        sp_runtime::traits::IdentifyAccount::into_account(fp_account::EthereumSigner::from(public))
+    }
+
+    fn sign(message: &[u8]) -> Signature {
+        let public = sp_io::crypto::ecdsa_generate(ACCOUNT_MAPPING_KEY_TYPE, Self::seed());
+        let signature = sp_io::crypto::ecdsa_sign_prehashed(
+            ACCOUNT_MAPPING_KEY_TYPE,
+            &public,
+            &sp_io::hashing::keccak_256(message),
+        )
+        .expect("The key has just been generated");
+        Signature::new(signature)
+    }
+
+    fn relay_account() -> sp_runtime::AccountId32 {
+        sp_io::crypto::sr25519_generate(ACCOUNT_MAPPING_KEY_TYPE, Self::seed()).into()
+    }
+
+    fn relay_sign(message: &[u8]) -> sp_runtime::MultiSignature {
+        let public = sp_io::crypto::sr25519_generate(ACCOUNT_MAPPING_KEY_TYPE, Self::seed());
+        sp_io::crypto::sr25519_sign(ACCOUNT_MAPPING_KEY_TYPE, &public, message)
+            .expect("The key has just been generated")
+            .into()
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ForeignAssetsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_registry::BenchmarkHelper<LocalAssetId> for ForeignAssetsBenchmarkHelper {
+    fn create_asset() -> LocalAssetId {
+        let asset_id = 1;
+        ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            AccountId::from([0u8; 20]),
+            true,
+            1,
+        )
+        .expect("Cannot create the benchmark asset");
+        asset_id
+    }
+}
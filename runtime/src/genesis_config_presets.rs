@@ -14,7 +14,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::constants::currency::tVFY;
-use crate::{AccountId, Balance, Precompiles, Runtime, SessionKeys};
+use crate::{AccountId, Balance, Runtime, SessionKeys, StaticPrecompiles};
 use alloc::{collections::BTreeMap, format, vec::Vec};
 use cumulus_primitives_core::ParaId;
 use hex_literal::hex;
@@ -100,7 +100,7 @@ fn genesis(
         )))
         .collect::<Vec<_>>();
 
-    let precompiles = Precompiles::<Runtime>::used_addresses().map(|addr| {
+    let precompiles = StaticPrecompiles::<Runtime>::used_addresses().map(|addr| {
         (
             addr.into(),
             fp_evm::GenesisAccount {
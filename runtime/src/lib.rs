@@ -26,7 +26,7 @@ pub mod constants;
 mod genesis_config_presets;
 
 mod precompiles;
-pub use precompiles::Precompiles;
+pub use precompiles::{foreign_asset_address, Precompiles, StaticPrecompiles};
 #[cfg(test)]
 mod tests;
 pub mod types;
@@ -74,6 +74,11 @@ use sp_std::prelude::{Vec, *};
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
+use xcm::{VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_runtime_apis::{
+    dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+    fees::Error as XcmPaymentApiError,
+};
 
 pub use crate::types::{
     AccountId, Balance, Block, BlockNumber, Executive, Nonce, Signature, UncheckedExtrinsic,
@@ -173,7 +178,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: Cow::Borrowed("tvflow-runtime"),
     impl_name: Cow::Borrowed("vflow-node"),
     authoring_version: 1,
-    spec_version: 1_000_000,
+    spec_version: 1_001_000,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -214,6 +219,7 @@ construct_runtime!(
         // Monetary
         Balances: pallet_balances = 10,
         TransactionPayment: pallet_transaction_payment = 11, // No weight
+        ForeignAssets: pallet_assets = 12,
 
         // Governance
         Sudo: pallet_sudo = 15,
@@ -240,6 +246,16 @@ construct_runtime!(
 
         // zkVerify Custom Pallets
         DeploymentPermissions: pallet_deployment_permissions = 100,
+        PrecompileKillSwitch: pallet_precompile_kill_switch = 101,
+        AggregationRoots: pallet_aggregation_roots = 102,
+        RelayRandomness: pallet_relay_randomness = 103,
+        RelayStorageRoots: pallet_relay_storage_roots = 104,
+        TrustedSiblings: pallet_trusted_siblings = 105,
+        AssetRegistry: pallet_asset_registry = 106,
+        AccountMapping: pallet_account_mapping = 107,
+        TrustedAliasers: pallet_trusted_aliasers = 108,
+        XcmSafeCalls: pallet_xcm_safe_calls = 109,
+        XcmTracing: pallet_xcm_tracing = 110,
     }
 );
 
@@ -616,6 +632,72 @@ impl_runtime_apis! {
         }
     }
 
+    impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
+        fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
+            // Execution can be bought with the native token and with the foreign assets having
+            // a fee rate.
+            let acceptable_assets = core::iter::once(configs::xcm::NativeAssetId::get())
+                .chain(AssetRegistry::fee_payment_locations().into_iter().map(xcm::latest::AssetId))
+                .collect();
+            ZKVXcm::query_acceptable_payment_assets(xcm_version, acceptable_assets)
+        }
+
+        fn query_weight_to_asset_fee(weight: Weight, asset: VersionedAssetId) -> Result<u128, XcmPaymentApiError> {
+            use frame_support::weights::WeightToFee;
+
+            match xcm::latest::AssetId::try_from(asset) {
+                Ok(asset_id) if asset_id == configs::xcm::NativeAssetId::get() => Ok(
+                    <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(&weight),
+                ),
+                Ok(asset_id) => AssetRegistry::fee_rate(&asset_id.0)
+                    .map(|(_, rate)| configs::xcm::ForeignAssetsTrader::weight_to_fee(&weight, rate))
+                    .ok_or(XcmPaymentApiError::AssetNotFound),
+                Err(_) => Err(XcmPaymentApiError::VersionedConversionFailed),
+            }
+        }
+
+        fn query_xcm_weight(message: VersionedXcm<()>) -> Result<Weight, XcmPaymentApiError> {
+            ZKVXcm::query_xcm_weight(message)
+        }
+
+        fn query_delivery_fees(destination: VersionedLocation, message: VersionedXcm<()>) -> Result<VersionedAssets, XcmPaymentApiError> {
+            ZKVXcm::query_delivery_fees(destination, message)
+        }
+    }
+
+    impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+        fn dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+            ZKVXcm::dry_run_call::<Runtime, configs::xcm::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
+        }
+
+        fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+            ZKVXcm::dry_run_xcm::<Runtime, configs::xcm::XcmRouter, RuntimeCall, configs::xcm::XcmConfig>(origin_location, xcm)
+        }
+    }
+
+    impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
+        fn convert_location(location: VersionedLocation) -> Result<AccountId, xcm_runtime_apis::conversions::Error> {
+            xcm_runtime_apis::conversions::LocationToAccountHelper::<
+                AccountId,
+                configs::xcm::LocationToAccountId,
+            >::convert_location(location)
+        }
+    }
+
+    impl pallet_xcm_tracing_runtime_api::XcmTracingApi<Block, AccountId, BlockNumber> for Runtime {
+        fn traces_by_topic(
+            topic: [u8; 32],
+        ) -> Vec<pallet_xcm_tracing::MessageTrace<AccountId, BlockNumber>> {
+            XcmTracing::traces_by_topic(topic)
+        }
+
+        fn traces_by_account(
+            account: AccountId,
+        ) -> Vec<pallet_xcm_tracing::MessageTrace<AccountId, BlockNumber>> {
+            XcmTracing::traces_by_account(account)
+        }
+    }
+
     #[cfg(feature = "try-runtime")]
     impl frame_try_runtime::TryRuntime<Block> for Runtime {
         fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -654,6 +736,8 @@ impl_runtime_apis! {
             pub mod xcm {
                 pub use pallet_xcm_benchmarks::fungible::Pallet as XcmPalletBenchFungible;
                 pub use pallet_xcm_benchmarks::generic::Pallet as XcmPalletBenchGeneric;
+                pub type XcmPalletBenchForeignFungible<T> =
+                    XcmPalletBenchFungible<T, frame_support::instances::Instance1>;
             }
 
             let mut list = Vec::<BenchmarkList>::new();
@@ -681,6 +765,9 @@ impl_runtime_apis! {
 
                 pub use pallet_xcm_benchmarks::fungible::Pallet as XcmPalletBenchFungible;
                 pub use pallet_xcm_benchmarks::generic::Pallet as XcmPalletBenchGeneric;
+                /// The fungible benchmarks of the `ForeignFungiblesTransactor`.
+                pub type XcmPalletBenchForeignFungible<T> =
+                    XcmPalletBenchFungible<T, frame_support::instances::Instance1>;
 
                 parameter_types! {
                     pub ExistentialDepositAsset: Option<Asset> = Some((
@@ -689,14 +776,50 @@ impl_runtime_apis! {
                     ).into());
                     /// The base fee for the message delivery fees. Kusama is based for the reference.
                     pub const ToParentBaseDeliveryFee: u128 = CENTS.saturating_mul(3);
+                    pub RandomParaId: cumulus_primitives_core::ParaId = cumulus_primitives_core::ParaId::new(43211234);
+                    /// The identifier of the asset created by `ForeignAssetsBenchmarkHelper`.
+                    pub const BenchmarkForeignAssetId: crate::types::AssetId = 1;
+                }
+
+                /// The foreign asset of the benchmarks, held in reserve by a sibling parachain:
+                /// returns the reserve and the asset location, after creating and registering
+                /// the asset the first time.
+                fn benchmark_foreign_asset() -> (Location, Location) {
+                    use pallet_asset_registry::BenchmarkHelper;
+
+                    let reserve =
+                        Location::new(1, [Junction::Parachain(RandomParaId::get().into())]);
+                    let location = reserve
+                        .clone()
+                        .pushed_with_interior(Junction::GeneralIndex(1984))
+                        .expect("The location has a single junction");
+                    if !pallet_asset_registry::LocationToAssetId::<Runtime>::contains_key(&location) {
+                        let asset_id = ForeignAssetsBenchmarkHelper::create_asset();
+                        AssetRegistry::register_foreign_asset(
+                            RuntimeOrigin::root(),
+                            asset_id,
+                            Box::new(location.clone().into()),
+                        )
+                        .expect("Cannot register the benchmark asset");
+                    }
+                    (reserve, location)
                 }
 
                 impl pallet_xcm::benchmarking::Config for Runtime {
-                    type DeliveryHelper = cumulus_primitives_utility::ToParentDeliveryHelper<
-                        XcmConfig,
-                        ExistentialDepositAsset,
-                        PriceForParentDelivery,
-                    >;
+                    type DeliveryHelper = (
+                        cumulus_primitives_utility::ToParentDeliveryHelper<
+                            XcmConfig,
+                            ExistentialDepositAsset,
+                            PriceForParentDelivery,
+                        >,
+                        polkadot_runtime_common::xcm_sender::ToParachainDeliveryHelper<
+                            XcmConfig,
+                            ExistentialDepositAsset,
+                            PriceForSiblingParachainDelivery,
+                            RandomParaId,
+                            ParachainSystem,
+                        >,
+                    );
 
                     fn reachable_dest() -> Option<Location> {
                         Some(Parent.into())
@@ -711,8 +834,16 @@ impl_runtime_apis! {
                     }
 
                     fn reserve_transferable_asset_and_dest() -> Option<(Asset, Location)> {
-                        // Reserve transfers are disabled on EVM.
-                        None
+                        // Only foreign assets can be reserve transferred: we send one back to
+                        // the sibling parachain holding its reserve.
+                        let (reserve, location) = benchmark_foreign_asset();
+                        Some((
+                            Asset {
+                                id: AssetId(location),
+                                fun: Fungible(CENTS),
+                            },
+                            reserve,
+                        ))
                     }
 
                     fn set_up_complex_asset_transfer(
@@ -756,7 +887,13 @@ impl_runtime_apis! {
                             fun: Fungible(ExistentialDeposit::get()),
                         },
                     ));
-                    pub const TrustedReserve: Option<(Location, Asset)> = None;
+                    pub TrustedReserve: Option<(Location, Asset)> = Some((
+                        RelayLocation::get(),
+                        Asset {
+                            id: NativeAssetId::get(),
+                            fun: Fungible(ExistentialDeposit::get()),
+                        },
+                    ));
                     pub const CheckedAccount: Option<(AccountId, xcm_builder::MintLocation)> = None;
                 }
 
@@ -774,6 +911,39 @@ impl_runtime_apis! {
                     }
                 }
 
+                /// The sibling parachain holding the reserve of the benchmark foreign asset.
+                pub struct ForeignAssetsTrustedReserve;
+                impl frame_support::traits::Get<Option<(Location, Asset)>> for ForeignAssetsTrustedReserve {
+                    fn get() -> Option<(Location, Asset)> {
+                        let (reserve, location) = benchmark_foreign_asset();
+                        Some((reserve, Asset { id: AssetId(location), fun: Fungible(CENTS) }))
+                    }
+                }
+
+                parameter_types! {
+                    pub const NoTeleporter: Option<(Location, Asset)> = None;
+                }
+
+                impl pallet_xcm_benchmarks::fungible::Config<frame_support::instances::Instance1> for Runtime {
+                    type TransactAsset = frame_support::traits::fungible::ItemOf<
+                        ForeignAssets,
+                        BenchmarkForeignAssetId,
+                        AccountId,
+                    >;
+                    type CheckedAccount = CheckedAccount;
+                    // Foreign assets are never teleported.
+                    type TrustedTeleporter = NoTeleporter;
+                    type TrustedReserve = ForeignAssetsTrustedReserve;
+
+                    fn get_asset() -> Asset {
+                        let (_, location) = benchmark_foreign_asset();
+                        Asset {
+                            id: AssetId(location),
+                            fun: Fungible(CENTS),
+                        }
+                    }
+                }
+
                 impl pallet_xcm_benchmarks::generic::Config for Runtime {
                     type TransactAsset = Balances;
                     type RuntimeCall = RuntimeCall;
@@ -828,8 +998,22 @@ impl_runtime_apis! {
                     }
 
                     fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
-                        // The XCM executor of ZKV doesn't have a configured `Aliasers`
-                        Err(BenchmarkError::Skip)
+                        // The worst case is a relay chain account aliasing into its linked
+                        // account, which reads both the trusted aliasers and the account mapping.
+                        let id = [1u8; 32];
+                        let key = [1u8; 20];
+                        pallet_trusted_aliasers::TrustedAliasers::<Runtime>::insert(
+                            RelayLocation::get(),
+                            (),
+                        );
+                        pallet_account_mapping::LinkedAccounts::<Runtime>::insert(
+                            sp_runtime::AccountId32::from(id),
+                            AccountId::from(key),
+                        );
+                        Ok((
+                            Location::new(1, [Junction::AccountId32 { network: None, id }]),
+                            Location::new(0, [Junction::AccountKey20 { network: None, key }]),
+                        ))
                     }
                 }
             }
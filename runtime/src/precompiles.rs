@@ -13,15 +13,62 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::xcm_teleport::XcmTeleportPrecompile;
+mod aggregation;
+mod bls12381;
+mod collator_staking;
+mod cross_chain_identity;
+mod dispatch;
+mod foreign_assets_erc20;
+mod groth16;
+mod kzg_point_evaluation;
+mod multisig;
+mod proof_submission;
+mod randomness;
+mod relay_storage_proof;
+
+use crate::{xcm_teleport::XcmTeleportPrecompile, Runtime, RuntimeOrigin};
+use aggregation::AggregationRootsPrecompile;
+use bls12381::{
+    Bls12381G1Add, Bls12381G1Msm, Bls12381G2Add, Bls12381G2Msm, Bls12381MapFp2ToG2,
+    Bls12381MapFpToG1, Bls12381PairingCheck,
+};
+use collator_staking::CollatorStakingPrecompile;
+use cross_chain_identity::CrossChainIdentityPrecompile;
+use dispatch::DispatchCallFilter;
+pub use foreign_assets_erc20::foreign_asset_address;
+use foreign_assets_erc20::{ForeignAssetsErc20Precompile, FOREIGN_ASSET_PREFIX};
+use fp_evm::PrecompileHandle;
+use frame_support::{parameter_types, traits::ConstU32};
+use groth16::Groth16VerifierPrecompile;
+use kzg_point_evaluation::KzgPointEvaluation;
+use multisig::MultisigPrecompile;
+#[cfg(test)]
+pub(crate) use multisig::PendingMultisig;
+use pallet_evm::AddressMapping;
 use pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata};
 use pallet_evm_precompile_batch::BatchPrecompile;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+use pallet_evm_precompile_call_permit::CallPermitPrecompile;
+use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_p256verify::P256Verify;
+use pallet_evm_precompile_registry::PrecompileRegistry;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_precompile_kill_switch::precompile_set::KillSwitchPrecompiles;
 use precompile_utils::precompile_set::*;
+use proof_submission::ProofSubmissionPrecompile;
+use randomness::RandomnessPrecompile;
+use relay_storage_proof::RelayStorageProofPrecompile;
+
+/// The signed origin of the EVM account calling the precompile.
+fn caller_origin(handle: &impl PrecompileHandle) -> RuntimeOrigin {
+    // We use IdentityAddressMapping, so no db access
+    let account_id =
+        <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
+    frame_system::RawOrigin::Signed(account_id).into()
+}
 
 pub struct NativeErc20Metadata;
 
@@ -49,6 +96,10 @@ impl Erc20Metadata for NativeErc20Metadata {
     }
 }
 
+parameter_types! {
+    pub ForeignAssetPrefix: &'static [u8] = &FOREIGN_ASSET_PREFIX;
+}
+
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
 
 #[precompile_utils::precompile_name_from_address]
@@ -64,9 +115,25 @@ type PrecompilesAt<R> = (
     PrecompileAt<AddressU64<7>, Bn128Mul, EthereumPrecompilesChecks>,
     PrecompileAt<AddressU64<8>, Bn128Pairing, EthereumPrecompilesChecks>,
     PrecompileAt<AddressU64<9>, Blake2F, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<10>, KzgPointEvaluation, EthereumPrecompilesChecks>,
+    // EIP-2537 BLS12-381 precompiles: G1MUL and G2MUL are served by the MSM ones.
+    PrecompileAt<AddressU64<11>, Bls12381G1Add, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<12>, Bls12381G1Msm, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<13>, Bls12381G2Add, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<14>, Bls12381G2Msm, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<15>, Bls12381PairingCheck, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<16>, Bls12381MapFpToG1, EthereumPrecompilesChecks>,
+    PrecompileAt<AddressU64<17>, Bls12381MapFp2ToG2, EthereumPrecompilesChecks>,
+    // RIP-7212 secp256r1 signature verification.
+    PrecompileAt<AddressU64<256>, P256Verify, EthereumPrecompilesChecks>,
     // Non-Moonbeam specific nor Ethereum precompiles :
     PrecompileAt<AddressU64<1024>, Sha3FIPS256, (CallableByContract, CallableByPrecompile)>,
     PrecompileAt<AddressU64<1025>, ECRecoverPublicKey, (CallableByContract, CallableByPrecompile)>,
+    PrecompileAt<
+        AddressU64<1026>,
+        Dispatch<R, DispatchCallFilter, ConstU32<8>>,
+        (CallableByContract, CallableByPrecompile),
+    >,
     // Moonbeam specific precompiles:
     PrecompileAt<
         AddressU64<2050>,
@@ -82,17 +149,78 @@ type PrecompilesAt<R> = (
             CallableByPrecompile<OnlyFrom<AddressU64<2056>>>,
         ),
     >,
+    PrecompileAt<
+        AddressU64<2058>,
+        CallPermitPrecompile<R>,
+        (SubcallWithMaxNesting<0>, CallableByContract),
+    >,
     PrecompileAt<
         AddressU64<2060>,
         XcmTeleportPrecompile<R>,
         (CallableByContract, CallableByPrecompile),
     >,
+    PrecompileAt<
+        AddressU64<2061>,
+        MultisigPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2062>,
+        CollatorStakingPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2063>,
+        ProofSubmissionPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2064>,
+        AggregationRootsPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2065>,
+        Groth16VerifierPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2066>,
+        CrossChainIdentityPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2067>,
+        RandomnessPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2068>,
+        RelayStorageProofPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2069>,
+        PrecompileRegistry<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
-pub type Precompiles<R> = PrecompileSetBuilder<
+/// The precompiles shipped with the runtime.
+pub type StaticPrecompiles<R> = PrecompileSetBuilder<
     R,
     (
         // Skip precompiles if out of range.
-        PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<2060>), PrecompilesAt<R>>,
+        PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<2069>), PrecompilesAt<R>>,
+        // ERC-20 precompiles of the registered foreign assets.
+        PrecompileSetStartingWith<
+            ForeignAssetPrefix,
+            ForeignAssetsErc20Precompile<R>,
+            (CallableByContract, CallableByPrecompile),
+        >,
     ),
 >;
+
+/// The precompiles exposed to the EVM: governance can disable any of them through
+/// `pallet_precompile_kill_switch`.
+pub type Precompiles<R> = KillSwitchPrecompiles<R, StaticPrecompiles<R>>;
@@ -0,0 +1,105 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile checking that a statement has been aggregated on zkVerify, against the aggregation
+//! roots delivered by the relay chain to `pallet_aggregation_roots`.
+
+use crate::Runtime;
+use frame_support::traits::ConstU32;
+use pallet_aggregation_roots::{AggregationId, DomainId};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The maximum length of a Merkle path: enough for any tree with up to `2^64` leaves.
+type GetMerklePathLimit = ConstU32<64>;
+
+/// The cost of hashing two nodes of the tree: the `KECCAK256` cost of 64 bytes.
+const HASH_NODES_GAS_COST: u64 = 30 + 6 * 2;
+
+pub struct AggregationRootsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl AggregationRootsPrecompile<Runtime> {
+    /// Returns `true` if `leaf`, the statement hash of a proof, is the `index`-th of the
+    /// `leafCount` leaves of the aggregation `aggregationId` published in `domainId`, and
+    /// `merklePath` is its Merkle proof. Returns `false` if the root of the aggregation has not
+    /// been delivered yet.
+    #[precompile::public("verifyProofAggregation(uint32,uint64,bytes32,bytes32[],uint256,uint256)")]
+    #[precompile::view]
+    fn verify_proof_aggregation(
+        handle: &mut impl PrecompileHandle,
+        domain_id: u32,
+        aggregation_id: u64,
+        leaf: H256,
+        merkle_path: BoundedVec<H256, GetMerklePathLimit>,
+        leaf_count: U256,
+        index: U256,
+    ) -> EvmResult<bool> {
+        let merkle_path = Vec::from(merkle_path);
+        Self::record_aggregation_root_read(handle)?;
+        // The leaf is hashed too.
+        handle.record_cost(HASH_NODES_GAS_COST.saturating_mul(merkle_path.len() as u64 + 1))?;
+
+        let leaf_count: u32 = leaf_count
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("u32").in_field("leafCount"))?;
+        let index: u32 = index
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("u32").in_field("index"))?;
+
+        Ok(
+            pallet_aggregation_roots::Pallet::<Runtime>::verify_proof_aggregation(
+                domain_id,
+                aggregation_id,
+                leaf,
+                merkle_path,
+                leaf_count,
+                index,
+            ),
+        )
+    }
+
+    /// Returns the root of the aggregation `aggregationId` published in `domainId`, or zero if
+    /// it has not been delivered yet.
+    #[precompile::public("aggregationRoot(uint32,uint64)")]
+    #[precompile::view]
+    fn aggregation_root(
+        handle: &mut impl PrecompileHandle,
+        domain_id: u32,
+        aggregation_id: u64,
+    ) -> EvmResult<H256> {
+        Self::record_aggregation_root_read(handle)?;
+
+        Ok(
+            pallet_aggregation_roots::AggregationRoots::<Runtime>::get(domain_id, aggregation_id)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+impl AggregationRootsPrecompile<Runtime> {
+    fn record_aggregation_root_read(handle: &mut impl PrecompileHandle) -> EvmResult {
+        // Storage item: AggregationRoots::AggregationRoots:
+        // Twox64Concat(8) + DomainId(4) + Twox64Concat(8) + AggregationId(8) + H256(32)
+        handle.record_db_read::<Runtime>(
+            8 + DomainId::max_encoded_len()
+                + 8
+                + AggregationId::max_encoded_len()
+                + H256::max_encoded_len(),
+        )
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! EIP-2537 BLS12-381 precompiles priced with the gas schedule of the final EIP.
+//!
+//! The operations are the ones of `pallet-evm-precompile-bls12381`, which charges the earlier
+//! draft schedule (e.g. 500 instead of 375 for G1ADD). Each precompile here charges the final
+//! price up front and runs the inner one with a handle that ignores the costs it records.
+//!
+//! The final EIP also requires the points of the MSM and pairing inputs to be in the prime order
+//! subgroup and rejects empty inputs: these rules are checked here, before running the inner
+//! precompile, so that they don't depend on the draft the inner precompiles implement.
+
+use ark_bls12_381::{Fq, Fq2, G1Affine, G2Affine};
+use ark_ff::Zero;
+use ark_serialize::CanonicalDeserialize;
+use fp_evm::{
+    Context, ExitError, ExitReason, Precompile, PrecompileFailure, PrecompileHandle,
+    PrecompileResult, Transfer,
+};
+use sp_core::{H160, H256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Gas cost of an EIP-2537 precompile for the given input.
+pub trait Eip2537GasCost {
+    fn gas_cost(input: &[u8]) -> u64;
+}
+
+/// Rules of the final EIP-2537 on the input of a precompile, checked before running it.
+pub trait Eip2537Input {
+    fn check(input: &[u8]) -> Result<(), &'static str>;
+}
+
+/// Precompile `P` charged with the gas cost `G` of the final EIP-2537, whose input follows `I`.
+pub struct Eip2537<P, G, I = InnerChecks>(PhantomData<(P, G, I)>);
+
+impl<P: Precompile, G: Eip2537GasCost, I: Eip2537Input> Precompile for Eip2537<P, G, I> {
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let gas = G::gas_cost(handle.input());
+        handle.record_cost(gas)?;
+
+        I::check(handle.input()).map_err(|error| PrecompileFailure::Error {
+            exit_status: ExitError::Other(error.into()),
+        })?;
+
+        P::execute(&mut UnmeteredHandle(handle))
+    }
+}
+
+pub type Bls12381G1Add =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381G1Add, FixedGasCost<G1_ADD_GAS>>;
+pub type Bls12381G1Msm =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381G1MultiExp, G1MsmGasCost, G1MsmInput>;
+pub type Bls12381G2Add =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381G2Add, FixedGasCost<G2_ADD_GAS>>;
+pub type Bls12381G2Msm =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381G2MultiExp, G2MsmGasCost, G2MsmInput>;
+pub type Bls12381PairingCheck =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381Pairing, PairingCheckGasCost, PairingInput>;
+pub type Bls12381MapFpToG1 =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381MapG1, FixedGasCost<MAP_FP_TO_G1_GAS>>;
+pub type Bls12381MapFp2ToG2 =
+    Eip2537<pallet_evm_precompile_bls12381::Bls12381MapG2, FixedGasCost<MAP_FP2_TO_G2_GAS>>;
+
+pub const G1_ADD_GAS: u64 = 375;
+pub const G2_ADD_GAS: u64 = 600;
+pub const G1_MUL_GAS: u64 = 12_000;
+pub const G2_MUL_GAS: u64 = 22_500;
+pub const PAIRING_CHECK_BASE_GAS: u64 = 37_700;
+pub const PAIRING_CHECK_PER_PAIR_GAS: u64 = 32_600;
+pub const MAP_FP_TO_G1_GAS: u64 = 5_500;
+pub const MAP_FP2_TO_G2_GAS: u64 = 23_800;
+
+/// Size of an encoded field element: 16 zero bytes and the 48 bytes of the element.
+const FP_SIZE: usize = 64;
+/// Size of the zero padding of an encoded field element.
+const FP_PADDING: usize = 16;
+/// Size of an encoded G1 point.
+const G1_SIZE: usize = 2 * FP_SIZE;
+/// Size of an encoded G2 point.
+const G2_SIZE: usize = 4 * FP_SIZE;
+/// Size of an encoded `(G1 point, scalar)` pair of the G1MSM input.
+const G1_MSM_PAIR_SIZE: usize = 160;
+/// Size of an encoded `(G2 point, scalar)` pair of the G2MSM input.
+const G2_MSM_PAIR_SIZE: usize = 288;
+/// Size of an encoded `(G1 point, G2 point)` pair of the PAIRING_CHECK input.
+const PAIRING_PAIR_SIZE: usize = 384;
+
+/// Per mille discount of G1MSM by number of pairs, from the final EIP-2537.
+const G1_MSM_DISCOUNTS: [u64; 128] = [
+    1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677, 673, 669, 665,
+    661, 658, 654, 651, 648, 645, 642, 640, 637, 635, 632, 630, 627, 625, 623, 621, 619, 617, 615,
+    613, 611, 609, 608, 606, 604, 603, 601, 599, 598, 596, 595, 593, 592, 591, 589, 588, 586, 585,
+    584, 582, 581, 580, 579, 577, 576, 575, 574, 573, 572, 570, 569, 568, 567, 566, 565, 564, 563,
+    562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547, 547, 546, 545,
+    544, 543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531, 530, 529,
+    528, 528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519,
+];
+
+/// Per mille discount of G2MSM by number of pairs, from the final EIP-2537.
+const G2_MSM_DISCOUNTS: [u64; 128] = [
+    1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717, 711, 704,
+    699, 693, 688, 683, 679, 674, 670, 666, 663, 659, 655, 652, 649, 646, 643, 640, 637, 634, 632,
+    629, 627, 624, 622, 620, 618, 615, 613, 611, 609, 607, 606, 604, 602, 600, 598, 597, 595, 593,
+    592, 590, 589, 587, 586, 584, 583, 582, 580, 579, 578, 576, 575, 574, 573, 571, 570, 569, 568,
+    567, 566, 565, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 552, 551, 550, 549,
+    548, 547, 546, 545, 545, 544, 543, 542, 541, 541, 540, 539, 538, 537, 537, 536, 535, 535, 534,
+    533, 532, 532, 531, 530, 530, 529, 528, 528, 527, 526, 526, 525, 524, 524,
+];
+
+pub struct FixedGasCost<const GAS: u64>;
+
+impl<const GAS: u64> Eip2537GasCost for FixedGasCost<GAS> {
+    fn gas_cost(_input: &[u8]) -> u64 {
+        GAS
+    }
+}
+
+pub struct G1MsmGasCost;
+
+impl Eip2537GasCost for G1MsmGasCost {
+    fn gas_cost(input: &[u8]) -> u64 {
+        msm_gas_cost(
+            input.len() / G1_MSM_PAIR_SIZE,
+            G1_MUL_GAS,
+            &G1_MSM_DISCOUNTS,
+        )
+    }
+}
+
+pub struct G2MsmGasCost;
+
+impl Eip2537GasCost for G2MsmGasCost {
+    fn gas_cost(input: &[u8]) -> u64 {
+        msm_gas_cost(
+            input.len() / G2_MSM_PAIR_SIZE,
+            G2_MUL_GAS,
+            &G2_MSM_DISCOUNTS,
+        )
+    }
+}
+
+pub struct PairingCheckGasCost;
+
+impl Eip2537GasCost for PairingCheckGasCost {
+    fn gas_cost(input: &[u8]) -> u64 {
+        let pairs = (input.len() / PAIRING_PAIR_SIZE) as u64;
+        PAIRING_CHECK_PER_PAIR_GAS
+            .saturating_mul(pairs)
+            .saturating_add(PAIRING_CHECK_BASE_GAS)
+    }
+}
+
+/// `k * multiplication_cost * discount(k) / 1000`, where the discount of the last entry applies
+/// to any larger `k`. An empty input costs nothing: the inner precompile rejects it.
+fn msm_gas_cost(pairs: usize, multiplication_cost: u64, discounts: &[u64; 128]) -> u64 {
+    if pairs == 0 {
+        return 0;
+    }
+    let discount = discounts[pairs.min(discounts.len()) - 1];
+
+    (pairs as u64)
+        .saturating_mul(multiplication_cost)
+        .saturating_mul(discount)
+        / 1000
+}
+
+/// Inputs whose rules are all checked by the inner precompile: the points of G1ADD and G2ADD
+/// only have to be on the curve, and the map inputs are single field elements.
+pub struct InnerChecks;
+
+impl Eip2537Input for InnerChecks {
+    fn check(_input: &[u8]) -> Result<(), &'static str> {
+        Ok(())
+    }
+}
+
+/// Non-empty list of `(G1 point, scalar)` pairs, whose points are in the subgroup.
+pub struct G1MsmInput;
+
+impl Eip2537Input for G1MsmInput {
+    fn check(input: &[u8]) -> Result<(), &'static str> {
+        pairs(input, G1_MSM_PAIR_SIZE)?.try_for_each(|pair| decode_g1(&pair[..G1_SIZE]).map(|_| ()))
+    }
+}
+
+/// Non-empty list of `(G2 point, scalar)` pairs, whose points are in the subgroup.
+pub struct G2MsmInput;
+
+impl Eip2537Input for G2MsmInput {
+    fn check(input: &[u8]) -> Result<(), &'static str> {
+        pairs(input, G2_MSM_PAIR_SIZE)?.try_for_each(|pair| decode_g2(&pair[..G2_SIZE]).map(|_| ()))
+    }
+}
+
+/// Non-empty list of `(G1 point, G2 point)` pairs, whose points are in the subgroups.
+pub struct PairingInput;
+
+impl Eip2537Input for PairingInput {
+    fn check(input: &[u8]) -> Result<(), &'static str> {
+        pairs(input, PAIRING_PAIR_SIZE)?.try_for_each(|pair| {
+            decode_g1(&pair[..G1_SIZE])?;
+            decode_g2(&pair[G1_SIZE..]).map(|_| ())
+        })
+    }
+}
+
+fn pairs(input: &[u8], pair_size: usize) -> Result<impl Iterator<Item = &[u8]>, &'static str> {
+    if input.is_empty() || input.len() % pair_size != 0 {
+        return Err("invalid input length");
+    }
+    Ok(input.chunks_exact(pair_size))
+}
+
+/// A field element: its padding is zero and it is lower than the modulus.
+fn decode_fp(encoded: &[u8]) -> Result<Fq, &'static str> {
+    let (padding, element) = encoded.split_at(FP_PADDING);
+    if padding.iter().any(|byte| *byte != 0) {
+        return Err("invalid field element top bytes");
+    }
+    let mut little_endian = [0u8; FP_SIZE - FP_PADDING];
+    little_endian.copy_from_slice(element);
+    little_endian.reverse();
+
+    Fq::deserialize_uncompressed(&little_endian[..]).map_err(|_| "invalid field element")
+}
+
+/// A point of G1: the point at infinity is encoded as zeros.
+fn decode_g1(encoded: &[u8]) -> Result<G1Affine, &'static str> {
+    let x = decode_fp(&encoded[..FP_SIZE])?;
+    let y = decode_fp(&encoded[FP_SIZE..])?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::identity());
+    }
+    let point = G1Affine::new_unchecked(x, y);
+    if !point.is_on_curve() {
+        return Err("point is not on curve");
+    }
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err("point is not in the subgroup");
+    }
+
+    Ok(point)
+}
+
+/// A point of G2, whose coordinates are encoded as `c0 || c1`: the point at infinity is encoded
+/// as zeros.
+fn decode_g2(encoded: &[u8]) -> Result<G2Affine, &'static str> {
+    let mut elements = encoded.chunks_exact(FP_SIZE).map(decode_fp);
+    let mut next = || elements.next().unwrap_or(Err("invalid input length"));
+    let x = Fq2::new(next()?, next()?);
+    let y = Fq2::new(next()?, next()?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::identity());
+    }
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() {
+        return Err("point is not on curve");
+    }
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err("point is not in the subgroup");
+    }
+
+    Ok(point)
+}
+
+/// Forwards everything to the wrapped handle but the costs, which are already paid.
+struct UnmeteredHandle<'a, H>(&'a mut H);
+
+impl<H: PrecompileHandle> PrecompileHandle for UnmeteredHandle<'_, H> {
+    fn call(
+        &mut self,
+        to: H160,
+        transfer: Option<Transfer>,
+        input: Vec<u8>,
+        gas_limit: Option<u64>,
+        is_static: bool,
+        context: &Context,
+    ) -> (ExitReason, Vec<u8>) {
+        self.0
+            .call(to, transfer, input, gas_limit, is_static, context)
+    }
+
+    fn record_cost(&mut self, _cost: u64) -> Result<(), ExitError> {
+        Ok(())
+    }
+
+    fn record_external_cost(
+        &mut self,
+        ref_time: Option<u64>,
+        proof_size: Option<u64>,
+        storage_growth: Option<u64>,
+    ) -> Result<(), ExitError> {
+        self.0
+            .record_external_cost(ref_time, proof_size, storage_growth)
+    }
+
+    fn refund_external_cost(&mut self, ref_time: Option<u64>, proof_size: Option<u64>) {
+        self.0.refund_external_cost(ref_time, proof_size)
+    }
+
+    fn remaining_gas(&self) -> u64 {
+        self.0.remaining_gas()
+    }
+
+    fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+        self.0.log(address, topics, data)
+    }
+
+    fn code_address(&self) -> H160 {
+        self.0.code_address()
+    }
+
+    fn input(&self) -> &[u8] {
+        self.0.input()
+    }
+
+    fn context(&self) -> &Context {
+        self.0.context()
+    }
+
+    fn is_static(&self) -> bool {
+        self.0.is_static()
+    }
+
+    fn gas_limit(&self) -> Option<u64> {
+        self.0.gas_limit()
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile exposing collator candidacy (`pallet_collator_selection`) and session keys
+//! management (`pallet_session`) to EVM accounts.
+
+use super::caller_origin;
+use crate::{
+    configs::consensus::{MaxCandidates, MaxInvulnerables},
+    AccountId, Balance, Runtime, SessionKeys,
+};
+use frame_support::traits::ConstU32;
+use pallet_collator_selection::CandidateInfo;
+use parity_scale_codec::{Decode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The maximum size of the SCALE encoded session keys and of their ownership proof.
+type GetKeysDataLimit = ConstU32<1024>;
+
+pub struct CollatorStakingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl CollatorStakingPrecompile<Runtime> {
+    #[precompile::public("registerAsCandidate()")]
+    fn register_as_candidate(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let call = pallet_collator_selection::Call::<Runtime>::register_as_candidate {};
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("leaveIntent()")]
+    fn leave_intent(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let call = pallet_collator_selection::Call::<Runtime>::leave_intent {};
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("updateBond(uint256)")]
+    fn update_bond(handle: &mut impl PrecompileHandle, new_deposit: U256) -> EvmResult {
+        let call = pallet_collator_selection::Call::<Runtime>::update_bond {
+            new_deposit: Self::balance(new_deposit, "newDeposit")?,
+        };
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("takeCandidateSlot(uint256,address)")]
+    fn take_candidate_slot(
+        handle: &mut impl PrecompileHandle,
+        deposit: U256,
+        target: Address,
+    ) -> EvmResult {
+        let call = pallet_collator_selection::Call::<Runtime>::take_candidate_slot {
+            deposit: Self::balance(deposit, "deposit")?,
+            target: target.0.into(),
+        };
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    /// `keys` are the SCALE encoded `SessionKeys` of the runtime.
+    #[precompile::public("setKeys(bytes,bytes)")]
+    fn set_keys(
+        handle: &mut impl PrecompileHandle,
+        keys: BoundedBytes<GetKeysDataLimit>,
+        proof: BoundedBytes<GetKeysDataLimit>,
+    ) -> EvmResult {
+        let keys: Vec<u8> = keys.into();
+        let keys = SessionKeys::decode(&mut &keys[..])
+            .map_err(|_| RevertReason::custom("Failed decoding session keys").in_field("keys"))?;
+
+        let call = pallet_session::Call::<Runtime>::set_keys {
+            keys,
+            proof: proof.into(),
+        };
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("purgeKeys()")]
+    fn purge_keys(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let call = pallet_session::Call::<Runtime>::purge_keys {};
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    /// Returns the candidates and their deposits.
+    #[precompile::public("candidates()")]
+    #[precompile::view]
+    fn candidates(handle: &mut impl PrecompileHandle) -> EvmResult<(Vec<Address>, Vec<U256>)> {
+        // Storage item: CandidateList: BoundedVec<CandidateInfo, MaxCandidates>
+        handle.record_db_read::<Runtime>(
+            CandidateInfo::<AccountId, Balance>::max_encoded_len()
+                .saturating_mul(MaxCandidates::get() as usize),
+        )?;
+
+        Ok(pallet_collator_selection::CandidateList::<Runtime>::get()
+            .into_iter()
+            .map(|candidate| (Address(candidate.who.into()), U256::from(candidate.deposit)))
+            .unzip())
+    }
+
+    #[precompile::public("isCandidate(address)")]
+    #[precompile::view]
+    fn is_candidate(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<bool> {
+        // Storage item: CandidateList: BoundedVec<CandidateInfo, MaxCandidates>
+        handle.record_db_read::<Runtime>(
+            CandidateInfo::<AccountId, Balance>::max_encoded_len()
+                .saturating_mul(MaxCandidates::get() as usize),
+        )?;
+
+        let who = AccountId::from(who.0);
+        Ok(pallet_collator_selection::CandidateList::<Runtime>::get()
+            .iter()
+            .any(|candidate| candidate.who == who))
+    }
+
+    #[precompile::public("invulnerables()")]
+    #[precompile::view]
+    fn invulnerables(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        // Storage item: Invulnerables: BoundedVec<AccountId, MaxInvulnerables>
+        handle.record_db_read::<Runtime>(
+            AccountId::max_encoded_len().saturating_mul(MaxInvulnerables::get() as usize),
+        )?;
+
+        Ok(pallet_collator_selection::Invulnerables::<Runtime>::get()
+            .into_iter()
+            .map(|account| Address(account.into()))
+            .collect())
+    }
+
+    #[precompile::public("candidacyBond()")]
+    #[precompile::view]
+    fn candidacy_bond(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // Storage item: CandidacyBond: Balance
+        handle.record_db_read::<Runtime>(Balance::max_encoded_len())?;
+
+        Ok(pallet_collator_selection::CandidacyBond::<Runtime>::get().into())
+    }
+
+    #[precompile::public("desiredCandidates()")]
+    #[precompile::view]
+    fn desired_candidates(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: DesiredCandidates: u32
+        handle.record_db_read::<Runtime>(u32::max_encoded_len())?;
+
+        Ok(pallet_collator_selection::DesiredCandidates::<Runtime>::get())
+    }
+}
+
+impl CollatorStakingPrecompile<Runtime> {
+    fn balance(amount: U256, field: &'static str) -> EvmResult<Balance> {
+        amount.try_into().map_err(|_| {
+            RevertReason::value_is_too_large("balance type")
+                .in_field(field)
+                .into()
+        })
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile exposing the conversions from XCM locations to local accounts used by the runtime,
+//! so that dapps derive the same addresses.
+
+use crate::{
+    configs::xcm::{LocationAccountId32ToAccountId, LocationToAccountId},
+    AccountId, Runtime,
+};
+use frame_support::traits::ConstU32;
+use parity_scale_codec::{DecodeLimit, Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_std::{marker::PhantomData, vec::Vec};
+use xcm::{latest::prelude::*, VersionedLocation, MAX_XCM_DECODE_DEPTH};
+use xcm_executor::traits::ConvertLocation;
+
+/// The maximum size of a SCALE encoded location.
+type GetLocationLimit = ConstU32<1024>;
+
+/// Base and per 32 bytes word gas of the `SHA256` precompile.
+const SHA256_BASE_GAS: u64 = 60;
+const SHA256_WORD_GAS: u64 = 12;
+
+pub struct CrossChainIdentityPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl CrossChainIdentityPrecompile<Runtime> {
+    /// Returns the address credited when assets are deposited to the `AccountId32` `account` of
+    /// this chain, e.g. by the teleport precompile: the address linked to `account` in
+    /// `AccountMapping` or, when there is none, the sovereign account of the relay chain
+    /// account `account`.
+    #[precompile::public("accountId32ToAddress(bytes32)")]
+    #[precompile::view]
+    fn account_id32_to_address(
+        handle: &mut impl PrecompileHandle,
+        account: H256,
+    ) -> EvmResult<Address> {
+        let location = Location::new(
+            0,
+            [AccountId32 {
+                network: None,
+                id: account.into(),
+            }],
+        );
+        Self::record_conversion_cost(handle, location.encoded_size())?;
+
+        Self::convert::<LocationAccountId32ToAccountId>(location)
+    }
+
+    /// Returns the address which dispatches the calls that the relay chain account `account`
+    /// sends here with XCM `Transact`.
+    #[precompile::public("relayAccountToAddress(bytes32)")]
+    #[precompile::view]
+    fn relay_account_to_address(
+        handle: &mut impl PrecompileHandle,
+        account: H256,
+    ) -> EvmResult<Address> {
+        let location = Location::new(
+            1,
+            [AccountId32 {
+                network: None,
+                id: account.into(),
+            }],
+        );
+        Self::record_conversion_cost(handle, location.encoded_size())?;
+
+        Self::convert::<LocationToAccountId>(location)
+    }
+
+    /// Returns the sovereign account of `location`, a SCALE encoded `VersionedLocation` as seen
+    /// from this chain.
+    #[precompile::public("sovereignAccountOf(bytes)")]
+    #[precompile::view]
+    fn sovereign_account_of(
+        handle: &mut impl PrecompileHandle,
+        location: BoundedBytes<GetLocationLimit>,
+    ) -> EvmResult<Address> {
+        let location: Vec<u8> = location.into();
+        Self::record_conversion_cost(handle, location.len())?;
+
+        let location =
+            VersionedLocation::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &location[..])
+                .ok()
+                .and_then(|location| Location::try_from(location).ok())
+                .ok_or_else(|| {
+                    RevertReason::custom("Failed decoding location").in_field("location")
+                })?;
+
+        Self::convert::<LocationToAccountId>(location)
+    }
+}
+
+impl CrossChainIdentityPrecompile<Runtime> {
+    /// Charges the conversion of a location of `encoded_len` bytes: decoding and hashing it are
+    /// priced as the `SHA256` precompile over the same bytes, and the lookup as a read of
+    /// `AccountMapping::LinkedAccounts`, which the runtime converters consult for `AccountId32`
+    /// locations.
+    fn record_conversion_cost(handle: &mut impl PrecompileHandle, encoded_len: usize) -> EvmResult {
+        let words = (encoded_len as u64).div_ceil(32);
+        handle
+            .record_cost(SHA256_BASE_GAS.saturating_add(SHA256_WORD_GAS.saturating_mul(words)))?;
+        // AccountMapping::LinkedAccounts: Blake2_128Concat(16) + AccountId32(32) + AccountId(20)
+        handle.record_db_read::<Runtime>(16 + 32 + AccountId::max_encoded_len())?;
+
+        Ok(())
+    }
+
+    fn convert<Converter: ConvertLocation<AccountId>>(location: Location) -> EvmResult<Address> {
+        Converter::convert_location(&location)
+            .map(|account| Address(account.into()))
+            .ok_or_else(|| revert("Location has no local account"))
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Filter of the SCALE encoded calls that EVM accounts can dispatch through the dispatch
+//! precompile.
+
+use crate::{AccountId, RuntimeCall};
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{DispatchClass, GetDispatchInfo, Pays};
+use pallet_evm_precompile_dispatch::DispatchValidateT;
+
+/// Only calls of the allowed pallets can be dispatched. The calls wrapped by `Utility`, `Proxy`
+/// and `Multisig` are checked as well, so that a batch cannot smuggle in a call of any other
+/// pallet.
+pub struct DispatchCallFilter;
+
+impl DispatchCallFilter {
+    fn is_allowed(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => {
+                calls.iter().all(Self::is_allowed)
+            }
+            RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. })
+            | RuntimeCall::Utility(pallet_utility::Call::dispatch_as { call, .. })
+            | RuntimeCall::Utility(pallet_utility::Call::with_weight { call, .. })
+            | RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. })
+            | RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { call, .. })
+            | RuntimeCall::Multisig(pallet_multisig::Call::as_multi { call, .. })
+            | RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) => {
+                Self::is_allowed(call)
+            }
+            RuntimeCall::Utility(..)
+            | RuntimeCall::Proxy(..)
+            | RuntimeCall::Multisig(..)
+            | RuntimeCall::ZKVXcm(..) => true,
+            _ => false,
+        }
+    }
+}
+
+impl DispatchValidateT<AccountId, RuntimeCall> for DispatchCallFilter {
+    fn validate_before_dispatch(
+        _origin: &AccountId,
+        call: &RuntimeCall,
+    ) -> Option<PrecompileFailure> {
+        let info = call.get_dispatch_info();
+        let allowed = Self::is_allowed(call)
+            && info.pays_fee == Pays::Yes
+            && info.class == DispatchClass::Normal;
+
+        (!allowed).then(|| PrecompileFailure::Error {
+            exit_status: ExitError::Other("call not allowed".into()),
+        })
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! ERC-20 precompiles of the foreign assets registered in `pallet_asset_registry`.
+//!
+//! Each registered asset is exposed at the address made of `FOREIGN_ASSET_PREFIX`, 12 zero bytes
+//! and its `u32` identifier as big-endian in the last 4 bytes, see [`foreign_asset_address`].
+//! Balances and allowances are the ones held by `pallet_assets`, so the same tokens can be moved
+//! through XCM and from the EVM. Contracts checking the code size of the token get the dummy code
+//! once `updateAccountCode` of the precompile registry is called for its address.
+
+use super::caller_origin;
+use crate::{
+    configs::monetary::AssetsStringLimit, types::AssetId, AccountId, Balance, ForeignAssets,
+    Runtime,
+};
+use frame_support::traits::fungibles::{approvals, metadata, Inspect};
+use pallet_assets::{Approval, AssetAccount, AssetDetails, AssetMetadata};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::BoundedVec;
+use sp_std::marker::PhantomData;
+
+/// The first bytes of the address of every foreign asset precompile.
+pub const FOREIGN_ASSET_PREFIX: [u8; 4] = [0xff; 4];
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Storage item: ForeignAssets::Asset: Blake2_128Concat(16) + AssetId(4) + AssetDetails(142)
+fn asset_details_size() -> usize {
+    16 + 4 + AssetDetails::<Balance, AccountId, Balance>::max_encoded_len()
+}
+
+/// Storage item: ForeignAssets::Account:
+/// Blake2_128Concat(16) + AssetId(4) + Blake2_128Concat(16) + AccountId(20) + AssetAccount(54)
+fn asset_account_size() -> usize {
+    16 + 4 + 16 + 20 + AssetAccount::<Balance, Balance, (), AccountId>::max_encoded_len()
+}
+
+/// Storage item: ForeignAssets::Approvals: Blake2_128Concat(16) + AssetId(4) +
+/// Blake2_128Concat(16) + AccountId(20) + Blake2_128Concat(16) + AccountId(20) + Approval(32)
+fn approval_size() -> usize {
+    16 + 4 + 16 + 20 + 16 + 20 + Approval::<Balance, Balance>::max_encoded_len()
+}
+
+/// Storage item: ForeignAssets::Metadata: Blake2_128Concat(16) + AssetId(4) + AssetMetadata(120)
+fn metadata_size() -> usize {
+    16 + 4 + AssetMetadata::<Balance, BoundedVec<u8, AssetsStringLimit>>::max_encoded_len()
+}
+
+/// Returns the address of the ERC-20 precompile of the foreign asset `asset_id`.
+pub fn foreign_asset_address(asset_id: AssetId) -> H160 {
+    let mut address = [0u8; 20];
+    address[..4].copy_from_slice(&FOREIGN_ASSET_PREFIX);
+    address[16..].copy_from_slice(&asset_id.to_be_bytes());
+    H160(address)
+}
+
+/// Returns the foreign asset identifier encoded in `address`, if any.
+fn address_to_asset_id(address: H160) -> Option<AssetId> {
+    let (prefix, rest) = address.as_bytes().split_at(16);
+    (prefix[..4] == FOREIGN_ASSET_PREFIX && prefix[4..].iter().all(|b| *b == 0))
+        .then(|| AssetId::from_be_bytes(rest.try_into().expect("4 bytes left; qed")))
+}
+
+pub struct ForeignAssetsErc20Precompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+#[precompile::precompile_set]
+impl ForeignAssetsErc20Precompile<Runtime> {
+    /// Only the addresses of the registered foreign assets are precompiles.
+    #[precompile::discriminant]
+    fn discriminant(address: H160, gas: u64) -> DiscriminantResult<AssetId> {
+        let extra_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+        if gas < extra_cost {
+            return DiscriminantResult::OutOfGas;
+        }
+
+        match address_to_asset_id(address) {
+            Some(asset_id)
+                if pallet_asset_registry::AssetIdToLocation::<Runtime>::contains_key(asset_id) =>
+            {
+                DiscriminantResult::Some(asset_id, extra_cost)
+            }
+            _ => DiscriminantResult::None(extra_cost),
+        }
+    }
+
+    #[precompile::public("name()")]
+    #[precompile::view]
+    fn name(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+        handle.record_db_read::<Runtime>(metadata_size())?;
+
+        Ok(<ForeignAssets as metadata::Inspect<AccountId>>::name(asset_id).into())
+    }
+
+    #[precompile::public("symbol()")]
+    #[precompile::view]
+    fn symbol(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+        handle.record_db_read::<Runtime>(metadata_size())?;
+
+        Ok(<ForeignAssets as metadata::Inspect<AccountId>>::symbol(asset_id).into())
+    }
+
+    #[precompile::public("decimals()")]
+    #[precompile::view]
+    fn decimals(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        handle.record_db_read::<Runtime>(metadata_size())?;
+
+        Ok(<ForeignAssets as metadata::Inspect<AccountId>>::decimals(
+            asset_id,
+        ))
+    }
+
+    #[precompile::public("totalSupply()")]
+    #[precompile::view]
+    fn total_supply(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_db_read::<Runtime>(asset_details_size())?;
+
+        Ok(<ForeignAssets as Inspect<AccountId>>::total_issuance(asset_id).into())
+    }
+
+    #[precompile::public("balanceOf(address)")]
+    #[precompile::view]
+    fn balance_of(
+        asset_id: AssetId,
+        handle: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<U256> {
+        handle.record_db_read::<Runtime>(asset_account_size())?;
+
+        Ok(<ForeignAssets as Inspect<AccountId>>::balance(asset_id, &Self::account_id(who)).into())
+    }
+
+    #[precompile::public("allowance(address,address)")]
+    #[precompile::view]
+    fn allowance(
+        asset_id: AssetId,
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+        spender: Address,
+    ) -> EvmResult<U256> {
+        handle.record_db_read::<Runtime>(approval_size())?;
+
+        Ok(<ForeignAssets as approvals::Inspect<AccountId>>::allowance(
+            asset_id,
+            &Self::account_id(owner),
+            &Self::account_id(spender),
+        )
+        .into())
+    }
+
+    /// Sets the allowance of `spender` over the caller tokens to `value`.
+    #[precompile::public("approve(address,uint256)")]
+    fn approve(
+        asset_id: AssetId,
+        handle: &mut impl PrecompileHandle,
+        spender: Address,
+        value: U256,
+    ) -> EvmResult<bool> {
+        let owner = handle.context().caller;
+        let amount = Self::u256_to_amount(value)?;
+        let delegate = Self::account_id(spender);
+
+        // `approve_transfer` adds to the current allowance, so we remove it first.
+        handle.record_db_read::<Runtime>(approval_size())?;
+        if <ForeignAssets as approvals::Inspect<AccountId>>::allowance(
+            asset_id,
+            &Self::account_id(owner.into()),
+            &delegate,
+        ) != 0
+        {
+            let call = pallet_assets::Call::<Runtime>::cancel_approval {
+                id: asset_id,
+                delegate,
+            };
+            let origin = caller_origin(handle);
+            RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+        }
+        if amount != 0 {
+            let call = pallet_assets::Call::<Runtime>::approve_transfer {
+                id: asset_id,
+                delegate,
+                amount,
+            };
+            let origin = caller_origin(handle);
+            RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, approval_size() as u64)?;
+        }
+
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_APPROVAL,
+            owner,
+            spender.0,
+            solidity::encode_event_data(value),
+        )
+        .record(handle)?;
+
+        Ok(true)
+    }
+
+    /// Moves `value` tokens from the caller to `to`.
+    #[precompile::public("transfer(address,uint256)")]
+    fn transfer(
+        asset_id: AssetId,
+        handle: &mut impl PrecompileHandle,
+        to: Address,
+        value: U256,
+    ) -> EvmResult<bool> {
+        let amount = Self::u256_to_amount(value)?;
+
+        let call = pallet_assets::Call::<Runtime>::transfer {
+            id: asset_id,
+            target: Self::account_id(to),
+            amount,
+        };
+        let origin = caller_origin(handle);
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, asset_account_size() as u64)?;
+
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER,
+            handle.context().caller,
+            to.0,
+            solidity::encode_event_data(value),
+        )
+        .record(handle)?;
+
+        Ok(true)
+    }
+
+    /// Moves `value` tokens from `from` to `to` using the allowance of the caller, unless the
+    /// caller is `from` itself.
+    #[precompile::public("transferFrom(address,address,uint256)")]
+    fn transfer_from(
+        asset_id: AssetId,
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> EvmResult<bool> {
+        let amount = Self::u256_to_amount(value)?;
+
+        if handle.context().caller == from.0 {
+            let call = pallet_assets::Call::<Runtime>::transfer {
+                id: asset_id,
+                target: Self::account_id(to),
+                amount,
+            };
+            let origin = caller_origin(handle);
+            RuntimeHelper::<Runtime>::try_dispatch(
+                handle,
+                origin,
+                call,
+                asset_account_size() as u64,
+            )?;
+        } else {
+            let call = pallet_assets::Call::<Runtime>::transfer_approved {
+                id: asset_id,
+                owner: Self::account_id(from),
+                destination: Self::account_id(to),
+                amount,
+            };
+            let origin = caller_origin(handle);
+            RuntimeHelper::<Runtime>::try_dispatch(
+                handle,
+                origin,
+                call,
+                asset_account_size() as u64,
+            )?;
+        }
+
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER,
+            from.0,
+            to.0,
+            solidity::encode_event_data(value),
+        )
+        .record(handle)?;
+
+        Ok(true)
+    }
+}
+
+impl ForeignAssetsErc20Precompile<Runtime> {
+    fn account_id(address: Address) -> AccountId {
+        // We use IdentityAddressMapping, so no db access
+        <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address.0)
+    }
+
+    fn u256_to_amount(value: U256) -> MayRevert<Balance> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("balance type").in_field("value"))
+    }
+}
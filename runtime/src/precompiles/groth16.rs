@@ -0,0 +1,146 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile verifying Groth16 proofs over BN254 natively.
+//!
+//! Points are encoded as for the Ethereum `ECADD`, `ECMUL` and `ECPAIRING` precompiles (EIP-196
+//! and EIP-197), which is also what the Solidity verifiers exported by snarkjs use: big endian
+//! coordinates, with the imaginary part first for `Fq2` elements, and `(0, 0)` for the point at
+//! infinity.
+
+use crate::Runtime;
+use bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use frame_support::traits::ConstU32;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The maximum number of public inputs.
+pub const MAX_PUBLIC_INPUTS: u32 = 64;
+
+type GetPublicInputsLimit = ConstU32<MAX_PUBLIC_INPUTS>;
+type GetVkLimit = ConstU32<{ VK_FIXED_SIZE as u32 + G1_SIZE as u32 * (MAX_PUBLIC_INPUTS + 1) }>;
+type GetProofLimit = ConstU32<{ PROOF_SIZE as u32 }>;
+
+const FQ_SIZE: usize = 32;
+const G1_SIZE: usize = 2 * FQ_SIZE;
+const G2_SIZE: usize = 4 * FQ_SIZE;
+/// `alpha`, `beta`, `gamma` and `delta`: followed by the `IC` points, one more than the inputs.
+const VK_FIXED_SIZE: usize = G1_SIZE + 3 * G2_SIZE;
+/// `A`, `B` and `C`.
+const PROOF_SIZE: usize = 2 * G1_SIZE + G2_SIZE;
+
+// Cost of the verification, derived from the execution time of `verify` compiled to WASM: the
+// least squares fit of its best time over 30 runs with 0 to 64 public inputs is 31.1ms plus
+// 1.36ms per input on an Intel Xeon (V8), converted at `GAS_PER_SECOND` and rounded up.
+// Re-measure them if the verifier or `GAS_PER_SECOND` change.
+const VERIFICATION_BASE_GAS_COST: u64 = 470_000;
+const VERIFICATION_PER_INPUT_GAS_COST: u64 = 20_500;
+
+pub struct Groth16VerifierPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl Groth16VerifierPrecompile<Runtime> {
+    /// Returns `true` if `proof` (`A`, `B`, `C`) is valid for the verification key `vk` (`alpha`,
+    /// `beta`, `gamma`, `delta`, `IC`) and the `publicInputs`. Reverts if the encoding of any of
+    /// them is invalid.
+    #[precompile::public("verifyGroth16(bytes,bytes,uint256[])")]
+    #[precompile::view]
+    fn verify_groth16(
+        handle: &mut impl PrecompileHandle,
+        vk: BoundedBytes<GetVkLimit>,
+        proof: BoundedBytes<GetProofLimit>,
+        public_inputs: BoundedVec<U256, GetPublicInputsLimit>,
+    ) -> EvmResult<bool> {
+        let public_inputs = Vec::from(public_inputs);
+        handle.record_cost(Self::verification_gas_cost(public_inputs.len() as u64))?;
+
+        verify(&Vec::from(vk), &Vec::from(proof), &public_inputs).map_err(revert)
+    }
+}
+
+impl Groth16VerifierPrecompile<Runtime> {
+    /// The check is a pairing of four points, the linear combination of the `IC` points costs a
+    /// multiplication and an addition per input.
+    fn verification_gas_cost(inputs: u64) -> u64 {
+        VERIFICATION_BASE_GAS_COST
+            .saturating_add(inputs.saturating_mul(VERIFICATION_PER_INPUT_GAS_COST))
+    }
+}
+
+/// Verifies a Groth16 proof: `e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1`,
+/// where `vk_x = IC[0] + sum(publicInputs[i] * IC[i + 1])`.
+pub fn verify(vk: &[u8], proof: &[u8], public_inputs: &[U256]) -> Result<bool, &'static str> {
+    if vk.len() != VK_FIXED_SIZE + G1_SIZE * (public_inputs.len() + 1) {
+        return Err("Verification key size does not match the public inputs");
+    }
+    if proof.len() != PROOF_SIZE {
+        return Err("Invalid proof size");
+    }
+
+    let alpha = read_g1(&vk[..G1_SIZE])?;
+    let beta = read_g2(&vk[G1_SIZE..G1_SIZE + G2_SIZE])?;
+    let gamma = read_g2(&vk[G1_SIZE + G2_SIZE..G1_SIZE + 2 * G2_SIZE])?;
+    let delta = read_g2(&vk[G1_SIZE + 2 * G2_SIZE..VK_FIXED_SIZE])?;
+    let mut ic = vk[VK_FIXED_SIZE..].chunks_exact(G1_SIZE).map(read_g1);
+
+    let mut vk_x = ic.next().expect("size checked above; qed")?;
+    for (point, input) in ic.zip(public_inputs) {
+        let input = Fr::from_slice(&input.to_big_endian()).map_err(|_| "Invalid public input")?;
+        vk_x = vk_x + point? * input;
+    }
+
+    let a = read_g1(&proof[..G1_SIZE])?;
+    let b = read_g2(&proof[G1_SIZE..G1_SIZE + G2_SIZE])?;
+    let c = read_g1(&proof[G1_SIZE + G2_SIZE..])?;
+
+    Ok(pairing_batch(&[(-a, b), (alpha, beta), (vk_x, gamma), (c, delta)]) == Gt::one())
+}
+
+fn read_fq(input: &[u8]) -> Result<Fq, &'static str> {
+    Fq::from_slice(input).map_err(|_| "Invalid field element")
+}
+
+fn read_g1(input: &[u8]) -> Result<G1, &'static str> {
+    let x = read_fq(&input[..FQ_SIZE])?;
+    let y = read_fq(&input[FQ_SIZE..G1_SIZE])?;
+
+    if x == Fq::zero() && y == Fq::zero() {
+        Ok(G1::zero())
+    } else {
+        AffineG1::new(x, y)
+            .map(Into::into)
+            .map_err(|_| "Invalid G1 point")
+    }
+}
+
+fn read_g2(input: &[u8]) -> Result<G2, &'static str> {
+    let x = Fq2::new(
+        read_fq(&input[FQ_SIZE..2 * FQ_SIZE])?,
+        read_fq(&input[..FQ_SIZE])?,
+    );
+    let y = Fq2::new(
+        read_fq(&input[3 * FQ_SIZE..G2_SIZE])?,
+        read_fq(&input[2 * FQ_SIZE..3 * FQ_SIZE])?,
+    );
+
+    if x == Fq2::zero() && y == Fq2::zero() {
+        Ok(G2::zero())
+    } else {
+        AffineG2::new(x, y)
+            .map(Into::into)
+            .map_err(|_| "Invalid G2 point")
+    }
+}
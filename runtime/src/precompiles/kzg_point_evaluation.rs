@@ -0,0 +1,129 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! EIP-4844 point evaluation precompile: verifies that the polynomial committed to by a blob
+//! commitment evaluates to `y` at `z`.
+
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use fp_evm::{
+    ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+    PrecompileResult,
+};
+use hex_literal::hex;
+use sp_std::vec::Vec;
+
+/// Gas cost of the precompile, as defined by EIP-4844.
+pub const POINT_EVALUATION_GAS_COST: u64 = 50_000;
+
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+const FIELD_ELEMENTS_PER_BLOB: u64 = 4096;
+/// Modulus of the BLS12-381 scalar field, big endian.
+const BLS_MODULUS: [u8; 32] =
+    hex!("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+
+/// `[tau]G2` from the trusted setup of the Ethereum KZG ceremony: the only point of the setup
+/// needed to verify an opening.
+const TAU_G2: [u8; 96] = hex!(
+    "b5bfd7dd8cdeb128843bc287230af38926187075cbfbefa81009a2ce615ac53d"
+    "2914e5870cb452d2afaaab24f3499f72185cbfee53492714734429b7b38608e2"
+    "3926c911cceceac9a36851477ba4c60b087041de621000edc98edada20c1def2"
+);
+
+pub struct KzgPointEvaluation;
+
+impl Precompile for KzgPointEvaluation {
+    /// The input is `versioned_hash | z | y | commitment | proof`, 192 bytes: on success it
+    /// returns `FIELD_ELEMENTS_PER_BLOB | BLS_MODULUS`, both as 32 bytes big endian integers.
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        handle.record_cost(POINT_EVALUATION_GAS_COST)?;
+
+        let input = handle.input();
+        if input.len() != 192 {
+            return Err(error("invalid input length"));
+        }
+        let versioned_hash = &input[..32];
+        let z = &input[32..64];
+        let y = &input[64..96];
+        let commitment = &input[96..144];
+        let proof = &input[144..192];
+
+        if kzg_to_versioned_hash(commitment) != versioned_hash {
+            return Err(error("mismatched versioned hash"));
+        }
+        if !verify_kzg_proof(commitment, read_scalar(z)?, read_scalar(y)?, proof)? {
+            return Err(error("invalid proof"));
+        }
+
+        let mut output = Vec::with_capacity(64);
+        output.extend_from_slice(&[0u8; 24]);
+        output.extend_from_slice(&FIELD_ELEMENTS_PER_BLOB.to_be_bytes());
+        output.extend_from_slice(&BLS_MODULUS);
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+}
+
+fn error(message: &'static str) -> PrecompileFailure {
+    PrecompileFailure::Error {
+        exit_status: ExitError::Other(message.into()),
+    }
+}
+
+fn kzg_to_versioned_hash(commitment: &[u8]) -> [u8; 32] {
+    let mut hash = sp_io::hashing::sha2_256(commitment);
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    hash
+}
+
+/// Reads a canonical, i.e. reduced, element of the scalar field.
+fn read_scalar(input: &[u8]) -> Result<Fr, PrecompileFailure> {
+    // Both are 32 bytes big endian integers.
+    if input >= BLS_MODULUS.as_slice() {
+        return Err(error("field element not canonical"));
+    }
+    Ok(Fr::from_be_bytes_mod_order(input))
+}
+
+/// Reads a compressed point, checking that it is in the right subgroup.
+fn read_point<P: CanonicalDeserialize>(input: &[u8]) -> Result<P, PrecompileFailure> {
+    P::deserialize_with_mode(input, Compress::Yes, Validate::Yes)
+        .map_err(|_| error("invalid point"))
+}
+
+/// Checks `e(commitment - [y]G1, -G2) * e(proof, [tau]G2 - [z]G2) == 1`.
+fn verify_kzg_proof(
+    commitment: &[u8],
+    z: Fr,
+    y: Fr,
+    proof: &[u8],
+) -> Result<bool, PrecompileFailure> {
+    let commitment: G1Affine = read_point(commitment)?;
+    let proof: G1Affine = read_point(proof)?;
+    let tau_g2: G2Affine = read_point(&TAU_G2)?;
+
+    let p_minus_y = commitment.into_group() - G1Affine::generator() * y;
+    let x_minus_z = tau_g2.into_group() - G2Affine::generator() * z;
+
+    Ok(Bls12_381::multi_pairing(
+        [p_minus_y, proof.into_group()],
+        [-G2Affine::generator().into_group(), x_minus_z],
+    )
+    .is_zero())
+}
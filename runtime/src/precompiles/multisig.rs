@@ -0,0 +1,212 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile exposing `pallet_multisig` to EVM accounts.
+
+use super::caller_origin;
+use crate::{
+    configs::system::MaxSignatories, AccountId, Balance, BlockNumber, Runtime, RuntimeCall,
+};
+use frame_support::{traits::ConstU32, MAX_EXTRINSIC_DEPTH};
+use pallet_multisig::{Multisig, Timepoint};
+use parity_scale_codec::{Decode, DecodeAll, DecodeLimit, Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+use sp_weights::Weight;
+
+/// The maximum size of the SCALE encoded call accepted by `asMulti`.
+type GetCallDataLimit = ConstU32<{ 2u32.pow(16) }>;
+
+type MultisigOf = Multisig<BlockNumber, Balance, AccountId, MaxSignatories>;
+
+/// Readable view of `pallet_multisig::Multisig`, whose fields are not public. It is decoded from
+/// the public encoding of the value returned by the pallet's storage getter. The runtime tests pin
+/// its fields to the pallet's type info and check that it re-encodes to the stored value, so an
+/// upstream layout change fails the tests instead of misreading storage.
+#[derive(Decode, Encode, TypeInfo)]
+pub(crate) struct PendingMultisig {
+    when: Timepoint<BlockNumber>,
+    deposit: Balance,
+    depositor: AccountId,
+    approvals: frame_support::BoundedVec<AccountId, MaxSignatories>,
+}
+
+impl TryFrom<MultisigOf> for PendingMultisig {
+    type Error = parity_scale_codec::Error;
+
+    fn try_from(multisig: MultisigOf) -> Result<Self, Self::Error> {
+        Self::decode_all(&mut &multisig.encode()[..])
+    }
+}
+
+pub struct MultisigPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl MultisigPrecompile<Runtime> {
+    #[precompile::public("asMulti(uint16,address[],uint32,uint32,bytes,uint64,uint64)")]
+    fn as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: BoundedVec<Address, MaxSignatories>,
+        timepoint_height: u32,
+        timepoint_index: u32,
+        call: BoundedBytes<GetCallDataLimit>,
+        max_weight_ref_time: u64,
+        max_weight_proof_size: u64,
+    ) -> EvmResult {
+        let call: Vec<u8> = call.into();
+        let call = RuntimeCall::decode_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &call[..])
+            .map_err(|_| RevertReason::custom("Failed decoding call").in_field("call"))?;
+
+        let call = pallet_multisig::Call::<Runtime>::as_multi {
+            threshold,
+            other_signatories: Self::sorted_accounts(other_signatories),
+            maybe_timepoint: Self::timepoint(timepoint_height, timepoint_index),
+            call: Box::new(call),
+            max_weight: Weight::from_parts(max_weight_ref_time, max_weight_proof_size),
+        };
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("approveAsMulti(uint16,address[],uint32,uint32,bytes32,uint64,uint64)")]
+    fn approve_as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: BoundedVec<Address, MaxSignatories>,
+        timepoint_height: u32,
+        timepoint_index: u32,
+        call_hash: H256,
+        max_weight_ref_time: u64,
+        max_weight_proof_size: u64,
+    ) -> EvmResult {
+        let call = pallet_multisig::Call::<Runtime>::approve_as_multi {
+            threshold,
+            other_signatories: Self::sorted_accounts(other_signatories),
+            maybe_timepoint: Self::timepoint(timepoint_height, timepoint_index),
+            call_hash: call_hash.into(),
+            max_weight: Weight::from_parts(max_weight_ref_time, max_weight_proof_size),
+        };
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("cancelAsMulti(uint16,address[],uint32,uint32,bytes32)")]
+    fn cancel_as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: BoundedVec<Address, MaxSignatories>,
+        timepoint_height: u32,
+        timepoint_index: u32,
+        call_hash: H256,
+    ) -> EvmResult {
+        let call = pallet_multisig::Call::<Runtime>::cancel_as_multi {
+            threshold,
+            other_signatories: Self::sorted_accounts(other_signatories),
+            timepoint: Timepoint {
+                height: timepoint_height,
+                index: timepoint_index,
+            },
+            call_hash: call_hash.into(),
+        };
+
+        let origin = caller_origin(handle);
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("multisigAccount(address[],uint16)")]
+    #[precompile::view]
+    fn multisig_account(
+        _handle: &mut impl PrecompileHandle,
+        signatories: BoundedVec<Address, MaxSignatories>,
+        threshold: u16,
+    ) -> EvmResult<Address> {
+        let account = pallet_multisig::Pallet::<Runtime>::multi_account_id(
+            &Self::sorted_accounts(signatories),
+            threshold,
+        );
+
+        Ok(Address(account.into()))
+    }
+
+    /// Returns `(exists, timepointHeight, timepointIndex, deposit, depositor, approvals)` for the
+    /// operation identified by `callHash` on the `multisig` account.
+    #[precompile::public("pendingMultisig(address,bytes32)")]
+    #[precompile::view]
+    fn pending_multisig(
+        handle: &mut impl PrecompileHandle,
+        multisig: Address,
+        call_hash: H256,
+    ) -> EvmResult<(bool, u32, u32, U256, Address, Vec<Address>)> {
+        // Storage item: Multisigs: Multisig<BlockNumber, Balance, AccountId, MaxSignatories>
+        handle.record_db_read::<Runtime>(MultisigOf::max_encoded_len())?;
+
+        let pending = pallet_multisig::Multisigs::<Runtime>::get(
+            AccountId::from(multisig.0),
+            <[u8; 32]>::from(call_hash),
+        )
+        .map(PendingMultisig::try_from)
+        .transpose()
+        .map_err(|_| revert("Failed decoding multisig"))?;
+
+        Ok(match pending {
+            Some(pending) => (
+                true,
+                pending.when.height,
+                pending.when.index,
+                pending.deposit.into(),
+                Address(pending.depositor.into()),
+                pending
+                    .approvals
+                    .into_iter()
+                    .map(|account| Address(account.into()))
+                    .collect(),
+            ),
+            None => (false, 0, 0, U256::zero(), Address(H160::zero()), Vec::new()),
+        })
+    }
+}
+
+impl MultisigPrecompile<Runtime> {
+    /// `pallet_multisig` requires the signatories to be sorted: do it here so that wallets can
+    /// pass them in any order.
+    fn sorted_accounts(addresses: BoundedVec<Address, MaxSignatories>) -> Vec<AccountId> {
+        let mut accounts = Vec::<Address>::from(addresses)
+            .into_iter()
+            .map(|address| AccountId::from(address.0))
+            .collect::<Vec<_>>();
+        accounts.sort();
+        accounts
+    }
+
+    /// Solidity has no optional values: a zero height means no timepoint, which is what the first
+    /// approval of a new operation must provide.
+    fn timepoint(height: u32, index: u32) -> Option<Timepoint<BlockNumber>> {
+        (height != 0).then_some(Timepoint { height, index })
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile submitting proofs to the zkVerify relay chain verifier pallets through XCM.
+//!
+//! The message is sent with the caller's origin: on the relay chain the proof is submitted, and
+//! the execution paid in VFY, by the account derived from the caller's location (see
+//! `relayAccountOf`). That account has to be funded beforehand, e.g. by teleporting tVFY to it.
+
+use crate::{
+    configs::xcm::{RelayLocation, RelayNetwork},
+    ParachainInfo, Runtime, RuntimeOrigin,
+};
+use frame_support::traits::ConstU32;
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_std::{boxed::Box, marker::PhantomData, vec, vec::Vec};
+use xcm::{
+    latest::prelude::*,
+    {VersionedLocation, VersionedXcm},
+};
+use xcm_builder::{DescribeAllTerminal, DescribeFamily, HashedDescription};
+use xcm_executor::traits::ConvertLocation;
+
+/// The maximum size of each of the SCALE encoded verification key, proof and public inputs.
+type GetProofDataLimit = ConstU32<{ 2u32.pow(16) }>;
+
+/// Index of `submit_proof` in the calls of the zkVerify verifier pallets.
+const SUBMIT_PROOF_CALL_INDEX: u8 = 0;
+
+/// `VkOrHash::Vk` variant of the zkVerify verifier pallets.
+const VK_VARIANT: u8 = 1;
+
+pub struct ProofSubmissionPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl ProofSubmissionPrecompile<Runtime> {
+    /// `verifier` is the index of the verifier pallet in the relay chain runtime, `vk`, `proof`
+    /// and `publicInputs` are SCALE encoded as that pallet expects them. `fee` is the amount of
+    /// VFY withdrawn on the relay chain to pay for the execution: the surplus is refunded to the
+    /// relay chain account of the caller.
+    #[precompile::public("submitProof(uint8,bytes,bytes,bytes,uint256)")]
+    fn submit_proof(
+        handle: &mut impl PrecompileHandle,
+        verifier: u8,
+        vk: BoundedBytes<GetProofDataLimit>,
+        proof: BoundedBytes<GetProofDataLimit>,
+        public_inputs: BoundedBytes<GetProofDataLimit>,
+        fee: U256,
+    ) -> EvmResult {
+        Self::send_submit_proof(handle, verifier, vk, proof, public_inputs, None, fee)
+    }
+
+    /// Same as `submitProof`, also publishing the proof in the aggregation domain `domainId`.
+    #[precompile::public("submitProofToDomain(uint8,bytes,bytes,bytes,uint32,uint256)")]
+    fn submit_proof_to_domain(
+        handle: &mut impl PrecompileHandle,
+        verifier: u8,
+        vk: BoundedBytes<GetProofDataLimit>,
+        proof: BoundedBytes<GetProofDataLimit>,
+        public_inputs: BoundedBytes<GetProofDataLimit>,
+        domain_id: u32,
+        fee: U256,
+    ) -> EvmResult {
+        Self::send_submit_proof(
+            handle,
+            verifier,
+            vk,
+            proof,
+            public_inputs,
+            Some(domain_id),
+            fee,
+        )
+    }
+
+    /// Returns the relay chain account which submits the proofs, and pays for them, on behalf of
+    /// `who`.
+    #[precompile::public("relayAccountOf(address)")]
+    #[precompile::view]
+    fn relay_account_of(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<H256> {
+        // Storage item: ParachainInfo::ParachainId: ParaId
+        handle.record_db_read::<Runtime>(u32::max_encoded_len())?;
+
+        Self::relay_account(who.0.into()).map(H256::from)
+    }
+}
+
+impl ProofSubmissionPrecompile<Runtime> {
+    fn send_submit_proof(
+        handle: &mut impl PrecompileHandle,
+        verifier: u8,
+        vk: BoundedBytes<GetProofDataLimit>,
+        proof: BoundedBytes<GetProofDataLimit>,
+        public_inputs: BoundedBytes<GetProofDataLimit>,
+        domain_id: Option<u32>,
+        fee: U256,
+    ) -> EvmResult {
+        let fee: u128 = fee
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("balance type").in_field("fee"))?;
+
+        let message = Self::submit_proof_message(
+            Self::submit_proof_call(verifier, vk, proof, public_inputs, domain_id),
+            fee,
+            handle.context().caller.into(),
+        )?;
+
+        let call = pallet_xcm::Call::<Runtime>::send {
+            dest: Box::new(VersionedLocation::from(RelayLocation::get())),
+            message: Box::new(VersionedXcm::from(message)),
+        };
+
+        // We use IdentityAddressMapping, so no db access
+        let account_id = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(
+            handle.context().caller,
+        );
+        let origin: RuntimeOrigin = frame_system::RawOrigin::Signed(account_id).into();
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    /// SCALE encoding of `submit_proof(VkOrHash::Vk(vk), proof, pubs, domain_id)` in the
+    /// `verifier` pallet.
+    fn submit_proof_call(
+        verifier: u8,
+        vk: BoundedBytes<GetProofDataLimit>,
+        proof: BoundedBytes<GetProofDataLimit>,
+        public_inputs: BoundedBytes<GetProofDataLimit>,
+        domain_id: Option<u32>,
+    ) -> Vec<u8> {
+        let mut call = vec![verifier, SUBMIT_PROOF_CALL_INDEX, VK_VARIANT];
+        call.extend(Vec::<u8>::from(vk));
+        call.extend(Vec::<u8>::from(proof));
+        call.extend(Vec::<u8>::from(public_inputs));
+        call.extend(domain_id.encode());
+        call
+    }
+
+    fn submit_proof_message(call: Vec<u8>, fee: u128, caller: [u8; 20]) -> EvmResult<Xcm<()>> {
+        // VFY, as seen from the relay chain.
+        let fees: Asset = (Here, fee).into();
+        let refund_to = Self::relay_account(caller)?;
+
+        Ok(Xcm(vec![
+            WithdrawAsset(fees.clone().into()),
+            BuyExecution {
+                fees,
+                weight_limit: Unlimited,
+            },
+            Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                fallback_max_weight: None,
+                call: call.into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: AccountId32 {
+                    network: None,
+                    id: refund_to,
+                }
+                .into(),
+            },
+        ]))
+    }
+
+    /// The relay chain describes the locations of the parachain accounts with
+    /// `DescribeFamily<DescribeAllTerminal>`.
+    fn relay_account(caller: [u8; 20]) -> EvmResult<[u8; 32]> {
+        let location = Location::new(
+            0,
+            [
+                Parachain(ParachainInfo::parachain_id().into()),
+                AccountKey20 {
+                    network: RelayNetwork::get(),
+                    key: caller,
+                },
+            ],
+        );
+        HashedDescription::<[u8; 32], DescribeFamily<DescribeAllTerminal>>::convert_location(
+            &location,
+        )
+        .ok_or_else(|| revert("Cannot derive the relay chain account"))
+    }
+}
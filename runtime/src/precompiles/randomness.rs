@@ -0,0 +1,92 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile exposing the relay chain randomness of `pallet_relay_randomness` to contracts.
+//!
+//! A contract requests the randomness of a future block with `requestRandomness`, holding the
+//! request deposit, then reads it with `randomnessOf` once that block is finalized and releases
+//! the deposit with `fulfilRandomness`.
+
+use super::caller_origin;
+use crate::{BlockNumber, Runtime};
+use pallet_relay_randomness::{RandomnessResult, RequestId, RequestOf};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_std::marker::PhantomData;
+
+pub struct RandomnessPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl RandomnessPrecompile<Runtime> {
+    /// Requests the randomness of the block `delay` blocks from now, mixed with `salt`, and
+    /// returns the identifier of the request.
+    #[precompile::public("requestRandomness(bytes32,uint32)")]
+    fn request_randomness(
+        handle: &mut impl PrecompileHandle,
+        salt: H256,
+        delay: u32,
+    ) -> EvmResult<u64> {
+        // Storage item: RelayRandomness::NextRequestId: RequestId(8)
+        handle.record_db_read::<Runtime>(RequestId::max_encoded_len())?;
+        let request_id = pallet_relay_randomness::NextRequestId::<Runtime>::get();
+
+        let call = pallet_relay_randomness::Call::<Runtime>::request_randomness { salt, delay };
+        // Storage item: RelayRandomness::Requests: Twox64Concat(8) + RequestId(8) + Request(88)
+        let storage_growth =
+            8 + RequestId::max_encoded_len() + RequestOf::<Runtime>::max_encoded_len();
+        let origin = caller_origin(handle);
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, storage_growth as u64)?;
+
+        Ok(request_id)
+    }
+
+    /// Removes the request `requestId` of the caller, whose randomness has to be available, and
+    /// releases its deposit.
+    #[precompile::public("fulfilRandomness(uint64)")]
+    fn fulfil_randomness(handle: &mut impl PrecompileHandle, request_id: u64) -> EvmResult {
+        let call = pallet_relay_randomness::Call::<Runtime>::fulfil_randomness { request_id };
+        let origin = caller_origin(handle);
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+        Ok(())
+    }
+
+    /// Returns whether the randomness of the request `requestId` is available and, if so, the
+    /// randomness itself.
+    #[precompile::public("randomnessOf(uint64)")]
+    #[precompile::view]
+    fn randomness_of(
+        handle: &mut impl PrecompileHandle,
+        request_id: u64,
+    ) -> EvmResult<(bool, H256)> {
+        // Storage item: RelayRandomness::Requests: Twox64Concat(8) + RequestId(8) + Request(88)
+        handle.record_db_read::<Runtime>(
+            8 + RequestId::max_encoded_len() + RequestOf::<Runtime>::max_encoded_len(),
+        )?;
+        // Storage item: RelayRandomness::RandomnessResults:
+        // Twox64Concat(8) + BlockNumber(4) + RandomnessResult(37)
+        handle.record_db_read::<Runtime>(
+            8 + BlockNumber::max_encoded_len() + RandomnessResult::max_encoded_len(),
+        )?;
+
+        Ok(
+            match pallet_relay_randomness::Pallet::<Runtime>::randomness_of(request_id) {
+                Some(randomness) => (true, randomness),
+                None => (false, H256::zero()),
+            },
+        )
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Precompile reading relay chain storage entries from proofs checked against the relay chain
+//! storage roots kept by `pallet_relay_storage_roots`.
+//!
+//! The proofs are the ones returned by the `state_getReadProof` RPC of the relay chain, at one of
+//! the relay parents of the last parachain blocks.
+
+use crate::Runtime;
+use frame_support::traits::{ConstU32, Get};
+use pallet_relay_storage_roots::{ReadEntryError, RelayBlockNumber};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The maximum number of trie nodes of a proof.
+type GetProofNodesLimit = ConstU32<64>;
+
+/// The maximum size of a trie node.
+type GetProofNodeSizeLimit = ConstU32<{ 2u32.pow(16) }>;
+
+/// The maximum size of a relay chain storage key.
+type GetKeyLimit = ConstU32<1024>;
+
+/// The cost of hashing a trie node: as `KECCAK256`, 30 plus 6 per word.
+const HASH_NODE_BASE_GAS_COST: u64 = 30;
+const HASH_NODE_WORD_GAS_COST: u64 = 6;
+
+pub struct RelayStorageProofPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl RelayStorageProofPrecompile<Runtime> {
+    /// Returns `(exists, value)` for `key` in the storage of the relay chain block
+    /// `relayBlockNumber`, read from `proof`: `exists` is false, and `value` empty, when the proof
+    /// shows that there is no such key. Reverts if the storage root of the block is not kept or if
+    /// the proof is invalid.
+    #[precompile::public("verifyEntry(uint32,bytes[],bytes)")]
+    #[precompile::view]
+    fn verify_entry(
+        handle: &mut impl PrecompileHandle,
+        relay_block_number: u32,
+        proof: BoundedVec<BoundedBytes<GetProofNodeSizeLimit>, GetProofNodesLimit>,
+        key: BoundedBytes<GetKeyLimit>,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
+        Self::record_storage_root_read(handle)?;
+        let proof: Vec<Vec<u8>> = Vec::from(proof).into_iter().map(Vec::from).collect();
+        // Every node of the proof is hashed to build the trie database.
+        let hash_cost = proof.iter().fold(0u64, |cost, node| {
+            let words = (node.len() as u64).div_ceil(32);
+            cost.saturating_add(HASH_NODE_BASE_GAS_COST)
+                .saturating_add(HASH_NODE_WORD_GAS_COST.saturating_mul(words))
+        });
+        handle.record_cost(hash_cost)?;
+
+        match pallet_relay_storage_roots::Pallet::<Runtime>::read_entry(
+            relay_block_number,
+            proof,
+            &Vec::from(key),
+        ) {
+            Ok(Some(value)) => Ok((true, value.into())),
+            Ok(None) => Ok((false, UnboundedBytes::from(Vec::new()))),
+            Err(ReadEntryError::UnknownRelayBlock) => {
+                Err(RevertReason::custom("Unknown relay chain block")
+                    .in_field("relayBlockNumber")
+                    .into())
+            }
+            Err(ReadEntryError::InvalidProof) => Err(RevertReason::custom("Invalid proof")
+                .in_field("proof")
+                .into()),
+        }
+    }
+
+    /// Returns the storage root of the relay chain block `relayBlockNumber`, or zero if it is
+    /// not kept.
+    #[precompile::public("relayStorageRoot(uint32)")]
+    #[precompile::view]
+    fn relay_storage_root(
+        handle: &mut impl PrecompileHandle,
+        relay_block_number: u32,
+    ) -> EvmResult<H256> {
+        Self::record_storage_root_read(handle)?;
+
+        Ok(
+            pallet_relay_storage_roots::RelayStorageRoots::<Runtime>::get(relay_block_number)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Returns the number of the latest relay chain block whose storage root is kept.
+    #[precompile::public("latestRelayBlockNumber()")]
+    #[precompile::view]
+    fn latest_relay_block_number(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: RelayStorageRoots::RelayBlockNumbers:
+        // BoundedVec length(5) + MaxStorageRoots * RelayBlockNumber(4)
+        let max_storage_roots =
+            <Runtime as pallet_relay_storage_roots::Config>::MaxStorageRoots::get() as usize;
+        handle.record_db_read::<Runtime>(
+            5 + max_storage_roots * RelayBlockNumber::max_encoded_len(),
+        )?;
+
+        pallet_relay_storage_roots::Pallet::<Runtime>::latest_relay_block_number()
+            .ok_or_else(|| revert("No relay chain storage root"))
+    }
+}
+
+impl RelayStorageProofPrecompile<Runtime> {
+    fn record_storage_root_read(handle: &mut impl PrecompileHandle) -> EvmResult {
+        // Storage item: RelayStorageRoots::RelayStorageRoots:
+        // Twox64Concat(8) + RelayBlockNumber(4) + H256(32)
+        handle.record_db_read::<Runtime>(
+            8 + RelayBlockNumber::max_encoded_len() + H256::max_encoded_len(),
+        )
+    }
+}
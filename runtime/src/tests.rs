@@ -22,13 +22,37 @@ pub use crate::{
 };
 use frame_support::weights::Weight;
 
+mod account_mapping;
+mod aggregation_roots_precompile;
+mod bls12381_precompiles;
+mod call_permit_precompile;
+mod collator_staking_precompile;
 mod constants_test;
+mod cross_chain_identity_precompile;
+mod dispatch_precompile;
+mod foreign_assets;
+mod groth16_verifier_precompile;
+mod kzg_point_evaluation;
 mod multiplier;
+mod multisig_precompile;
+mod p256_verify_precompile;
 mod permissioned_deploy;
+mod precompile_kill_switch;
+mod precompile_registry;
+mod proof_submission_precompile;
+mod randomness_precompile;
+mod relay_storage_proof_precompile;
 mod storage;
 mod use_correct_weights;
+mod xcm_aliasers;
 mod xcm_eth;
+mod xcm_foreign_asset_fees;
+mod xcm_reserve_transfers;
+mod xcm_runtime_apis;
+mod xcm_safe_calls;
 mod xcm_teleport_integration;
+mod xcm_tracing;
+mod xcm_trusted_siblings;
 
 mod misc {
     use super::*;
@@ -131,8 +155,45 @@ pub fn ethereum_transaction(raw_hex_tx: &str) -> pallet_ethereum::Transaction {
 }
 
 use fp_rpc::ConvertTransaction;
+use precompile_utils::solidity::codec::Writer;
+use sp_core::{H160, U256};
 
 pub fn unchecked_eth_tx(raw_hex_tx: &str) -> UncheckedExtrinsic {
     let converter = TransactionConverter;
     converter.convert_transaction(ethereum_transaction(raw_hex_tx))
 }
+
+/// Builds the input of a precompile call, starting from the solidity signature of the function.
+pub fn precompile_input(signature: &str) -> Writer {
+    let selector = sp_io::hashing::keccak_256(signature.as_bytes());
+    Writer::new_with_selector(u32::from_be_bytes([
+        selector[0],
+        selector[1],
+        selector[2],
+        selector[3],
+    ]))
+}
+
+/// Executes an EVM call in the same way `eth_call` does: the call is not validated and no fee
+/// is charged, but the state changes are kept.
+pub fn evm_call(from: impl Into<H160>, to: H160, input: Vec<u8>) -> pallet_evm::CallInfo {
+    use pallet_evm::Runner;
+
+    <Runtime as pallet_evm::Config>::Runner::call(
+        from.into(),
+        to,
+        input,
+        U256::zero(),
+        10_000_000,
+        None,
+        None,
+        None,
+        Vec::new(),
+        false,
+        false,
+        None,
+        None,
+        <Runtime as pallet_evm::Config>::config(),
+    )
+    .expect("EVM call should be executed")
+}
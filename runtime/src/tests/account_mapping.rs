@@ -0,0 +1,253 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Linking relay chain accounts to the accounts of this chain.
+
+use crate::{
+    configs::xcm::{
+        LocationAccountId32ToAccountId, LocationToAccountId, RelayLocation, TruncatedAccountId32,
+        XcmConfig,
+    },
+    constants::currency::tVFY,
+    tests::{ExtBuilder, BOB},
+    AccountId, AccountMapping, Balances, Runtime, RuntimeCall, RuntimeOrigin, Signature, System,
+};
+use fp_account::EthereumSigner;
+use frame_support::{assert_ok, weights::Weight};
+use parity_scale_codec::Encode;
+use sp_core::{ecdsa, keccak_256, sr25519, Pair};
+use sp_runtime::traits::{Convert, IdentifyAccount};
+use xcm::latest::prelude::*;
+use xcm_executor::{traits::ConvertLocation, XcmExecutor};
+
+const FEE: u128 = tVFY / 2;
+
+fn pair() -> ecdsa::Pair {
+    ecdsa::Pair::from_seed(&[1; 32])
+}
+
+fn relay_pair() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[2; 32])
+}
+
+fn account() -> AccountId {
+    EthereumSigner::from(pair().public()).into_account()
+}
+
+fn relay_account() -> sp_runtime::AccountId32 {
+    relay_pair().public().into()
+}
+
+/// The relay chain account, as seen from VFlow.
+fn relay_location() -> Location {
+    Location::new(
+        1,
+        [AccountId32 {
+            network: None,
+            id: relay_pair().public().0,
+        }],
+    )
+}
+
+/// The beneficiary of the assets deposited by the relay chain account to itself.
+fn beneficiary() -> Location {
+    Location::new(
+        0,
+        [AccountId32 {
+            network: None,
+            id: relay_pair().public().0,
+        }],
+    )
+}
+
+fn sovereign_account() -> AccountId {
+    LocationToAccountId::convert_location(&relay_location()).unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(sovereign_account(), 10 * tVFY), (BOB.into(), tVFY)])
+        .build();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+mod asset_transactor {
+    use super::*;
+
+    #[test]
+    fn converts_unlinked_account_id32_to_the_relay_account_sovereign() {
+        new_test_ext().execute_with(|| {
+            for location in [beneficiary(), relay_location()] {
+                assert_eq!(
+                    LocationAccountId32ToAccountId::convert_location(&location),
+                    Some(sovereign_account())
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn converts_linked_account_id32_to_the_linked_account() {
+        new_test_ext().execute_with(|| {
+            let payload = AccountMapping::link_payload(&relay_account(), &BOB.into());
+            assert_ok!(AccountMapping::link_account(
+                RuntimeOrigin::signed(BOB.into()),
+                relay_account(),
+                relay_pair().sign(&payload).into()
+            ));
+
+            for location in [beneficiary(), relay_location()] {
+                assert_eq!(
+                    LocationAccountId32ToAccountId::convert_location(&location),
+                    Some(BOB.into())
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn leaves_other_locations_to_location_to_account_id() {
+        new_test_ext().execute_with(|| {
+            let location = Location::new(
+                0,
+                [AccountKey20 {
+                    network: None,
+                    key: BOB,
+                }],
+            );
+            assert_eq!(
+                LocationAccountId32ToAccountId::convert_location(&location),
+                Some(BOB.into())
+            );
+        });
+    }
+}
+
+mod legacy_account {
+    use super::*;
+
+    fn legacy_account() -> AccountId {
+        TruncatedAccountId32::convert(relay_account()).unwrap()
+    }
+
+    #[test]
+    fn is_the_account_the_local_account_id32_was_converted_to() {
+        new_test_ext().execute_with(|| {
+            let id = relay_pair().public().0;
+            let truncated = Location::new(
+                0,
+                [AccountKey20 {
+                    network: None,
+                    key: id[12..].try_into().unwrap(),
+                }],
+            );
+            assert_eq!(
+                LocationToAccountId::convert_location(&truncated),
+                Some(legacy_account())
+            );
+        });
+    }
+
+    #[test]
+    fn balance_is_claimed_by_the_linked_account() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Balances::force_set_balance(
+                RuntimeOrigin::root(),
+                legacy_account(),
+                tVFY
+            ));
+            let payload = AccountMapping::link_payload(&relay_account(), &BOB.into());
+            assert_ok!(AccountMapping::link_account(
+                RuntimeOrigin::signed(BOB.into()),
+                relay_account(),
+                relay_pair().sign(&payload).into()
+            ));
+
+            assert_ok!(AccountMapping::claim_legacy_account(RuntimeOrigin::signed(
+                BOB.into()
+            )));
+
+            assert_eq!(Balances::free_balance(legacy_account()), 0);
+            assert_eq!(Balances::free_balance(AccountId::from(BOB)), 2 * tVFY);
+        });
+    }
+}
+
+mod transact {
+    use super::*;
+
+    /// The relay chain account linking itself to `account()`, paying with its sovereign account.
+    fn link_message(signature: Signature) -> Xcm<RuntimeCall> {
+        let call = RuntimeCall::AccountMapping(
+            pallet_account_mapping::Call::<Runtime>::link_account_from_relay {
+                account: account(),
+                signature,
+            },
+        );
+        Xcm(vec![
+            DescendOrigin(relay_location().interior().clone()),
+            WithdrawAsset((RelayLocation::get(), FEE).into()),
+            BuyExecution {
+                fees: (RelayLocation::get(), FEE).into(),
+                weight_limit: Unlimited,
+            },
+            Transact {
+                origin_kind: OriginKind::Xcm,
+                fallback_max_weight: None,
+                call: call.encode().into(),
+            },
+        ])
+    }
+
+    fn execute_from_relay(message: Xcm<RuntimeCall>) -> Outcome {
+        let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+        XcmExecutor::<XcmConfig>::prepare_and_execute(
+            RelayLocation::get(),
+            message,
+            &mut hash,
+            Weight::MAX,
+            Weight::zero(),
+        )
+    }
+
+    #[test]
+    fn relay_account_can_link_itself() {
+        new_test_ext().execute_with(|| {
+            let payload = AccountMapping::link_payload(&relay_account(), &account());
+            let signature = Signature::new(pair().sign_prehashed(&keccak_256(&payload)));
+
+            assert_ok!(execute_from_relay(link_message(signature)).ensure_complete());
+
+            assert_eq!(
+                AccountMapping::linked_account(&relay_account()),
+                Some(account())
+            );
+        });
+    }
+
+    #[test]
+    fn relay_account_cannot_link_without_consent() {
+        new_test_ext().execute_with(|| {
+            let payload = AccountMapping::link_payload(&relay_account(), &account());
+            let other_pair = ecdsa::Pair::from_seed(&[3; 32]);
+            let signature = Signature::new(other_pair.sign_prehashed(&keccak_256(&payload)));
+
+            // The message is executed, but the dispatch of the call fails.
+            assert_ok!(execute_from_relay(link_message(signature)).ensure_complete());
+
+            assert_eq!(AccountMapping::linked_account(&relay_account()), None);
+        });
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::xcm::RelayLocation,
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE},
+    AggregationRoots, Runtime, RuntimeOrigin,
+};
+use fp_evm::ExitReason;
+use frame_support::{assert_noop, assert_ok};
+use precompile_utils::solidity::codec::Writer;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+
+const DOMAIN_ID: u32 = 1;
+const AGGREGATION_ID: u64 = 42;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2064)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+fn statements() -> [H256; 2] {
+    [H256::repeat_byte(1), H256::repeat_byte(2)]
+}
+
+/// Root of the two statements tree, built as zkVerify does: leaves are hashed too.
+fn aggregation_root() -> H256 {
+    let [first, second] = statements().map(|s| keccak_256(s.as_bytes()));
+    keccak_256(&[first, second].concat()).into()
+}
+
+fn verify_input(leaf: H256, merkle_path: Vec<H256>, leaf_count: U256, index: U256) -> Vec<u8> {
+    precompile_input("verifyProofAggregation(uint32,uint64,bytes32,bytes32[],uint256,uint256)")
+        .write(DOMAIN_ID)
+        .write(AGGREGATION_ID)
+        .write(leaf)
+        .write(merkle_path)
+        .write(leaf_count)
+        .write(index)
+        .build()
+}
+
+fn store_root() {
+    assert_ok!(AggregationRoots::store_aggregation_root(
+        RuntimeOrigin::root(),
+        DOMAIN_ID,
+        AGGREGATION_ID,
+        aggregation_root()
+    ));
+}
+
+#[test]
+fn relay_chain_can_store_roots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AggregationRoots::store_aggregation_root(
+            pallet_xcm::Origin::Xcm(RelayLocation::get()).into(),
+            DOMAIN_ID,
+            AGGREGATION_ID,
+            aggregation_root()
+        ));
+
+        assert_eq!(
+            pallet_aggregation_roots::AggregationRoots::<Runtime>::get(DOMAIN_ID, AGGREGATION_ID),
+            Some(aggregation_root())
+        );
+    });
+}
+
+#[test]
+fn relay_chain_accounts_cannot_store_roots() {
+    new_test_ext().execute_with(|| {
+        let relay_account = Location::new(
+            1,
+            [AccountId32 {
+                network: None,
+                id: [1; 32],
+            }],
+        );
+        assert_noop!(
+            AggregationRoots::store_aggregation_root(
+                pallet_xcm::Origin::Xcm(relay_account).into(),
+                DOMAIN_ID,
+                AGGREGATION_ID,
+                aggregation_root()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AggregationRoots::store_aggregation_root(
+                RuntimeOrigin::signed(ALICE.into()),
+                DOMAIN_ID,
+                AGGREGATION_ID,
+                aggregation_root()
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn verifies_aggregated_statements() {
+    new_test_ext().execute_with(|| {
+        store_root();
+        let [first, second] = statements();
+
+        for (index, (leaf, sibling)) in [(first, second), (second, first)].into_iter().enumerate() {
+            let info = evm_call(
+                ALICE,
+                precompile_address(),
+                verify_input(
+                    leaf,
+                    vec![keccak_256(sibling.as_bytes()).into()],
+                    U256::from(2u32),
+                    U256::from(index),
+                ),
+            );
+
+            assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+            assert_eq!(info.value, Writer::new().write(true).build());
+        }
+    });
+}
+
+#[test]
+fn rejects_wrong_proofs() {
+    new_test_ext().execute_with(|| {
+        store_root();
+        let [first, second] = statements();
+        let wrong_proofs = [
+            // Wrong index
+            (first, second, 1),
+            // Statement not in the aggregation
+            (H256::repeat_byte(3), second, 0),
+            // Wrong path
+            (first, first, 0),
+        ];
+
+        for (leaf, sibling, index) in wrong_proofs {
+            let info = evm_call(
+                ALICE,
+                precompile_address(),
+                verify_input(
+                    leaf,
+                    vec![keccak_256(sibling.as_bytes()).into()],
+                    U256::from(2u32),
+                    U256::from(index),
+                ),
+            );
+
+            assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+            assert_eq!(info.value, Writer::new().write(false).build());
+        }
+    });
+}
+
+#[test]
+fn unknown_aggregations_are_not_verified() {
+    new_test_ext().execute_with(|| {
+        let [first, second] = statements();
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            verify_input(
+                first,
+                vec![keccak_256(second.as_bytes()).into()],
+                U256::from(2u32),
+                U256::zero(),
+            ),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(false).build());
+    });
+}
+
+#[test]
+fn reverts_on_too_large_leaf_count() {
+    new_test_ext().execute_with(|| {
+        store_root();
+        let [first, second] = statements();
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            verify_input(
+                first,
+                vec![keccak_256(second.as_bytes()).into()],
+                U256::from(u32::MAX) + 1,
+                U256::zero(),
+            ),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn returns_aggregation_root() {
+    new_test_ext().execute_with(|| {
+        store_root();
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("aggregationRoot(uint32,uint64)")
+                .write(DOMAIN_ID)
+                .write(AGGREGATION_ID)
+                .build(),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(aggregation_root()).build());
+    });
+}
@@ -0,0 +1,159 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE, BOB},
+    AccountId, Balances, Runtime, Timestamp,
+};
+use fp_account::EthereumSigner;
+use fp_evm::ExitReason;
+use pallet_evm_precompile_call_permit::CallPermitPrecompile;
+use precompile_utils::solidity::codec::{Address, UnboundedBytes, Writer};
+use sp_core::{ecdsa, Pair, H160, H256, U256};
+use sp_runtime::traits::IdentifyAccount;
+
+const GAS_LIMIT: u64 = 100_000;
+const DEADLINE: u64 = 1_000;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2058)
+}
+
+fn signer() -> ecdsa::Pair {
+    ecdsa::Pair::from_seed(&[7u8; 32])
+}
+
+fn signer_address(pair: &ecdsa::Pair) -> H160 {
+    EthereumSigner::from(pair.public()).into_account().into()
+}
+
+/// Builds the `dispatch` input of a permit, signed by `pair`, transferring `value` from the
+/// `from` account to Bob.
+fn dispatch_input(pair: &ecdsa::Pair, from: H160, value: U256, nonce: U256) -> Vec<u8> {
+    let permit = CallPermitPrecompile::<Runtime>::generate_permit(
+        precompile_address(),
+        from,
+        BOB.into(),
+        value,
+        Vec::new(),
+        GAS_LIMIT,
+        nonce,
+        DEADLINE.into(),
+    );
+    let signature = pair.sign_prehashed(&permit);
+    let signature: &[u8] = signature.as_ref();
+
+    precompile_input("dispatch(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)")
+        .write(Address(from))
+        .write(Address(BOB.into()))
+        .write(value)
+        .write(UnboundedBytes::from(Vec::new()))
+        .write(GAS_LIMIT)
+        .write(U256::from(DEADLINE))
+        .write(signature[64] + 27)
+        .write(H256::from_slice(&signature[0..32]))
+        .write(H256::from_slice(&signature[32..64]))
+        .build()
+}
+
+fn nonce_of(who: H160) -> Vec<u8> {
+    evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("nonces(address)")
+            .write(Address(who))
+            .build(),
+    )
+    .value
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (ALICE.into(), 10 * tVFY),
+            (signer_address(&signer()).into(), 10 * tVFY),
+        ])
+        .build()
+}
+
+#[test]
+fn relayer_dispatches_signed_permit() {
+    new_test_ext().execute_with(|| {
+        let from = signer_address(&signer());
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            dispatch_input(&signer(), from, tVFY.into(), U256::zero()),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), tVFY);
+        assert_eq!(Balances::free_balance(AccountId::from(from)), 9 * tVFY);
+        assert_eq!(nonce_of(from), Writer::new().write(U256::one()).build());
+    });
+}
+
+#[test]
+fn permit_cannot_be_replayed() {
+    new_test_ext().execute_with(|| {
+        let from = signer_address(&signer());
+        let input = dispatch_input(&signer(), from, tVFY.into(), U256::zero());
+
+        let info = evm_call(ALICE, precompile_address(), input.clone());
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        let info = evm_call(ALICE, precompile_address(), input);
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), tVFY);
+    });
+}
+
+#[test]
+fn permit_signed_by_another_account_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let from = signer_address(&signer());
+        let other = ecdsa::Pair::from_seed(&[8u8; 32]);
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            dispatch_input(&other, from, tVFY.into(), U256::zero()),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert_eq!(Balances::free_balance(AccountId::from(from)), 10 * tVFY);
+        assert_eq!(nonce_of(from), Writer::new().write(U256::zero()).build());
+    });
+}
+
+#[test]
+fn expired_permit_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let from = signer_address(&signer());
+        // The deadline is expressed in seconds, the timestamp in milliseconds.
+        Timestamp::set_timestamp((DEADLINE + 1) * 1_000);
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            dispatch_input(&signer(), from, tVFY.into(), U256::zero()),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+    });
+}
@@ -0,0 +1,194 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE, BOB},
+    AccountId, AuraId, Balances, CollatorSelection, Runtime, RuntimeOrigin, Session, SessionKeys,
+};
+use fp_evm::ExitReason;
+use parity_scale_codec::Encode;
+use precompile_utils::solidity::codec::{Address, UnboundedBytes, Writer};
+use sp_core::{sr25519, H160, U256};
+
+const BOND: u128 = 5 * tVFY;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2062)
+}
+
+fn session_keys(seed: u8) -> SessionKeys {
+    SessionKeys {
+        aura: AuraId::from(sr25519::Public::from_raw([seed; 32])),
+    }
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY), (BOB.into(), 10 * tVFY)])
+        .build();
+    ext.execute_with(|| {
+        CollatorSelection::set_candidacy_bond(RuntimeOrigin::root(), BOND).unwrap();
+        CollatorSelection::set_desired_candidates(RuntimeOrigin::root(), 5).unwrap();
+        // Bob is the only invulnerable: candidates can leave without making the set too small.
+        Session::set_keys(
+            RuntimeOrigin::signed(BOB.into()),
+            session_keys(2),
+            Vec::new(),
+        )
+        .unwrap();
+        CollatorSelection::set_invulnerables(RuntimeOrigin::root(), vec![BOB.into()]).unwrap();
+    });
+    ext
+}
+
+fn set_keys(who: [u8; 20]) {
+    let input = precompile_input("setKeys(bytes,bytes)")
+        .write(UnboundedBytes::from(session_keys(1).encode()))
+        .write(UnboundedBytes::from(Vec::new()))
+        .build();
+
+    let info = evm_call(who, precompile_address(), input);
+    assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+}
+
+fn register_as_candidate(who: [u8; 20]) -> ExitReason {
+    evm_call(
+        who,
+        precompile_address(),
+        precompile_input("registerAsCandidate()").build(),
+    )
+    .exit_reason
+}
+
+#[test]
+fn set_keys_and_register_as_candidate() {
+    new_test_ext().execute_with(|| {
+        set_keys(ALICE);
+        assert!(matches!(
+            register_as_candidate(ALICE),
+            ExitReason::Succeed(_)
+        ));
+
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), BOND);
+
+        let info = evm_call(
+            BOB,
+            precompile_address(),
+            precompile_input("candidates()").build(),
+        );
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            info.value,
+            Writer::new()
+                .write(vec![Address(ALICE.into())])
+                .write(vec![U256::from(BOND)])
+                .build()
+        );
+
+        let info = evm_call(
+            BOB,
+            precompile_address(),
+            precompile_input("isCandidate(address)")
+                .write(Address(ALICE.into()))
+                .build(),
+        );
+        assert_eq!(info.value, Writer::new().write(true).build());
+    });
+}
+
+#[test]
+fn cannot_register_without_session_keys() {
+    new_test_ext().execute_with(|| {
+        assert!(matches!(
+            register_as_candidate(ALICE),
+            ExitReason::Revert(_)
+        ));
+        assert!(pallet_collator_selection::CandidateList::<Runtime>::get().is_empty());
+    });
+}
+
+#[test]
+fn update_bond_and_leave_intent() {
+    new_test_ext().execute_with(|| {
+        set_keys(ALICE);
+        assert!(matches!(
+            register_as_candidate(ALICE),
+            ExitReason::Succeed(_)
+        ));
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("updateBond(uint256)")
+                .write(U256::from(2 * BOND))
+                .build(),
+        );
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 2 * BOND);
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("leaveIntent()").build(),
+        );
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+        assert!(pallet_collator_selection::CandidateList::<Runtime>::get().is_empty());
+    });
+}
+
+#[test]
+fn set_keys_reverts_on_undecodable_keys() {
+    new_test_ext().execute_with(|| {
+        let input = precompile_input("setKeys(bytes,bytes)")
+            .write(UnboundedBytes::from(vec![1u8; 3]))
+            .write(UnboundedBytes::from(Vec::new()))
+            .build();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn read_collator_selection_configuration() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("invulnerables()").build(),
+        );
+        assert_eq!(
+            info.value,
+            Writer::new().write(vec![Address(BOB.into())]).build()
+        );
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("candidacyBond()").build(),
+        );
+        assert_eq!(info.value, Writer::new().write(U256::from(BOND)).build());
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("desiredCandidates()").build(),
+        );
+        assert_eq!(info.value, Writer::new().write(5u32).build());
+    });
+}
@@ -0,0 +1,166 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::xcm::{LocationAccountId32ToAccountId, LocationToAccountId, RelayLocation},
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE, BOB},
+    AccountId, Runtime,
+};
+use fp_evm::ExitReason;
+use parity_scale_codec::Encode;
+use precompile_utils::solidity::codec::{Address, UnboundedBytes, Writer};
+use sp_core::{H160, H256};
+use xcm::{latest::prelude::*, VersionedLocation};
+use xcm_executor::traits::ConvertLocation;
+
+const ACCOUNT_ID32: [u8; 32] = [7u8; 32];
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2066)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+fn address(account: Option<AccountId>) -> Vec<u8> {
+    Writer::new()
+        .write(Address(account.unwrap().into()))
+        .build()
+}
+
+fn sovereign_account_of(location: Vec<u8>) -> pallet_evm::CallInfo {
+    evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("sovereignAccountOf(bytes)")
+            .write(UnboundedBytes::from(location))
+            .build(),
+    )
+}
+
+fn account_id32_to_address() -> pallet_evm::CallInfo {
+    evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("accountId32ToAddress(bytes32)")
+            .write(H256::from(ACCOUNT_ID32))
+            .build(),
+    )
+}
+
+#[test]
+fn account_id32_to_address_matches_the_asset_transactor() {
+    new_test_ext().execute_with(|| {
+        let info = account_id32_to_address();
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        let expected = LocationAccountId32ToAccountId::convert_location(&Location::new(
+            0,
+            [AccountId32 {
+                network: None,
+                id: ACCOUNT_ID32,
+            }],
+        ));
+        assert_eq!(info.value, address(expected));
+        // Without a linked account, it is the sovereign account of the relay chain account.
+        let sovereign = LocationToAccountId::convert_location(&Location::new(
+            1,
+            [AccountId32 {
+                network: None,
+                id: ACCOUNT_ID32,
+            }],
+        ));
+        assert_eq!(info.value, address(sovereign));
+    });
+}
+
+#[test]
+fn account_id32_to_address_follows_the_account_mapping() {
+    new_test_ext().execute_with(|| {
+        pallet_account_mapping::LinkedAccounts::<Runtime>::insert(
+            sp_runtime::AccountId32::from(ACCOUNT_ID32),
+            AccountId::from(BOB),
+        );
+
+        let info = account_id32_to_address();
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, address(Some(BOB.into())));
+    });
+}
+
+#[test]
+fn relay_account_to_address_matches_transact_origin() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("relayAccountToAddress(bytes32)")
+                .write(H256::from(ACCOUNT_ID32))
+                .build(),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        let expected = LocationToAccountId::convert_location(&Location::new(
+            1,
+            [AccountId32 {
+                network: None,
+                id: ACCOUNT_ID32,
+            }],
+        ));
+        assert_eq!(info.value, address(expected));
+    });
+}
+
+#[test]
+fn sovereign_account_of_matches_runtime() {
+    new_test_ext().execute_with(|| {
+        for location in [
+            RelayLocation::get(),
+            Location::new(1, [Parachain(2000)]),
+            Location::new(
+                1,
+                [
+                    Parachain(2000),
+                    AccountKey20 {
+                        network: None,
+                        key: ALICE,
+                    },
+                ],
+            ),
+        ] {
+            let info = sovereign_account_of(VersionedLocation::from(location.clone()).encode());
+
+            assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+            assert_eq!(
+                info.value,
+                address(LocationToAccountId::convert_location(&location))
+            );
+        }
+    });
+}
+
+#[test]
+fn sovereign_account_of_reverts_on_invalid_location() {
+    new_test_ext().execute_with(|| {
+        let info = sovereign_account_of(vec![0xff, 0xff]);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
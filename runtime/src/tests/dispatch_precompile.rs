@@ -0,0 +1,165 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::system::ProxyType,
+    constants::currency::tVFY,
+    tests::{evm_call, ExtBuilder, ALICE, BOB},
+    AccountId, Balances, Runtime, RuntimeCall,
+};
+use fp_evm::ExitReason;
+use frame_support::dispatch::GetDispatchInfo;
+use pallet_evm::GasWeightMapping;
+use parity_scale_codec::Encode;
+use sp_core::H160;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(1026)
+}
+
+fn transfer_call() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: BOB.into(),
+        value: tVFY,
+    })
+}
+
+fn add_proxy_call() -> RuntimeCall {
+    RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {
+        delegate: BOB.into(),
+        proxy_type: ProxyType::Any,
+        delay: 0,
+    })
+}
+
+fn proxies_of(account: [u8; 20]) -> usize {
+    pallet_proxy::Proxies::<Runtime>::get(AccountId::from(account))
+        .0
+        .len()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+#[test]
+fn dispatch_allowed_call_as_caller() {
+    new_test_ext().execute_with(|| {
+        let call = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: vec![add_proxy_call()],
+        });
+
+        let info = evm_call(ALICE, precompile_address(), call.encode());
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(proxies_of(ALICE), 1);
+        // The call is priced by its dispatch weight.
+        let call_gas = <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+            call.get_dispatch_info().total_weight(),
+        );
+        assert!(info.used_gas.standard.as_u64() >= call_gas);
+    });
+}
+
+#[test]
+fn cannot_dispatch_call_outside_allowlist() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, precompile_address(), transfer_call().encode());
+
+        assert!(matches!(info.exit_reason, ExitReason::Error(_)));
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+    });
+}
+
+#[test]
+fn cannot_dispatch_call_outside_allowlist_inside_batch() {
+    new_test_ext().execute_with(|| {
+        let batches = [
+            pallet_utility::Call::batch {
+                calls: vec![add_proxy_call(), transfer_call()],
+            },
+            pallet_utility::Call::batch_all {
+                calls: vec![add_proxy_call(), transfer_call()],
+            },
+            pallet_utility::Call::force_batch {
+                calls: vec![add_proxy_call(), transfer_call()],
+            },
+        ];
+
+        for batch in batches {
+            let info = evm_call(
+                ALICE,
+                precompile_address(),
+                RuntimeCall::Utility(batch).encode(),
+            );
+
+            assert!(matches!(info.exit_reason, ExitReason::Error(_)));
+        }
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+        assert_eq!(proxies_of(ALICE), 0);
+    });
+}
+
+#[test]
+fn cannot_dispatch_call_outside_allowlist_nested_in_wrappers() {
+    new_test_ext().execute_with(|| {
+        let call = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: vec![RuntimeCall::Utility(pallet_utility::Call::as_derivative {
+                index: 0,
+                call: Box::new(RuntimeCall::Multisig(
+                    pallet_multisig::Call::as_multi_threshold_1 {
+                        other_signatories: vec![BOB.into()],
+                        call: Box::new(transfer_call()),
+                    },
+                )),
+            })],
+        });
+
+        let info = evm_call(ALICE, precompile_address(), call.encode());
+
+        assert!(matches!(info.exit_reason, ExitReason::Error(_)));
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+    });
+}
+
+#[test]
+fn cannot_dispatch_undecodable_call() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, precompile_address(), vec![0xff, 0xff, 0xff]);
+
+        assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+    });
+}
+
+#[test]
+fn failed_dispatch_is_an_error() {
+    new_test_ext().execute_with(|| {
+        // There's no proxy to remove
+        let call = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: vec![RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy {
+                delegate: BOB.into(),
+                proxy_type: ProxyType::Any,
+                delay: 0,
+            })],
+        });
+
+        let info = evm_call(ALICE, precompile_address(), call.encode());
+
+        assert!(matches!(info.exit_reason, ExitReason::Error(_)));
+        assert_eq!(proxies_of(ALICE), 0);
+    });
+}
@@ -0,0 +1,330 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::{monetary::ApprovalDeposit, xcm::AssetTransactors},
+    constants::currency::tVFY,
+    foreign_asset_address,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE, BOB},
+    AccountId, AssetRegistry, Balances, ForeignAssets, RuntimeOrigin,
+};
+use fp_evm::ExitReason;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::fungibles::{approvals::Inspect as _, Inspect},
+};
+use precompile_utils::solidity::codec::{Address, UnboundedBytes, Writer};
+use sp_core::{H160, U256};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::TransactAsset;
+
+const ASSET_ID: u32 = 1;
+const AMOUNT: u128 = 1_000_000;
+
+fn foreign_location() -> Location {
+    Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+}
+
+fn account_location(account: [u8; 20]) -> Location {
+    Location::new(
+        0,
+        [AccountKey20 {
+            network: None,
+            key: account,
+        }],
+    )
+}
+
+/// Creates the local asset `ASSET_ID` and registers it as the foreign asset at
+/// `foreign_location`.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY), (BOB.into(), 10 * tVFY)])
+        .build();
+    ext.execute_with(|| {
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            ALICE.into(),
+            true,
+            1
+        ));
+        assert_ok!(ForeignAssets::force_set_metadata(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            b"Tether USD".to_vec(),
+            b"USDT".to_vec(),
+            6,
+            false
+        ));
+        assert_ok!(AssetRegistry::register_foreign_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            Box::new(foreign_location().into())
+        ));
+    });
+    ext
+}
+
+fn deposit(amount: u128, to: [u8; 20]) -> Result<(), XcmError> {
+    AssetTransactors::deposit_asset(
+        &(foreign_location(), amount).into(),
+        &account_location(to),
+        None,
+    )
+}
+
+fn balance_of(account: [u8; 20]) -> u128 {
+    ForeignAssets::balance(ASSET_ID, AccountId::from(account))
+}
+
+mod xcm_transactor {
+    use super::*;
+
+    #[test]
+    fn deposits_registered_foreign_asset() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(deposit(AMOUNT, ALICE));
+
+            assert_eq!(balance_of(ALICE), AMOUNT);
+            assert_eq!(ForeignAssets::total_issuance(ASSET_ID), AMOUNT);
+        })
+    }
+
+    #[test]
+    fn withdraws_registered_foreign_asset() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(deposit(AMOUNT, ALICE));
+
+            assert_ok!(AssetTransactors::withdraw_asset(
+                &(foreign_location(), AMOUNT / 4).into(),
+                &account_location(ALICE),
+                None,
+            ));
+
+            assert_eq!(balance_of(ALICE), AMOUNT - AMOUNT / 4);
+        })
+    }
+
+    #[test]
+    fn rejects_unregistered_foreign_asset() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(AssetRegistry::unregister_foreign_asset(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+
+            assert_noop!(deposit(AMOUNT, ALICE), XcmError::AssetNotFound);
+        })
+    }
+}
+
+mod erc20_precompile {
+    use super::*;
+
+    fn call(from: [u8; 20], input: Vec<u8>) -> pallet_evm::CallInfo {
+        evm_call(from, foreign_asset_address(ASSET_ID), input)
+    }
+
+    fn assert_succeeds(info: &pallet_evm::CallInfo) {
+        assert!(
+            matches!(info.exit_reason, ExitReason::Succeed(_)),
+            "{:?}",
+            info.exit_reason
+        );
+    }
+
+    #[test]
+    fn address_is_prefix_followed_by_asset_id() {
+        assert_eq!(
+            foreign_asset_address(0x0102_0304),
+            H160::from(hex_literal::hex!(
+                "ffffffff00000000000000000000000001020304"
+            ))
+        );
+    }
+
+    #[test]
+    fn exposes_asset_metadata() {
+        new_test_ext().execute_with(|| {
+            let info = call(ALICE, precompile_input("name()").build());
+            assert_succeeds(&info);
+            assert_eq!(
+                info.value,
+                Writer::new()
+                    .write(UnboundedBytes::from(&b"Tether USD"[..]))
+                    .build()
+            );
+
+            let info = call(ALICE, precompile_input("symbol()").build());
+            assert_succeeds(&info);
+            assert_eq!(
+                info.value,
+                Writer::new()
+                    .write(UnboundedBytes::from(&b"USDT"[..]))
+                    .build()
+            );
+
+            let info = call(ALICE, precompile_input("decimals()").build());
+            assert_succeeds(&info);
+            assert_eq!(info.value, Writer::new().write(6u8).build());
+        })
+    }
+
+    #[test]
+    fn exposes_balances_received_through_xcm() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(deposit(AMOUNT, ALICE));
+
+            let info = call(
+                BOB,
+                precompile_input("balanceOf(address)")
+                    .write(Address(ALICE.into()))
+                    .build(),
+            );
+            assert_succeeds(&info);
+            assert_eq!(info.value, Writer::new().write(U256::from(AMOUNT)).build());
+
+            let info = call(BOB, precompile_input("totalSupply()").build());
+            assert_succeeds(&info);
+            assert_eq!(info.value, Writer::new().write(U256::from(AMOUNT)).build());
+        })
+    }
+
+    #[test]
+    fn transfers_tokens() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(deposit(AMOUNT, ALICE));
+
+            let info = call(
+                ALICE,
+                precompile_input("transfer(address,uint256)")
+                    .write(Address(BOB.into()))
+                    .write(U256::from(AMOUNT / 4))
+                    .build(),
+            );
+            assert_succeeds(&info);
+            assert_eq!(info.value, Writer::new().write(true).build());
+
+            assert_eq!(balance_of(ALICE), AMOUNT - AMOUNT / 4);
+            assert_eq!(balance_of(BOB), AMOUNT / 4);
+        })
+    }
+
+    #[test]
+    fn cannot_transfer_more_than_balance() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(deposit(AMOUNT, ALICE));
+
+            let info = call(
+                ALICE,
+                precompile_input("transfer(address,uint256)")
+                    .write(Address(BOB.into()))
+                    .write(U256::from(AMOUNT + 1))
+                    .build(),
+            );
+
+            assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+            assert_eq!(balance_of(ALICE), AMOUNT);
+        })
+    }
+
+    #[test]
+    fn transfers_approved_tokens() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(deposit(AMOUNT, ALICE));
+
+            let info = call(
+                ALICE,
+                precompile_input("approve(address,uint256)")
+                    .write(Address(BOB.into()))
+                    .write(U256::from(AMOUNT / 2))
+                    .build(),
+            );
+            assert_succeeds(&info);
+
+            let info = call(
+                BOB,
+                precompile_input("allowance(address,address)")
+                    .write(Address(ALICE.into()))
+                    .write(Address(BOB.into()))
+                    .build(),
+            );
+            assert_eq!(
+                info.value,
+                Writer::new().write(U256::from(AMOUNT / 2)).build()
+            );
+
+            let info = call(
+                BOB,
+                precompile_input("transferFrom(address,address,uint256)")
+                    .write(Address(ALICE.into()))
+                    .write(Address(BOB.into()))
+                    .write(U256::from(AMOUNT / 4))
+                    .build(),
+            );
+            assert_succeeds(&info);
+
+            assert_eq!(balance_of(ALICE), AMOUNT - AMOUNT / 4);
+            assert_eq!(balance_of(BOB), AMOUNT / 4);
+            assert_eq!(
+                ForeignAssets::allowance(ASSET_ID, &ALICE.into(), &BOB.into()),
+                AMOUNT / 4
+            );
+        })
+    }
+
+    #[test]
+    fn approve_replaces_the_allowance() {
+        new_test_ext().execute_with(|| {
+            for amount in [AMOUNT / 2, AMOUNT / 8] {
+                let info = call(
+                    ALICE,
+                    precompile_input("approve(address,uint256)")
+                        .write(Address(BOB.into()))
+                        .write(U256::from(amount))
+                        .build(),
+                );
+                assert_succeeds(&info);
+            }
+
+            assert_eq!(
+                ForeignAssets::allowance(ASSET_ID, &ALICE.into(), &BOB.into()),
+                AMOUNT / 8
+            );
+            // The deposit of the replaced approval is given back.
+            assert_eq!(
+                Balances::reserved_balance(AccountId::from(ALICE)),
+                ApprovalDeposit::get()
+            );
+        })
+    }
+
+    #[test]
+    fn is_not_available_for_unregistered_assets() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(AssetRegistry::unregister_foreign_asset(
+                RuntimeOrigin::root(),
+                ASSET_ID
+            ));
+
+            let info = call(ALICE, precompile_input("totalSupply()").build());
+
+            // There's no contract at the address, so the call does nothing.
+            assert_succeeds(&info);
+            assert!(info.value.is_empty());
+        })
+    }
+}
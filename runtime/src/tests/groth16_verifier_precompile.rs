@@ -0,0 +1,175 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE},
+};
+use fp_evm::ExitReason;
+use hex_literal::hex;
+use precompile_utils::solidity::codec::{UnboundedBytes, Writer};
+use sp_core::{H160, U256};
+
+// A verification key, and a proof for the public inputs `[3, 4]`, built from known discrete
+// logarithms: `alpha = 5 * G1`, `beta = gamma = G2`, `delta = 2 * G2`, `IC = [7, 11, 13] * G1`,
+// `C = 17 * G1` and `B = G2`, which makes `A = alpha + vk_x + 2 * C`.
+const VK: [u8; 640] = hex!(
+    // alpha
+    "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9"
+    "01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c"
+    // beta
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    // gamma
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    // delta
+    "203e205db4f19b37b60121b83a7333706db86431c6d835849957ed8c3928ad79"
+    "27dc7234fd11d3e8c36c59277c3e6f149d5cd3cfa9a62aee49f8130962b4b3b9"
+    "195e8aa5b7827463722b8c153931579d3505566b4edf48d498e185f0509de152"
+    "04bb53b8977e5f92a0bc372742c4830944a59b4fe6b1c0466e2a6dad122b5d2e"
+    // IC
+    "17072b2ed3bb8d759a5325f477629386cb6fc6ecb801bd76983a6b86abffe078"
+    "168ada6cd130dd52017bb54bfa19377aadfe3bf05d18f41b77809f7f60d4af9e"
+    "2a14705537b009189da8808651eecdb82482477fe92ac12ca8b71f80fc3d49ef"
+    "2df7ee7f243ea8b38e1ddf14029258877a618c779fd4717db6177e19ea67ec38"
+    "05e86f8cc8a7a4f10f56093465679f17f8b8c3fdb41469e408b529e030f52f3f"
+    "2857bd14bbc09767bed8e913d3ccb42b2bc8738f715417dd6f020725d22bcd90"
+);
+
+const PROOF: [u8; 256] = hex!(
+    // A
+    "0fb3815156c1f9007db6c9a094865fbff6cc2b539370f99a2c0d72bbeb8b7bab"
+    "10974d31f50b739138d3a5bf83d060adce47d330642dfd77e59fd7f9b9e1564f"
+    // B
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    // C
+    "1c6a451060210f3baad93fe1631753751da9857edae0468e8e4bee7dd33cfb2c"
+    "2331a64aa86c50d2d1e0237893ef7744a77228881ce73fcc2ad555a37d4ab405"
+);
+
+const PUBLIC_INPUTS: [u64; 2] = [3, 4];
+
+// The BN254 scalar field modulus.
+const R: U256 = U256([
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2065)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+fn verify(vk: &[u8], proof: &[u8], public_inputs: Vec<U256>) -> pallet_evm::CallInfo {
+    evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("verifyGroth16(bytes,bytes,uint256[])")
+            .write(UnboundedBytes::from(vk))
+            .write(UnboundedBytes::from(proof))
+            .write(public_inputs)
+            .build(),
+    )
+}
+
+#[test]
+fn accepts_valid_proof() {
+    new_test_ext().execute_with(|| {
+        let info = verify(&VK, &PROOF, PUBLIC_INPUTS.map(U256::from).to_vec());
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(true).build());
+    });
+}
+
+#[test]
+fn rejects_proof_for_other_public_inputs() {
+    new_test_ext().execute_with(|| {
+        let info = verify(&VK, &PROOF, vec![U256::from(4), U256::from(3)]);
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(false).build());
+    });
+}
+
+#[test]
+fn rejects_tampered_proof() {
+    new_test_ext().execute_with(|| {
+        // Swap `A` and `C`.
+        let proof = [&PROOF[192..], &PROOF[64..192], &PROOF[..64]].concat();
+
+        let info = verify(&VK, &proof, PUBLIC_INPUTS.map(U256::from).to_vec());
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(false).build());
+    });
+}
+
+#[test]
+fn reverts_on_public_inputs_not_in_field() {
+    new_test_ext().execute_with(|| {
+        // Same as `3` modulo the field size.
+        let info = verify(&VK, &PROOF, vec![R + U256::from(3), U256::from(4)]);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn reverts_on_vk_not_matching_public_inputs() {
+    new_test_ext().execute_with(|| {
+        let info = verify(&VK, &PROOF, vec![U256::from(3)]);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn reverts_on_points_not_on_curve() {
+    new_test_ext().execute_with(|| {
+        let mut proof = PROOF;
+        proof[63] ^= 1;
+
+        let info = verify(&VK, &proof, PUBLIC_INPUTS.map(U256::from).to_vec());
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn charges_measured_verification_cost() {
+    new_test_ext().execute_with(|| {
+        let info = verify(&VK, &PROOF, PUBLIC_INPUTS.map(U256::from).to_vec());
+
+        // Base cost and the cost of the two public inputs.
+        let verification_cost = 470_000 + 2 * 20_500;
+        assert!(info.used_gas.standard > U256::from(verification_cost));
+    });
+}
@@ -0,0 +1,302 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, ExtBuilder, ALICE},
+};
+use fp_evm::ExitReason;
+use hex_literal::hex;
+use sp_core::{H160, U256};
+
+// Opening at `z = 5` of `p(x) = 2 + 3x`, whose value is `y = 17`: the commitment is
+// `[2]G1 + [3][tau]G1`, the proof `[3]G1`.
+const VERSIONED_HASH: [u8; 32] =
+    hex!("014416a93344f5661c21fe1f55e77619ca019a183fd2e6826985dece6378d13f");
+const COMMITMENT: [u8; 48] = hex!("b035021c0f860f9188d5f0f27dd7db1a9c6b3a15060347069017eeb0c077a350a0e8de6996e2bd5dd590af579fe43948");
+const PROOF: [u8; 48] = hex!("89ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224");
+const Z: u64 = 5;
+const Y: u64 = 17;
+
+const BLS_MODULUS: [u8; 32] =
+    hex!("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+
+/// The point evaluation vector of EIP-4844, as in go-ethereum's precompile test data
+/// (`core/vm/testdata/precompiles/pointEvaluation.json`), checked against the reference
+/// `verify_kzg_proof` of the consensus specs.
+const EIP_4844_INPUT: [u8; 192] = hex!(
+    "01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b"
+    "564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d36306"
+    "24d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a1"
+    "8f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca2"
+    "5f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253"
+    "fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"
+);
+
+// Compressed encodings of `[2]G1`, `G1` and of the point at infinity, which the c-kzg
+// `verify_kzg_proof` cases use for constant and zero polynomials.
+const TWO_G1: [u8; 48] = hex!("a572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e");
+const G1: [u8; 48] = hex!("97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb");
+const INFINITY: [u8; 48] = hex!("c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+const TWO_G1_VERSIONED_HASH: [u8; 32] =
+    hex!("01cf45213dd7b4716864d378f3c6d861467987e4d94b7f79a1f814a697e38637");
+const INFINITY_VERSIONED_HASH: [u8; 32] =
+    hex!("010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014");
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(10)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+fn scalar(value: U256) -> [u8; 32] {
+    value.to_big_endian()
+}
+
+fn input(versioned_hash: [u8; 32], z: U256, y: U256, proof: [u8; 48]) -> Vec<u8> {
+    input_with_commitment(versioned_hash, z, y, COMMITMENT, proof)
+}
+
+fn input_with_commitment(
+    versioned_hash: [u8; 32],
+    z: U256,
+    y: U256,
+    commitment: [u8; 48],
+    proof: [u8; 48],
+) -> Vec<u8> {
+    [
+        versioned_hash.as_slice(),
+        &scalar(z),
+        &scalar(y),
+        &commitment,
+        &proof,
+    ]
+    .concat()
+}
+
+fn success_output() -> Vec<u8> {
+    [scalar(U256::from(4096)), BLS_MODULUS].concat()
+}
+
+#[test]
+fn verifies_opening() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            input(VERSIONED_HASH, Z.into(), Y.into(), PROOF),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, success_output());
+    });
+}
+
+#[test]
+fn rejects_wrong_evaluation() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            input(VERSIONED_HASH, Z.into(), (Y + 1).into(), PROOF),
+        );
+
+        assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+    });
+}
+
+#[test]
+fn rejects_mismatched_versioned_hash() {
+    new_test_ext().execute_with(|| {
+        let mut versioned_hash = VERSIONED_HASH;
+        versioned_hash[0] = 0;
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            input(versioned_hash, Z.into(), Y.into(), PROOF),
+        );
+
+        assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+    });
+}
+
+#[test]
+fn rejects_non_canonical_field_elements() {
+    new_test_ext().execute_with(|| {
+        let modulus = U256::from_big_endian(&BLS_MODULUS);
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            input(VERSIONED_HASH, modulus + Z, Y.into(), PROOF),
+        );
+
+        assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+    });
+}
+
+#[test]
+fn rejects_invalid_input_length() {
+    new_test_ext().execute_with(|| {
+        let mut input = input(VERSIONED_HASH, Z.into(), Y.into(), PROOF);
+        input.push(0);
+
+        let info = evm_call(ALICE, precompile_address(), input);
+
+        assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+    });
+}
+
+mod eip_4844_vector {
+    use super::*;
+
+    #[test]
+    fn verifies_opening() {
+        new_test_ext().execute_with(|| {
+            let info = evm_call(ALICE, precompile_address(), EIP_4844_INPUT.to_vec());
+
+            assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+            assert_eq!(info.value, success_output());
+        });
+    }
+
+    #[test]
+    fn rejects_wrong_evaluation() {
+        new_test_ext().execute_with(|| {
+            let mut input = EIP_4844_INPUT;
+            input[95] ^= 1;
+
+            let info = evm_call(ALICE, precompile_address(), input.to_vec());
+
+            assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+        });
+    }
+
+    #[test]
+    fn rejects_wrong_proof() {
+        new_test_ext().execute_with(|| {
+            let mut input = EIP_4844_INPUT;
+            input[144..].copy_from_slice(&G1);
+
+            let info = evm_call(ALICE, precompile_address(), input.to_vec());
+
+            assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+        });
+    }
+
+    #[test]
+    fn rejects_mismatched_versioned_hash() {
+        new_test_ext().execute_with(|| {
+            let mut input = EIP_4844_INPUT;
+            input[..32].copy_from_slice(&TWO_G1_VERSIONED_HASH);
+
+            let info = evm_call(ALICE, precompile_address(), input.to_vec());
+
+            assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+        });
+    }
+
+    #[test]
+    fn rejects_unknown_versioned_hash_version() {
+        new_test_ext().execute_with(|| {
+            let mut input = EIP_4844_INPUT;
+            input[0] = 0x02;
+
+            let info = evm_call(ALICE, precompile_address(), input.to_vec());
+
+            assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+        });
+    }
+}
+
+mod c_kzg_cases {
+    use super::*;
+
+    // Random point, the openings of constant polynomials don't depend on it.
+    const Z: [u8; 32] = hex!("5eb7004fe57383e6c88b99d839937fddf3f99279353aaf8d5c9a75f91ce33c62");
+
+    #[test]
+    fn verifies_constant_polynomial_with_proof_at_infinity() {
+        new_test_ext().execute_with(|| {
+            for z in [U256::zero(), U256::from_big_endian(&Z)] {
+                let info = evm_call(
+                    ALICE,
+                    precompile_address(),
+                    input_with_commitment(TWO_G1_VERSIONED_HASH, z, 2.into(), TWO_G1, INFINITY),
+                );
+
+                assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+                assert_eq!(info.value, success_output());
+            }
+        });
+    }
+
+    #[test]
+    fn verifies_zero_polynomial_with_proof_at_infinity() {
+        new_test_ext().execute_with(|| {
+            let info = evm_call(
+                ALICE,
+                precompile_address(),
+                input_with_commitment(
+                    INFINITY_VERSIONED_HASH,
+                    U256::from_big_endian(&Z),
+                    U256::zero(),
+                    INFINITY,
+                    INFINITY,
+                ),
+            );
+
+            assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+            assert_eq!(info.value, success_output());
+        });
+    }
+
+    #[test]
+    fn rejects_incorrect_proof() {
+        new_test_ext().execute_with(|| {
+            let info = evm_call(
+                ALICE,
+                precompile_address(),
+                input_with_commitment(TWO_G1_VERSIONED_HASH, U256::zero(), 2.into(), TWO_G1, G1),
+            );
+
+            assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+        });
+    }
+
+    #[test]
+    fn rejects_wrong_evaluation_of_zero_polynomial() {
+        new_test_ext().execute_with(|| {
+            let info = evm_call(
+                ALICE,
+                precompile_address(),
+                input_with_commitment(
+                    INFINITY_VERSIONED_HASH,
+                    U256::from_big_endian(&Z),
+                    U256::one(),
+                    INFINITY,
+                    INFINITY,
+                ),
+            );
+
+            assert!(!matches!(info.exit_reason, ExitReason::Succeed(_)));
+        });
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::system::MaxSignatories,
+    constants::currency::tVFY,
+    precompiles::PendingMultisig,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE, BOB},
+    AccountId, Balance, Balances, BlockNumber, Multisig, Runtime, RuntimeCall, RuntimeOrigin,
+    System,
+};
+use fp_evm::ExitReason;
+use frame_support::{assert_ok, traits::Get, weights::Weight};
+use parity_scale_codec::Encode;
+use precompile_utils::solidity::codec::{Address, UnboundedBytes, Writer};
+use scale_info::{form::MetaForm, Type, TypeDef, TypeInfo};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+const CHARLIE: [u8; 20] = [6u8; 20];
+const THRESHOLD: u16 = 2;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2061)
+}
+
+fn multisig_account() -> AccountId {
+    Multisig::multi_account_id(&[ALICE.into(), BOB.into()], THRESHOLD)
+}
+
+fn transfer_call() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: CHARLIE.into(),
+        value: tVFY,
+    })
+}
+
+fn as_multi_input(other: [u8; 20], timepoint: (u32, u32)) -> Vec<u8> {
+    precompile_input("asMulti(uint16,address[],uint32,uint32,bytes,uint64,uint64)")
+        .write(THRESHOLD)
+        .write(vec![Address(other.into())])
+        .write(timepoint.0)
+        .write(timepoint.1)
+        .write(UnboundedBytes::from(transfer_call().encode()))
+        .write(10_000_000_000u64)
+        .write(100_000u64)
+        .build()
+}
+
+fn pending_multisig_input() -> Vec<u8> {
+    precompile_input("pendingMultisig(address,bytes32)")
+        .write(Address(multisig_account().into()))
+        .write(BlakeTwo256::hash_of(&transfer_call()))
+        .build()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY), (BOB.into(), 10 * tVFY)])
+        .build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Balances::force_set_balance(RuntimeOrigin::root(), multisig_account(), 10 * tVFY).unwrap();
+    });
+    ext
+}
+
+#[test]
+fn multisig_account_matches_pallet_derivation() {
+    new_test_ext().execute_with(|| {
+        // Signatories order doesn't matter
+        let input = precompile_input("multisigAccount(address[],uint16)")
+            .write(vec![Address(BOB.into()), Address(ALICE.into())])
+            .write(THRESHOLD)
+            .build();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            info.value,
+            Writer::new()
+                .write(Address(multisig_account().into()))
+                .build()
+        );
+    });
+}
+
+#[test]
+fn pending_multisig_matches_pallet_layout() {
+    // The precompile reads the pallet's private fields through `PendingMultisig`: any upstream
+    // change to the stored layout must show up here.
+    let fields = |info: Type<MetaForm>| match info.type_def {
+        TypeDef::Composite(composite) => composite
+            .fields
+            .into_iter()
+            .map(|field| (field.name, field.ty))
+            .collect::<Vec<_>>(),
+        _ => panic!("Multisig is a struct"),
+    };
+
+    assert_eq!(
+        fields(PendingMultisig::type_info()),
+        fields(pallet_multisig::Multisig::<
+            BlockNumber,
+            Balance,
+            AccountId,
+            MaxSignatories,
+        >::type_info())
+    );
+}
+
+#[test]
+fn pending_multisig_decodes_stored_multisig() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Multisig::as_multi(
+            RuntimeOrigin::signed(ALICE.into()),
+            THRESHOLD,
+            vec![BOB.into()],
+            None,
+            Box::new(transfer_call()),
+            Weight::zero(),
+        ));
+        let stored = pallet_multisig::Multisigs::<Runtime>::get(
+            multisig_account(),
+            <[u8; 32]>::from(BlakeTwo256::hash_of(&transfer_call())),
+        )
+        .unwrap();
+
+        let pending = PendingMultisig::try_from(stored.clone()).unwrap();
+
+        assert_eq!(pending.encode(), stored.encode());
+    });
+}
+
+#[test]
+fn first_approval_creates_pending_operation() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, precompile_address(), as_multi_input(BOB, (0, 0)));
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        let info = evm_call(ALICE, precompile_address(), pending_multisig_input());
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            info.value,
+            Writer::new()
+                .write(true)
+                .write(1u32)
+                .write(0u32)
+                .write(U256::from(
+                    <Runtime as pallet_multisig::Config>::DepositBase::get()
+                        + <Runtime as pallet_multisig::Config>::DepositFactor::get()
+                            * THRESHOLD as u128
+                ))
+                .write(Address(ALICE.into()))
+                .write(vec![Address(ALICE.into())])
+                .build()
+        );
+    });
+}
+
+#[test]
+fn second_approval_executes_the_call() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, precompile_address(), as_multi_input(BOB, (0, 0)));
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        let info = evm_call(BOB, precompile_address(), as_multi_input(ALICE, (1, 0)));
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        assert_eq!(Balances::free_balance(AccountId::from(CHARLIE)), tVFY);
+        assert_eq!(Balances::free_balance(multisig_account()), 9 * tVFY);
+
+        let info = evm_call(ALICE, precompile_address(), pending_multisig_input());
+        assert_eq!(
+            info.value,
+            Writer::new()
+                .write(false)
+                .write(0u32)
+                .write(0u32)
+                .write(U256::zero())
+                .write(Address(H160::zero()))
+                .write(Vec::<Address>::new())
+                .build()
+        );
+    });
+}
+
+#[test]
+fn depositor_can_cancel_pending_operation() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, precompile_address(), as_multi_input(BOB, (0, 0)));
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert!(Balances::reserved_balance(AccountId::from(ALICE)) > 0);
+
+        let input = precompile_input("cancelAsMulti(uint16,address[],uint32,uint32,bytes32)")
+            .write(THRESHOLD)
+            .write(vec![Address(BOB.into())])
+            .write(1u32)
+            .write(0u32)
+            .write(BlakeTwo256::hash_of(&transfer_call()))
+            .build();
+        let info = evm_call(ALICE, precompile_address(), input);
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+        assert!(pallet_multisig::Multisigs::<Runtime>::get(
+            multisig_account(),
+            <[u8; 32]>::from(BlakeTwo256::hash_of(&transfer_call()))
+        )
+        .is_none());
+    });
+}
+
+#[test]
+fn as_multi_reverts_on_undecodable_call() {
+    new_test_ext().execute_with(|| {
+        let input = precompile_input("asMulti(uint16,address[],uint32,uint32,bytes,uint64,uint64)")
+            .write(THRESHOLD)
+            .write(vec![Address(BOB.into())])
+            .write(0u32)
+            .write(0u32)
+            .write(UnboundedBytes::from(vec![0xff, 0xff, 0xff]))
+            .write(10_000_000_000u64)
+            .write(100_000u64)
+            .build();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+    });
+}
+
+#[test]
+fn approve_as_multi_records_approval_by_hash() {
+    new_test_ext().execute_with(|| {
+        let input = precompile_input(
+            "approveAsMulti(uint16,address[],uint32,uint32,bytes32,uint64,uint64)",
+        )
+        .write(THRESHOLD)
+        .write(vec![Address(ALICE.into())])
+        .write(0u32)
+        .write(0u32)
+        .write(BlakeTwo256::hash_of(&transfer_call()))
+        .write(0u64)
+        .write(0u64)
+        .build();
+
+        let info = evm_call(BOB, precompile_address(), input);
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        let info = evm_call(BOB, precompile_address(), pending_multisig_input());
+        let expected_hash: H256 = BlakeTwo256::hash_of(&transfer_call());
+        assert!(pallet_multisig::Multisigs::<Runtime>::contains_key(
+            multisig_account(),
+            <[u8; 32]>::from(expected_hash)
+        ));
+        assert_eq!(&info.value[..32], &Writer::new().write(true).build()[..]);
+    });
+}
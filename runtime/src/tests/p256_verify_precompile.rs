@@ -0,0 +1,191 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, ExtBuilder, ALICE},
+};
+use fp_evm::ExitReason;
+use hex_literal::hex;
+use sp_core::H160;
+
+// ECDSA secp256r1 signature of `sha256("vflow passkey")`.
+const HASH: [u8; 32] = hex!("c6c85a78a2027ce5496d459dcb7ad265f06f98374f2cb30ca4d67a6f5f663344");
+const R: [u8; 32] = hex!("91e3961a89015de6175a27d9819b465bc1bd1c9073f55416040e6141ae5c9c21");
+const S: [u8; 32] = hex!("fd9aa3803c1c49cbdc8b79f1935250a07b411384f810c9781dcaacc5017509f1");
+const X: [u8; 32] = hex!("3988322ab9f52c7f11d5d1aa92a2ac0b00275bcad8e934682257323fda672482");
+const Y: [u8; 32] = hex!("855b7389f116c19c0014311c3d57dc02001e3a0ec8bd90c797732034aacd9918");
+
+// The order of the curve and the prime of its field.
+const N: [u8; 32] = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+const P: [u8; 32] = hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+
+// Wycheproof `ecdsa_secp256r1_sha256_test.json`: the key of the first test group and its
+// signatures of the message "123400", the valid one (tcId 3) and the one with `s` replaced by
+// `n - s` (tcId 1, "signature malleability"), which is valid too.
+const WYCHEPROOF_HASH: [u8; 32] =
+    hex!("bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023");
+const WYCHEPROOF_X: [u8; 32] =
+    hex!("2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838");
+const WYCHEPROOF_Y: [u8; 32] =
+    hex!("c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e");
+const WYCHEPROOF_R: [u8; 32] =
+    hex!("2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18");
+const WYCHEPROOF_S: [u8; 32] =
+    hex!("b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db");
+const WYCHEPROOF_MALLEABLE_S: [u8; 32] =
+    hex!("4cd60b855d442f5b3c7b11eb6c4e0ae7525fe710fab9aa7c77a67f79e6fadd76");
+
+// Signature of the Wycheproof message by a second key pair, generated with the Python
+// cryptography package.
+const OTHER_X: [u8; 32] = hex!("adf593901ce250755e9cf611b040155a1ac468e32de004cbc639ca0b7674bfea");
+const OTHER_Y: [u8; 32] = hex!("04d8a3660930150b678bdf616e5147ea57ab169627f341b1110a96fb380f424c");
+const OTHER_R: [u8; 32] = hex!("b8dbba88136339a224623e9ff74916f7003a8f7a0c5b7e08e86297a5d5664e51");
+const OTHER_S: [u8; 32] = hex!("ed9677c9ed45cdb15f49a3ebeb669c488a2a3c6fb71f75a924bab010b62ae2bd");
+
+/// The first valid vector of go-ethereum's RIP-7212 precompile test data
+/// (`core/vm/testdata/precompiles/p256Verify.json`).
+const RIP_7212_INPUT: [u8; 160] = hex!(
+    "4cee90eb86eaa050036147a12d49004b6b9c72bd725d39d4785011fe190f0b4d"
+    "a73bd4903f0ce3b639bbbf6e8e80d16931ff4bcf5993d58468e8fb19086e8cac"
+    "36dbcd03009df8c59286b162af3bd7fcc0450c9aa81be5d10d312af6c66b1d60"
+    "4aebd3099c618202fcfe16ae7770b0c49ab5eadf74b754204a3bb6060e44eff3"
+    "7618b065f9832de4ca6ca971a7a1adc826d0f7c00181a5fb2ddf79ae00b4e10e"
+);
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(0x100)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+/// Calls the precompile, which must succeed, and returns its output.
+fn verify(hash: [u8; 32], r: [u8; 32], s: [u8; 32], x: [u8; 32], y: [u8; 32]) -> Vec<u8> {
+    let info = evm_call(ALICE, precompile_address(), [hash, r, s, x, y].concat());
+    // Invalid signatures don't revert: they return no data.
+    assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+    info.value
+}
+
+fn valid() -> Vec<u8> {
+    let mut output = vec![0u8; 32];
+    output[31] = 1;
+    output
+}
+
+#[test]
+fn accepts_valid_signature() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(verify(HASH, R, S, X, Y), valid());
+    });
+}
+
+#[test]
+fn accepts_wycheproof_signatures() {
+    new_test_ext().execute_with(|| {
+        let verify_wycheproof =
+            |s| verify(WYCHEPROOF_HASH, WYCHEPROOF_R, s, WYCHEPROOF_X, WYCHEPROOF_Y);
+
+        assert_eq!(verify_wycheproof(WYCHEPROOF_S), valid());
+        // RIP-7212 does not require the low `s` form
+        assert_eq!(verify_wycheproof(WYCHEPROOF_MALLEABLE_S), valid());
+    });
+}
+
+#[test]
+fn accepts_rip_7212_vector() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, precompile_address(), RIP_7212_INPUT.to_vec());
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, valid());
+    });
+}
+
+#[test]
+fn rejects_signature_of_other_message() {
+    new_test_ext().execute_with(|| {
+        let mut hash = HASH;
+        hash[0] ^= 1;
+
+        assert!(verify(hash, R, S, X, Y).is_empty());
+    });
+}
+
+#[test]
+fn rejects_signature_of_other_key() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            verify(WYCHEPROOF_HASH, OTHER_R, OTHER_S, OTHER_X, OTHER_Y),
+            valid()
+        );
+
+        assert!(verify(
+            WYCHEPROOF_HASH,
+            OTHER_R,
+            OTHER_S,
+            WYCHEPROOF_X,
+            WYCHEPROOF_Y
+        )
+        .is_empty());
+        assert!(verify(
+            WYCHEPROOF_HASH,
+            WYCHEPROOF_R,
+            WYCHEPROOF_S,
+            OTHER_X,
+            OTHER_Y
+        )
+        .is_empty());
+    });
+}
+
+#[test]
+fn rejects_invalid_public_keys() {
+    new_test_ext().execute_with(|| {
+        // Swapping the coordinates gives a point which is not on the curve.
+        assert!(verify(HASH, R, S, Y, X).is_empty());
+        // The point at infinity has no affine encoding: (0, 0) must be rejected.
+        assert!(verify(HASH, R, S, [0u8; 32], [0u8; 32]).is_empty());
+        // Coordinates are field elements.
+        assert!(verify(HASH, R, S, P, Y).is_empty());
+        assert!(verify(HASH, R, S, X, P).is_empty());
+    });
+}
+
+#[test]
+fn rejects_out_of_range_signatures() {
+    new_test_ext().execute_with(|| {
+        assert!(verify(HASH, [0u8; 32], S, X, Y).is_empty());
+        assert!(verify(HASH, R, [0u8; 32], X, Y).is_empty());
+        assert!(verify(HASH, N, S, X, Y).is_empty());
+        assert!(verify(HASH, R, N, X, Y).is_empty());
+    });
+}
+
+#[test]
+fn rejects_invalid_input_length() {
+    new_test_ext().execute_with(|| {
+        let input = [HASH, R, S, X].concat();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert!(info.value.is_empty());
+    });
+}
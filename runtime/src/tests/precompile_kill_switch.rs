@@ -0,0 +1,112 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, ExtBuilder, ALICE},
+    PrecompileKillSwitch, RuntimeEvent, RuntimeOrigin, System,
+};
+use fp_evm::{ExitReason, ExitRevert};
+use pallet_precompile_kill_switch::precompile_set::DISABLED_PRECOMPILE_OUTPUT;
+use sp_core::{H160, U256};
+use sp_runtime::DispatchError;
+
+fn identity_address() -> H160 {
+    H160::from_low_u64_be(4)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn disabled_precompile_reverts() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, identity_address(), b"hello".to_vec());
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, b"hello".to_vec());
+
+        PrecompileKillSwitch::disable_precompile(RuntimeOrigin::root(), identity_address())
+            .unwrap();
+        System::assert_last_event(RuntimeEvent::PrecompileKillSwitch(
+            pallet_precompile_kill_switch::Event::PrecompileDisabled {
+                address: identity_address(),
+            },
+        ));
+
+        let info = evm_call(ALICE, identity_address(), b"hello".to_vec());
+        assert_eq!(info.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+        assert_eq!(info.value, DISABLED_PRECOMPILE_OUTPUT.to_vec());
+    });
+}
+
+#[test]
+fn enabled_precompile_works_again() {
+    new_test_ext().execute_with(|| {
+        PrecompileKillSwitch::disable_precompile(RuntimeOrigin::root(), identity_address())
+            .unwrap();
+        PrecompileKillSwitch::enable_precompile(RuntimeOrigin::root(), identity_address()).unwrap();
+        System::assert_last_event(RuntimeEvent::PrecompileKillSwitch(
+            pallet_precompile_kill_switch::Event::PrecompileEnabled {
+                address: identity_address(),
+            },
+        ));
+
+        let info = evm_call(ALICE, identity_address(), b"hello".to_vec());
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, b"hello".to_vec());
+    });
+}
+
+#[test]
+fn other_precompiles_are_not_affected() {
+    new_test_ext().execute_with(|| {
+        PrecompileKillSwitch::disable_precompile(
+            RuntimeOrigin::root(),
+            H160::from_low_u64_be(2060),
+        )
+        .unwrap();
+
+        let info = evm_call(ALICE, identity_address(), b"hello".to_vec());
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+    });
+}
+
+#[test]
+fn precompiles_cost_what_they_charge() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(ALICE, identity_address(), b"hello".to_vec());
+
+        // Intrinsic cost, calldata and the Ethereum price of Identity: 15 + 3 per word
+        assert_eq!(info.used_gas.standard, U256::from(21_000 + 5 * 16 + 15 + 3));
+    });
+}
+
+#[test]
+fn only_root_can_disable_precompiles() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            PrecompileKillSwitch::disable_precompile(
+                RuntimeOrigin::signed(ALICE.into()),
+                identity_address()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
@@ -0,0 +1,122 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE},
+    PrecompileKillSwitch, Runtime, RuntimeOrigin,
+};
+use fp_evm::ExitReason;
+use precompile_utils::solidity::codec::{Address, Writer};
+use sp_core::H160;
+
+// PUSH1 0x00 PUSH1 0x00 REVERT, as written at genesis.
+const DUMMY_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2069)
+}
+
+fn query(signature: &str, address: H160) -> Vec<u8> {
+    let info = evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input(signature).write(Address(address)).build(),
+    );
+    assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+    info.value
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build()
+}
+
+#[test]
+fn detects_precompiles() {
+    new_test_ext().execute_with(|| {
+        for address in [1, 1026, 2056, 2060, 2069].map(H160::from_low_u64_be) {
+            assert_eq!(
+                query("isPrecompile(address)", address),
+                Writer::new().write(true).build()
+            );
+            assert_eq!(
+                query("isActivePrecompile(address)", address),
+                Writer::new().write(true).build()
+            );
+        }
+
+        assert_eq!(
+            query("isPrecompile(address)", ALICE.into()),
+            Writer::new().write(false).build()
+        );
+    });
+}
+
+#[test]
+fn disabled_precompile_is_not_active() {
+    new_test_ext().execute_with(|| {
+        let teleport = H160::from_low_u64_be(2060);
+        PrecompileKillSwitch::disable_precompile(RuntimeOrigin::root(), teleport).unwrap();
+
+        assert_eq!(
+            query("isPrecompile(address)", teleport),
+            Writer::new().write(true).build()
+        );
+        assert_eq!(
+            query("isActivePrecompile(address)", teleport),
+            Writer::new().write(false).build()
+        );
+    });
+}
+
+#[test]
+fn update_account_code_writes_dummy_code() {
+    new_test_ext().execute_with(|| {
+        let batch = H160::from_low_u64_be(2056);
+        assert!(pallet_evm::AccountCodes::<Runtime>::get(batch).is_empty());
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("updateAccountCode(address)")
+                .write(Address(batch))
+                .build(),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            pallet_evm::AccountCodes::<Runtime>::get(batch),
+            DUMMY_CODE.to_vec()
+        );
+    });
+}
+
+#[test]
+fn update_account_code_rejects_non_precompiles() {
+    new_test_ext().execute_with(|| {
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("updateAccountCode(address)")
+                .write(Address(ALICE.into()))
+                .build(),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert!(pallet_evm::AccountCodes::<Runtime>::get(H160::from(ALICE)).is_empty());
+    });
+}
@@ -0,0 +1,213 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::xcm_teleport_integration::new_test_ext;
+use crate::{
+    configs::xcm::{RelayLocation, RelayNetwork},
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ALICE},
+    ParachainInfo, Runtime, RuntimeOrigin, ZKVXcm,
+};
+use fp_evm::ExitReason;
+use hex_literal::hex;
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::solidity::codec::{Address, UnboundedBytes, Writer};
+use sp_core::{H160, H256, U256};
+use xcm::{latest::prelude::*, VersionedXcm};
+
+const VERIFIER: u8 = 161;
+const FEE: u128 = tVFY / 10;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2063)
+}
+
+fn vk() -> Vec<u8> {
+    vec![0x11; 32].encode()
+}
+
+fn proof() -> Vec<u8> {
+    vec![0x22; 64].encode()
+}
+
+fn public_inputs() -> Vec<u8> {
+    vec![[0x33u8; 32]].encode()
+}
+
+/// Account of `ALICE` on the relay chain, as derived by the relay's `HashedDescription` converter
+/// for `(0, [Parachain(100), AccountKey20 { key: ALICE, .. }])`: the blake2-256 of
+/// `("ChildChain", Compact(100), ("AccountKey20", ALICE).encode())`.
+const ALICE_RELAY_ACCOUNT: [u8; 32] =
+    hex!("deb3b0105ce0efeb89132ae1a6a07318c1436fceacbb7c388eefd0d34d0a9e09");
+
+/// Relay chain speaks the latest XCM version, which lets `Transact` omit the weight.
+fn new_relay_test_ext() -> sp_io::TestExternalities {
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        ZKVXcm::force_xcm_version(
+            RuntimeOrigin::root(),
+            Box::new(RelayLocation::get()),
+            XCM_VERSION,
+        )
+        .unwrap();
+    });
+    ext
+}
+
+/// Returns the only message sent to the relay chain.
+fn sent_message() -> Xcm<()> {
+    let messages = cumulus_pallet_parachain_system::PendingUpwardMessages::<Runtime>::get();
+    assert_eq!(messages.len(), 1);
+    VersionedXcm::<()>::decode(&mut &messages[0][..])
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+fn expected_message(call: Vec<u8>) -> Vec<Instruction<()>> {
+    let fees: Asset = (Here, FEE).into();
+    vec![
+        DescendOrigin(
+            AccountKey20 {
+                network: RelayNetwork::get(),
+                key: ALICE,
+            }
+            .into(),
+        ),
+        WithdrawAsset(fees.clone().into()),
+        BuyExecution {
+            fees,
+            weight_limit: Unlimited,
+        },
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            fallback_max_weight: None,
+            call: call.into(),
+        },
+        RefundSurplus,
+        DepositAsset {
+            assets: Wild(AllCounted(1)),
+            beneficiary: AccountId32 {
+                network: None,
+                id: ALICE_RELAY_ACCOUNT,
+            }
+            .into(),
+        },
+    ]
+}
+
+#[test]
+fn submit_proof_sends_transact_to_relay() {
+    new_relay_test_ext().execute_with(|| {
+        let input = precompile_input("submitProof(uint8,bytes,bytes,bytes,uint256)")
+            .write(VERIFIER)
+            .write(UnboundedBytes::from(vk()))
+            .write(UnboundedBytes::from(proof()))
+            .write(UnboundedBytes::from(public_inputs()))
+            .write(U256::from(FEE))
+            .build();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        let call = [
+            vec![VERIFIER, 0, 1],
+            vk(),
+            proof(),
+            public_inputs(),
+            None::<u32>.encode(),
+        ]
+        .concat();
+        let message = sent_message();
+        // The router appends the message topic.
+        assert!(matches!(message.0.last(), Some(SetTopic(_))));
+        assert_eq!(
+            message.0[..message.0.len() - 1].to_vec(),
+            expected_message(call)
+        );
+    });
+}
+
+#[test]
+fn submit_proof_to_domain_encodes_domain_id() {
+    new_relay_test_ext().execute_with(|| {
+        let input = precompile_input("submitProofToDomain(uint8,bytes,bytes,bytes,uint32,uint256)")
+            .write(VERIFIER)
+            .write(UnboundedBytes::from(vk()))
+            .write(UnboundedBytes::from(proof()))
+            .write(UnboundedBytes::from(public_inputs()))
+            .write(7u32)
+            .write(U256::from(FEE))
+            .build();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        let call = [
+            vec![VERIFIER, 0, 1],
+            vk(),
+            proof(),
+            public_inputs(),
+            Some(7u32).encode(),
+        ]
+        .concat();
+        let message = sent_message();
+        assert_eq!(
+            message.0[..message.0.len() - 1].to_vec(),
+            expected_message(call)
+        );
+    });
+}
+
+#[test]
+fn submit_proof_reverts_on_too_large_fee() {
+    new_relay_test_ext().execute_with(|| {
+        let input = precompile_input("submitProof(uint8,bytes,bytes,bytes,uint256)")
+            .write(VERIFIER)
+            .write(UnboundedBytes::from(vk()))
+            .write(UnboundedBytes::from(proof()))
+            .write(UnboundedBytes::from(public_inputs()))
+            .write(U256::MAX)
+            .build();
+
+        let info = evm_call(ALICE, precompile_address(), input);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert!(
+            cumulus_pallet_parachain_system::PendingUpwardMessages::<Runtime>::get().is_empty()
+        );
+    });
+}
+
+#[test]
+fn relay_account_of_matches_relay_derivation() {
+    new_relay_test_ext().execute_with(|| {
+        assert_eq!(u32::from(ParachainInfo::parachain_id()), 100);
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("relayAccountOf(address)")
+                .write(Address(ALICE.into()))
+                .build(),
+        );
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            info.value,
+            Writer::new().write(H256::from(ALICE_RELAY_ACCOUNT)).build()
+        );
+    });
+}
@@ -0,0 +1,165 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::system::RandomnessRequestDeposit,
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE, BOB},
+    Balances, Runtime,
+};
+use fp_evm::ExitReason;
+use frame_support::traits::ReservableCurrency;
+use pallet_relay_randomness::{RandomnessResult, RandomnessResults, Requests};
+use precompile_utils::solidity::codec::Writer;
+use sp_core::{H160, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+const SALT: H256 = H256::repeat_byte(3);
+const RELAY_RANDOMNESS: H256 = H256::repeat_byte(9);
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2067)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY), (BOB.into(), 10 * tVFY)])
+        .build()
+}
+
+fn request(delay: u32) -> pallet_evm::CallInfo {
+    evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("requestRandomness(bytes32,uint32)")
+            .write(SALT)
+            .write(delay)
+            .build(),
+    )
+}
+
+fn randomness_of(request_id: u64) -> Vec<u8> {
+    let info = evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("randomnessOf(uint64)")
+            .write(request_id)
+            .build(),
+    );
+    assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+    info.value
+}
+
+fn fulfil(from: [u8; 20], request_id: u64) -> pallet_evm::CallInfo {
+    evm_call(
+        from,
+        precompile_address(),
+        precompile_input("fulfilRandomness(uint64)")
+            .write(request_id)
+            .build(),
+    )
+}
+
+/// Finalizes the block the request `request_id` was made for, as if the relay chain
+/// randomness were `RELAY_RANDOMNESS`.
+fn finalize_requested_block(request_id: u64) {
+    let fulfil_at = Requests::<Runtime>::get(request_id).unwrap().fulfil_at;
+    RandomnessResults::<Runtime>::mutate(fulfil_at, |result| {
+        result.as_mut().unwrap().randomness = Some(RELAY_RANDOMNESS)
+    });
+}
+
+#[test]
+fn request_randomness_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        let info = request(2);
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(0u64).build());
+        let request = Requests::<Runtime>::get(0).unwrap();
+        assert_eq!(request.requester, ALICE.into());
+        assert_eq!(request.salt, SALT);
+        assert_eq!(
+            RandomnessResults::<Runtime>::get(request.fulfil_at),
+            Some(RandomnessResult {
+                randomness: None,
+                request_count: 1
+            })
+        );
+        assert_eq!(
+            Balances::reserved_balance(&ALICE.into()),
+            RandomnessRequestDeposit::get()
+        );
+    });
+}
+
+#[test]
+fn request_randomness_reverts_on_invalid_delay() {
+    new_test_ext().execute_with(|| {
+        let info = request(0);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert!(Requests::<Runtime>::get(0).is_none());
+    });
+}
+
+#[test]
+fn randomness_is_not_ready_before_the_block_is_finalized() {
+    new_test_ext().execute_with(|| {
+        request(2);
+
+        assert_eq!(
+            randomness_of(0),
+            Writer::new().write(false).write(H256::zero()).build()
+        );
+        assert!(matches!(
+            fulfil(ALICE, 0).exit_reason,
+            ExitReason::Revert(_)
+        ));
+    });
+}
+
+#[test]
+fn fulfil_randomness_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        request(2);
+        finalize_requested_block(0);
+
+        let expected = BlakeTwo256::hash_of(&(RELAY_RANDOMNESS, SALT, 0u64));
+        assert_eq!(
+            randomness_of(0),
+            Writer::new().write(true).write(expected).build()
+        );
+
+        let info = fulfil(ALICE, 0);
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert!(Requests::<Runtime>::get(0).is_none());
+        assert_eq!(Balances::reserved_balance(&ALICE.into()), 0);
+    });
+}
+
+#[test]
+fn only_requester_can_fulfil() {
+    new_test_ext().execute_with(|| {
+        request(2);
+        finalize_requested_block(0);
+
+        let info = fulfil(BOB, 0);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+        assert!(Requests::<Runtime>::get(0).is_some());
+    });
+}
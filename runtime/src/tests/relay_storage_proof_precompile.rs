@@ -0,0 +1,163 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::currency::tVFY,
+    tests::{evm_call, precompile_input, ExtBuilder, ALICE},
+    Runtime,
+};
+use fp_evm::ExitReason;
+use pallet_relay_storage_roots::{RelayBlockNumbers, RelayStorageRoots};
+use precompile_utils::solidity::codec::{BoundedBytes, UnboundedBytes, Writer};
+use sp_core::{ConstU32, H160, H256};
+use sp_runtime::traits::BlakeTwo256;
+use sp_trie::{LayoutV1, MemoryDB, TrieDBMutBuilder, TrieMut};
+
+const RELAY_BLOCK_NUMBER: u32 = 100;
+const KEY: &[u8] = b"relay key";
+const VALUE: &[u8] = b"relay value";
+
+type Node = BoundedBytes<ConstU32<{ 2u32.pow(16) }>>;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2068)
+}
+
+/// Builds the trie of `entries`, returning its root and a proof containing all of its nodes.
+fn trie(entries: &[(&[u8], &[u8])]) -> (H256, Vec<Node>) {
+    let mut db = MemoryDB::<BlakeTwo256>::default();
+    let mut root = H256::zero();
+    {
+        let mut trie = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+        for (key, value) in entries {
+            trie.insert(key, value).unwrap();
+        }
+    }
+    let proof = db
+        .drain()
+        .into_values()
+        .filter(|(_, rc)| *rc > 0)
+        .map(|(node, _)| node.into())
+        .collect();
+    (root, proof)
+}
+
+/// Stores the root of a relay chain storage holding `KEY`, returning a proof of it.
+fn new_test_ext() -> (sp_io::TestExternalities, Vec<Node>) {
+    let (root, proof) = trie(&[(KEY, VALUE), (b"other relay key", b"other relay value")]);
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 10 * tVFY)])
+        .build();
+    ext.execute_with(|| {
+        RelayStorageRoots::<Runtime>::insert(RELAY_BLOCK_NUMBER, root);
+        RelayBlockNumbers::<Runtime>::put(
+            sp_runtime::BoundedVec::try_from(vec![RELAY_BLOCK_NUMBER]).unwrap(),
+        );
+    });
+    (ext, proof)
+}
+
+fn verify_entry(relay_block_number: u32, proof: Vec<Node>, key: &[u8]) -> pallet_evm::CallInfo {
+    evm_call(
+        ALICE,
+        precompile_address(),
+        precompile_input("verifyEntry(uint32,bytes[],bytes)")
+            .write(relay_block_number)
+            .write(proof)
+            .write(UnboundedBytes::from(key))
+            .build(),
+    )
+}
+
+#[test]
+fn verify_entry_returns_value() {
+    let (mut ext, proof) = new_test_ext();
+    ext.execute_with(|| {
+        let info = verify_entry(RELAY_BLOCK_NUMBER, proof, KEY);
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            info.value,
+            Writer::new()
+                .write(true)
+                .write(UnboundedBytes::from(VALUE))
+                .build()
+        );
+    });
+}
+
+#[test]
+fn verify_entry_proves_missing_key() {
+    let (mut ext, proof) = new_test_ext();
+    ext.execute_with(|| {
+        let info = verify_entry(RELAY_BLOCK_NUMBER, proof, b"missing key");
+
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            info.value,
+            Writer::new()
+                .write(false)
+                .write(UnboundedBytes::from(Vec::new()))
+                .build()
+        );
+    });
+}
+
+#[test]
+fn verify_entry_reverts_on_unknown_relay_block() {
+    let (mut ext, proof) = new_test_ext();
+    ext.execute_with(|| {
+        let info = verify_entry(RELAY_BLOCK_NUMBER + 1, proof, KEY);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn verify_entry_reverts_on_forged_proof() {
+    let (mut ext, _) = new_test_ext();
+    ext.execute_with(|| {
+        let (_, forged_proof) = trie(&[(KEY, b"forged value")]);
+
+        let info = verify_entry(RELAY_BLOCK_NUMBER, forged_proof, KEY);
+
+        assert!(matches!(info.exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn relay_storage_root_and_latest_relay_block_number() {
+    let (mut ext, _) = new_test_ext();
+    ext.execute_with(|| {
+        let root = RelayStorageRoots::<Runtime>::get(RELAY_BLOCK_NUMBER).unwrap();
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("relayStorageRoot(uint32)")
+                .write(RELAY_BLOCK_NUMBER)
+                .build(),
+        );
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(root).build());
+
+        let info = evm_call(
+            ALICE,
+            precompile_address(),
+            precompile_input("latestRelayBlockNumber()").build(),
+        );
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, Writer::new().write(RELAY_BLOCK_NUMBER).build());
+    });
+}
@@ -184,3 +184,113 @@ fn pallet_deployment_permissions() {
         weights::pallet_deployment_permissions::ZKVEvmWeight::<Runtime>::grant_deploy_permission()
     )
 }
+
+#[test]
+fn pallet_precompile_kill_switch() {
+    use pallet_precompile_kill_switch::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_precompile_kill_switch::Config>::WeightInfo::disable_precompile(),
+        weights::pallet_precompile_kill_switch::ZKVEvmWeight::<Runtime>::disable_precompile()
+    )
+}
+
+#[test]
+fn pallet_aggregation_roots() {
+    use pallet_aggregation_roots::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_aggregation_roots::Config>::WeightInfo::store_aggregation_root(),
+        weights::pallet_aggregation_roots::ZKVEvmWeight::<Runtime>::store_aggregation_root()
+    )
+}
+
+#[test]
+fn pallet_relay_randomness() {
+    use pallet_relay_randomness::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_relay_randomness::Config>::WeightInfo::request_randomness(),
+        weights::pallet_relay_randomness::ZKVEvmWeight::<Runtime>::request_randomness()
+    )
+}
+
+#[test]
+fn pallet_relay_storage_roots() {
+    use pallet_relay_storage_roots::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_relay_storage_roots::Config>::WeightInfo::on_finalize(),
+        weights::pallet_relay_storage_roots::ZKVEvmWeight::<Runtime>::on_finalize()
+    )
+}
+
+#[test]
+fn pallet_trusted_siblings() {
+    use pallet_trusted_siblings::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_trusted_siblings::Config>::WeightInfo::trust_sibling(),
+        weights::pallet_trusted_siblings::ZKVEvmWeight::<Runtime>::trust_sibling()
+    )
+}
+
+#[test]
+fn pallet_assets() {
+    use pallet_assets::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_assets::Config>::WeightInfo::transfer(),
+        pallet_assets::weights::SubstrateWeight::<Runtime>::transfer()
+    )
+}
+
+#[test]
+fn pallet_asset_registry() {
+    use pallet_asset_registry::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_asset_registry::Config>::WeightInfo::register_foreign_asset(),
+        weights::pallet_asset_registry::ZKVEvmWeight::<Runtime>::register_foreign_asset()
+    )
+}
+
+#[test]
+fn pallet_account_mapping() {
+    use pallet_account_mapping::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_account_mapping::Config>::WeightInfo::link_account(),
+        weights::pallet_account_mapping::ZKVEvmWeight::<Runtime>::link_account()
+    )
+}
+
+#[test]
+fn pallet_trusted_aliasers() {
+    use pallet_trusted_aliasers::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_trusted_aliasers::Config>::WeightInfo::trust_aliaser(),
+        weights::pallet_trusted_aliasers::ZKVEvmWeight::<Runtime>::trust_aliaser()
+    )
+}
+
+#[test]
+fn pallet_xcm_safe_calls() {
+    use pallet_xcm_safe_calls::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_xcm_safe_calls::Config>::WeightInfo::allow_call(),
+        weights::pallet_xcm_safe_calls::ZKVEvmWeight::<Runtime>::allow_call()
+    )
+}
+
+#[test]
+fn pallet_xcm_tracing() {
+    use pallet_xcm_tracing::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_xcm_tracing::Config>::WeightInfo::record_trace(),
+        weights::pallet_xcm_tracing::ZKVEvmWeight::<Runtime>::record_trace()
+    )
+}
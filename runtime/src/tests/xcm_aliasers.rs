@@ -0,0 +1,282 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Aliasing the origin of the accounts of the trusted chains with the XCM `AliasOrigin`
+//! instruction.
+
+use crate::{
+    configs::xcm::{
+        LinkedRelayAccounts, LocationToAccountId, RelayLocation, SameAccountKey20, XcmConfig,
+    },
+    constants::currency::tVFY,
+    tests::{ExtBuilder, ALICE, BOB},
+    AccountId, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, TrustedAliasers,
+    TrustedSiblings,
+};
+use frame_support::{assert_ok, traits::ContainsPair, weights::Weight};
+use parity_scale_codec::Encode;
+use xcm::latest::prelude::*;
+use xcm_executor::{traits::ConvertLocation, XcmExecutor};
+
+const SIBLING: u32 = 2000;
+const RELAY_ACCOUNT: [u8; 32] = [7; 32];
+const FEE: u128 = tVFY / 2;
+
+fn sibling() -> Location {
+    Location::new(1, [Parachain(SIBLING)])
+}
+
+fn account_key20(parents: u8, key: [u8; 20]) -> Location {
+    let account = AccountKey20 { network: None, key };
+    match parents {
+        0 => Location::new(0, [account]),
+        _ => Location::new(1, [Parachain(SIBLING), account]),
+    }
+}
+
+fn relay_account() -> Location {
+    Location::new(
+        1,
+        [AccountId32 {
+            network: None,
+            id: RELAY_ACCOUNT,
+        }],
+    )
+}
+
+fn sibling_account_sovereign() -> AccountId {
+    LocationToAccountId::convert_location(&account_key20(1, BOB)).unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (BOB.into(), 10 * tVFY),
+            (sibling_account_sovereign(), 10 * tVFY),
+        ])
+        .build();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn trust_aliaser(location: Location) {
+    assert_ok!(TrustedAliasers::trust_aliaser(
+        RuntimeOrigin::root(),
+        Box::new(location.into())
+    ));
+}
+
+fn link_relay_account(account: [u8; 20]) {
+    pallet_account_mapping::LinkedAccounts::<Runtime>::insert(
+        sp_runtime::AccountId32::from(RELAY_ACCOUNT),
+        AccountId::from(account),
+    );
+}
+
+mod same_account_key20 {
+    use super::*;
+
+    #[test]
+    fn aliases_same_key_of_trusted_chain() {
+        new_test_ext().execute_with(|| {
+            trust_aliaser(sibling());
+
+            assert!(SameAccountKey20::contains(
+                &account_key20(1, BOB),
+                &account_key20(0, BOB)
+            ));
+        });
+    }
+
+    #[test]
+    fn does_not_alias_untrusted_chain() {
+        new_test_ext().execute_with(|| {
+            assert!(!SameAccountKey20::contains(
+                &account_key20(1, BOB),
+                &account_key20(0, BOB)
+            ));
+        });
+    }
+
+    #[test]
+    fn does_not_alias_another_key() {
+        new_test_ext().execute_with(|| {
+            trust_aliaser(sibling());
+
+            assert!(!SameAccountKey20::contains(
+                &account_key20(1, BOB),
+                &account_key20(0, ALICE)
+            ));
+        });
+    }
+}
+
+mod linked_relay_accounts {
+    use super::*;
+
+    #[test]
+    fn aliases_relay_account_to_its_linked_account() {
+        new_test_ext().execute_with(|| {
+            trust_aliaser(RelayLocation::get());
+            link_relay_account(BOB);
+
+            assert!(LinkedRelayAccounts::contains(
+                &relay_account(),
+                &account_key20(0, BOB)
+            ));
+            assert!(!LinkedRelayAccounts::contains(
+                &relay_account(),
+                &account_key20(0, ALICE)
+            ));
+        });
+    }
+
+    #[test]
+    fn does_not_alias_unlinked_relay_account() {
+        new_test_ext().execute_with(|| {
+            trust_aliaser(RelayLocation::get());
+
+            assert!(!LinkedRelayAccounts::contains(
+                &relay_account(),
+                &account_key20(0, BOB)
+            ));
+        });
+    }
+
+    #[test]
+    fn does_not_alias_when_relay_chain_is_not_trusted() {
+        new_test_ext().execute_with(|| {
+            link_relay_account(BOB);
+
+            assert!(!LinkedRelayAccounts::contains(
+                &relay_account(),
+                &account_key20(0, BOB)
+            ));
+        });
+    }
+}
+
+mod alias_origin {
+    use super::*;
+
+    /// The account at `interior` of `origin` aliasing into BOB to remark with an event.
+    fn execute(origin: Location, interior: Junction) -> Outcome {
+        let remark = RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: b"aliased".to_vec(),
+        });
+        let message = Xcm::<RuntimeCall>(vec![
+            DescendOrigin(interior.into()),
+            WithdrawAsset((RelayLocation::get(), FEE).into()),
+            BuyExecution {
+                fees: (RelayLocation::get(), FEE).into(),
+                weight_limit: Unlimited,
+            },
+            AliasOrigin(account_key20(0, BOB)),
+            Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                fallback_max_weight: None,
+                call: remark.encode().into(),
+            },
+        ]);
+        let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+        XcmExecutor::<XcmConfig>::prepare_and_execute(
+            origin,
+            message,
+            &mut hash,
+            Weight::MAX,
+            Weight::zero(),
+        )
+    }
+
+    fn remarked_by_bob() -> bool {
+        System::events().iter().any(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::System(frame_system::Event::Remarked { ref sender, .. })
+                    if *sender == AccountId::from(BOB)
+            )
+        })
+    }
+
+    fn sibling_account() -> Junction {
+        AccountKey20 {
+            network: None,
+            key: BOB,
+        }
+    }
+
+    fn relay_account_junction() -> Junction {
+        AccountId32 {
+            network: None,
+            id: RELAY_ACCOUNT,
+        }
+    }
+
+    #[test]
+    fn trusted_sibling_account_acts_as_same_local_account() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustedSiblings::trust_sibling(
+                RuntimeOrigin::root(),
+                SIBLING.into()
+            ));
+            trust_aliaser(sibling());
+
+            assert_ok!(execute(sibling(), sibling_account()).ensure_complete());
+
+            assert!(remarked_by_bob());
+        });
+    }
+
+    #[test]
+    fn untrusted_sibling_account_cannot_alias() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustedSiblings::trust_sibling(
+                RuntimeOrigin::root(),
+                SIBLING.into()
+            ));
+
+            assert!(execute(sibling(), sibling_account())
+                .ensure_complete()
+                .is_err());
+
+            assert!(!remarked_by_bob());
+        });
+    }
+
+    #[test]
+    fn relay_account_acts_as_its_linked_account() {
+        new_test_ext().execute_with(|| {
+            trust_aliaser(RelayLocation::get());
+            link_relay_account(BOB);
+
+            assert_ok!(execute(RelayLocation::get(), relay_account_junction()).ensure_complete());
+
+            assert!(remarked_by_bob());
+        });
+    }
+
+    #[test]
+    fn relay_account_cannot_alias_an_account_it_is_not_linked_to() {
+        new_test_ext().execute_with(|| {
+            trust_aliaser(RelayLocation::get());
+            link_relay_account(ALICE);
+
+            assert!(execute(RelayLocation::get(), relay_account_junction())
+                .ensure_complete()
+                .is_err());
+
+            assert!(!remarked_by_bob());
+        });
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     configs::xcm::RelayLocation,
     constants::currency::tVFY,
     tests::{ExtBuilder, ALICE, BOB},
-    Balances, RuntimeCall, RuntimeOrigin,
+    Balances, RuntimeCall, RuntimeEvent, RuntimeOrigin, System,
 };
 use frame_support::{
     assert_err_ignore_postinfo, assert_noop, assert_ok,
@@ -133,9 +133,10 @@ fn can_call_eth_from_xcm() {
         })
 }
 
-// This is currently not supported by pallet_ethereum_xcm
+// pallet_ethereum_xcm doesn't create contracts through transact: XCM creations are dispatched by
+// `RemoteEVMCall`
 #[test]
-fn cannot_create_eth_from_xcm() {
+fn cannot_create_eth_from_ethereum_xcm_transact() {
     ExtBuilder::default()
         .with_balances(vec![(ALICE.into(), tVFY)])
         .build()
@@ -159,6 +160,141 @@ fn cannot_create_eth_from_xcm() {
         })
 }
 
+fn execute_create_eth_from_xcm() {
+    let xcm_cost = tVFY / 2;
+    let eth_create_bytes = RuntimeCall::from(pallet_ethereum_xcm::Call::<Runtime>::transact {
+        xcm_transaction: xcm_evm_create_eip_1559_transaction(CONTRACT_BYTECODE),
+    })
+    .encode()
+    .into();
+
+    let base_xcm = Box::new(VersionedXcm::from(Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), Fungible(xcm_cost)).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), Fungible(xcm_cost)).into(),
+            weight_limit: Unlimited,
+        },
+        Transact {
+            origin_kind: OriginKind::Native,
+            call: eth_create_bytes,
+            fallback_max_weight: None,
+        },
+    ])));
+
+    assert_ok!(pallet_xcm::Pallet::<Runtime>::execute(
+        RuntimeOrigin::signed(ALICE.into()),
+        base_xcm,
+        Weight::from_parts(10000000000, 1000000),
+    ));
+}
+
+fn created_contract() -> Option<H160> {
+    System::events()
+        .into_iter()
+        .find_map(|record| match record.event {
+            RuntimeEvent::EVM(pallet_evm::Event::Created { address }) => Some(address),
+            _ => None,
+        })
+}
+
+#[test]
+fn deployer_can_create_eth_from_xcm() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), tVFY)])
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+            );
+
+            execute_create_eth_from_xcm();
+
+            let contract = created_contract().expect("contract should be created");
+            assert!(!pallet_evm::AccountCodes::<Runtime>::get(contract).is_empty());
+        })
+}
+
+#[test]
+fn cannot_create_eth_from_xcm_without_deploy_permission() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), tVFY)])
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+
+            execute_create_eth_from_xcm();
+
+            assert_eq!(created_contract(), None);
+        })
+}
+
+#[test]
+fn cannot_create_eth_from_xcm_after_deploy_permission_is_revoked() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), tVFY)])
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+            );
+            assert_ok!(
+                pallet_deployment_permissions::Pallet::<Runtime>::revoke_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+            );
+
+            execute_create_eth_from_xcm();
+
+            assert_eq!(created_contract(), None);
+        })
+}
+
+// Deployers only create contracts through `RemoteEVMCall`: their origin cannot call
+// `force_transact_as`, which could act as any account.
+#[test]
+fn deployers_cannot_force_transact_as_with_the_ethereum_xcm_origin() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), tVFY), (BOB.into(), tVFY)])
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+            );
+
+            assert_noop!(
+                pallet_ethereum_xcm::Pallet::<Runtime>::force_transact_as(
+                    RawOrigin::XcmEthereumTransaction(ALICE.into()).into(),
+                    BOB.into(),
+                    xcm_evm_create_eip_1559_transaction(CONTRACT_BYTECODE),
+                    None,
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                pallet_ethereum_xcm::Pallet::<Runtime>::force_transact_as(
+                    RawOrigin::XcmEthereumTransaction(ALICE.into()).into(),
+                    ALICE.into(),
+                    xcm_evm_create_eip_1559_transaction(CONTRACT_BYTECODE),
+                    Some(H160::repeat_byte(0x42)),
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+}
+
 #[test]
 fn cannot_call_transact_remark_from_xcm() {
     ExtBuilder::default()
@@ -0,0 +1,378 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Paying for XCM execution with the registered foreign assets having a fee rate.
+
+use crate::{
+    configs::xcm::{ForeignAssetsTrader, LocationToAccountId, NativeAssetId, StakingPot},
+    constants::currency::tVFY,
+    tests::{ExtBuilder, ALICE, BOB},
+    AccountId, AssetRegistry, ForeignAssets, MessageQueue, Runtime, RuntimeEvent, RuntimeOrigin,
+    System, TrustedSiblings, XcmpQueue,
+};
+use cumulus_primitives_core::{ParaId, XcmpMessageFormat, XcmpMessageHandler};
+use frame_support::{
+    assert_ok,
+    traits::{fungibles::Inspect, ServiceQueues},
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
+use sp_runtime::FixedU128;
+use xcm::{latest::prelude::*, VersionedAssetId, VersionedXcm};
+use xcm_executor::{
+    traits::{ConvertLocation, WeightTrader},
+    AssetsInHolding,
+};
+use xcm_runtime_apis::fees::{
+    runtime_decl_for_xcm_payment_api::XcmPaymentApiV1, Error as XcmPaymentApiError,
+};
+
+/// The sibling parachain holding the reserve of the foreign asset.
+const RESERVE: u32 = 1000;
+const ASSET_ID: u32 = 1;
+const AMOUNT: u128 = 10 * tVFY;
+const FEE: u128 = 2 * tVFY;
+
+/// The location of the foreign asset, as seen from VFlow.
+fn foreign_location() -> Location {
+    Location::new(
+        1,
+        [Parachain(RESERVE), PalletInstance(50), GeneralIndex(1984)],
+    )
+}
+
+/// Two units of the foreign asset for each unit of native token.
+fn rate() -> FixedU128 {
+    FixedU128::from_u32(2)
+}
+
+fn sovereign_account(para_id: u32) -> AccountId {
+    LocationToAccountId::convert_location(&Location::new(1, [Parachain(para_id)])).unwrap()
+}
+
+/// Registers the foreign asset, without a fee rate, and trusts its reserve.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (ALICE.into(), 10 * tVFY),
+            (sovereign_account(RESERVE), 10 * tVFY),
+        ])
+        .build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            ALICE.into(),
+            true,
+            1
+        ));
+        assert_ok!(AssetRegistry::register_foreign_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            Box::new(foreign_location().into())
+        ));
+        assert_ok!(TrustedSiblings::trust_sibling(
+            RuntimeOrigin::root(),
+            RESERVE.into()
+        ));
+    });
+    ext
+}
+
+fn set_fee_rate() {
+    assert_ok!(AssetRegistry::set_fee_rate(
+        RuntimeOrigin::root(),
+        ASSET_ID,
+        rate()
+    ));
+}
+
+fn asset_balance(account: impl Into<AccountId>) -> u128 {
+    ForeignAssets::balance(ASSET_ID, account.into())
+}
+
+mod trader {
+    use super::*;
+
+    fn weight() -> Weight {
+        Weight::from_parts(100_000_000, 0)
+    }
+
+    fn context() -> XcmContext {
+        XcmContext::with_message_id([0; 32])
+    }
+
+    fn payment(amount: u128) -> AssetsInHolding {
+        Asset::from((foreign_location(), amount)).into()
+    }
+
+    #[test]
+    fn charges_the_native_fee_at_the_fee_rate() {
+        new_test_ext().execute_with(|| {
+            set_fee_rate();
+            let fee = ForeignAssetsTrader::weight_to_fee(&weight(), rate());
+            assert!(fee > 0 && fee < FEE);
+
+            let mut trader = ForeignAssetsTrader::new();
+            let unused = trader
+                .buy_weight(weight(), payment(FEE), &context())
+                .unwrap();
+
+            assert_eq!(
+                unused.fungible_assets_iter().collect::<Vec<_>>(),
+                vec![Asset::from((foreign_location(), FEE - fee))]
+            );
+            drop(trader);
+            assert_eq!(asset_balance(StakingPot::get()), fee);
+        });
+    }
+
+    #[test]
+    fn refunds_the_unused_weight() {
+        new_test_ext().execute_with(|| {
+            set_fee_rate();
+            let fee = ForeignAssetsTrader::weight_to_fee(&weight(), rate());
+
+            let mut trader = ForeignAssetsTrader::new();
+            trader
+                .buy_weight(weight(), payment(FEE), &context())
+                .unwrap();
+            let refund = trader.refund_weight(weight() / 2, &context()).unwrap();
+
+            assert_eq!(refund.id, AssetId(foreign_location()));
+            let Fungible(refunded) = refund.fun else {
+                panic!("The refund should be fungible")
+            };
+            assert!(refunded > 0);
+            // Nothing more than the bought weight can be refunded.
+            let rest = trader.refund_weight(weight(), &context()).unwrap();
+            let Fungible(rest) = rest.fun else {
+                panic!("The refund should be fungible")
+            };
+            assert!(trader.refund_weight(weight(), &context()).is_none());
+            drop(trader);
+            // Only the fees not refunded are collected.
+            assert_eq!(asset_balance(StakingPot::get()), fee - refunded - rest);
+        });
+    }
+
+    /// Raises the minimum balance of the foreign asset above the fee charged for `weight()`.
+    fn set_minimum_balance_above_fee(fee: u128) -> u128 {
+        let minimum_balance = fee + 1;
+        assert_ok!(ForeignAssets::force_asset_status(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            ALICE.into(),
+            ALICE.into(),
+            ALICE.into(),
+            ALICE.into(),
+            minimum_balance,
+            true,
+            false
+        ));
+        minimum_balance
+    }
+
+    #[test]
+    fn charges_at_least_the_minimum_balance_to_the_empty_pot() {
+        new_test_ext().execute_with(|| {
+            set_fee_rate();
+            let fee = ForeignAssetsTrader::weight_to_fee(&weight(), rate());
+            let minimum_balance = set_minimum_balance_above_fee(fee);
+
+            let mut trader = ForeignAssetsTrader::new();
+            let unused = trader
+                .buy_weight(weight(), payment(FEE), &context())
+                .unwrap();
+
+            assert_eq!(
+                unused.fungible_assets_iter().collect::<Vec<_>>(),
+                vec![Asset::from((foreign_location(), FEE - minimum_balance))]
+            );
+            // Refunding part of the weight would leave less than the minimum balance.
+            assert!(trader.refund_weight(weight() / 2, &context()).is_none());
+            drop(trader);
+            assert_eq!(asset_balance(StakingPot::get()), minimum_balance);
+        });
+    }
+
+    #[test]
+    fn refunds_everything_when_no_weight_is_used() {
+        new_test_ext().execute_with(|| {
+            set_fee_rate();
+            let fee = ForeignAssetsTrader::weight_to_fee(&weight(), rate());
+            let minimum_balance = set_minimum_balance_above_fee(fee);
+
+            let mut trader = ForeignAssetsTrader::new();
+            trader
+                .buy_weight(weight(), payment(FEE), &context())
+                .unwrap();
+
+            assert_eq!(
+                trader.refund_weight(weight(), &context()),
+                Some((foreign_location(), minimum_balance).into())
+            );
+            drop(trader);
+            assert_eq!(asset_balance(StakingPot::get()), 0);
+        });
+    }
+
+    #[test]
+    fn rejects_assets_without_fee_rate() {
+        new_test_ext().execute_with(|| {
+            let mut trader = ForeignAssetsTrader::new();
+            assert_eq!(
+                trader.buy_weight(weight(), payment(FEE), &context()).err(),
+                Some(XcmError::AssetNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_insufficient_payment() {
+        new_test_ext().execute_with(|| {
+            set_fee_rate();
+            let fee = ForeignAssetsTrader::weight_to_fee(&weight(), rate());
+
+            let mut trader = ForeignAssetsTrader::new();
+            assert_eq!(
+                trader
+                    .buy_weight(weight(), payment(fee - 1), &context())
+                    .err(),
+                Some(XcmError::TooExpensive)
+            );
+        });
+    }
+}
+
+mod inbound {
+    use super::*;
+
+    /// Delivers `message` from the reserve through the XCMP queue and processes it.
+    fn receive_from_reserve(message: Xcm<()>) {
+        let mut data = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+        data.extend(VersionedXcm::from(message).encode());
+        XcmpQueue::handle_xcmp_messages(
+            [(ParaId::from(RESERVE), 1, &data[..])].into_iter(),
+            Weight::MAX,
+        );
+        MessageQueue::service_queues(Weight::MAX);
+    }
+
+    fn processed_successfully() -> bool {
+        System::events().iter().any(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+                    success: true,
+                    ..
+                })
+            )
+        })
+    }
+
+    /// The reserve transferring `AMOUNT` of the foreign asset to BOB, paying the fees with it.
+    fn reserve_asset_deposited_message() -> Xcm<()> {
+        Xcm(vec![
+            ReserveAssetDeposited((foreign_location(), AMOUNT).into()),
+            ClearOrigin,
+            BuyExecution {
+                fees: (foreign_location(), FEE).into(),
+                weight_limit: Unlimited,
+            },
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: Location::new(
+                    0,
+                    [AccountKey20 {
+                        network: None,
+                        key: BOB,
+                    }],
+                ),
+            },
+        ])
+    }
+
+    #[test]
+    fn reserve_can_pay_fees_with_its_asset() {
+        new_test_ext().execute_with(|| {
+            set_fee_rate();
+            let weight =
+                Runtime::query_xcm_weight(VersionedXcm::from(reserve_asset_deposited_message()))
+                    .unwrap();
+            let fee = ForeignAssetsTrader::weight_to_fee(&weight, rate());
+
+            receive_from_reserve(reserve_asset_deposited_message());
+
+            assert!(processed_successfully());
+            assert!(fee > 0);
+            assert_eq!(asset_balance(StakingPot::get()), fee);
+            assert_eq!(asset_balance(BOB), AMOUNT - fee);
+        });
+    }
+
+    #[test]
+    fn asset_without_fee_rate_cannot_pay_fees() {
+        new_test_ext().execute_with(|| {
+            receive_from_reserve(reserve_asset_deposited_message());
+
+            assert!(!processed_successfully());
+            assert_eq!(asset_balance(BOB), 0);
+        });
+    }
+}
+
+mod payment_api {
+    use super::*;
+
+    #[test]
+    fn lists_assets_with_fee_rate() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(
+                Runtime::query_acceptable_payment_assets(XCM_VERSION),
+                Ok(vec![VersionedAssetId::from(NativeAssetId::get())])
+            );
+
+            set_fee_rate();
+
+            assert_eq!(
+                Runtime::query_acceptable_payment_assets(XCM_VERSION),
+                Ok(vec![
+                    VersionedAssetId::from(NativeAssetId::get()),
+                    VersionedAssetId::from(AssetId(foreign_location())),
+                ])
+            );
+        });
+    }
+
+    #[test]
+    fn prices_assets_with_fee_rate() {
+        new_test_ext().execute_with(|| {
+            let weight = Weight::from_parts(1_000_000, 0);
+            let asset = VersionedAssetId::from(AssetId(foreign_location()));
+            assert_eq!(
+                Runtime::query_weight_to_asset_fee(weight, asset.clone()),
+                Err(XcmPaymentApiError::AssetNotFound)
+            );
+
+            set_fee_rate();
+
+            assert_eq!(
+                Runtime::query_weight_to_asset_fee(weight, asset),
+                Ok(ForeignAssetsTrader::weight_to_fee(&weight, rate()))
+            );
+        });
+    }
+}
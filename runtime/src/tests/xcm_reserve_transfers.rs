@@ -0,0 +1,274 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reserve transfers of foreign assets whose reserve is a sibling parachain.
+
+use crate::{
+    configs::xcm::{LocationToAccountId, RelayLocation, StakingPot},
+    constants::currency::tVFY,
+    tests::{ExtBuilder, ALICE, BOB},
+    AccountId, AssetRegistry, Balances, ForeignAssets, MessageQueue, ParachainSystem, Runtime,
+    RuntimeEvent, RuntimeOrigin, System, TrustedSiblings, XcmpQueue, ZKVXcm,
+};
+use cumulus_primitives_core::{ParaId, XcmpMessageFormat, XcmpMessageHandler};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{fungibles::Inspect, ServiceQueues},
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
+use xcm::{latest::prelude::*, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_executor::traits::ConvertLocation;
+
+/// The sibling parachain holding the reserve of the foreign asset.
+const RESERVE: u32 = 1000;
+/// Another trusted sibling parachain, which is not the reserve of the foreign asset.
+const OTHER_SIBLING: u32 = 2000;
+const ASSET_ID: u32 = 1;
+const AMOUNT: u128 = 1_000_000;
+const FEE: u128 = tVFY / 2;
+
+/// The location of the foreign asset, as seen from VFlow.
+fn foreign_location() -> Location {
+    Location::new(
+        1,
+        [Parachain(RESERVE), PalletInstance(50), GeneralIndex(1984)],
+    )
+}
+
+fn sovereign_account(para_id: u32) -> AccountId {
+    LocationToAccountId::convert_location(&Location::new(1, [Parachain(para_id)])).unwrap()
+}
+
+fn beneficiary(account: [u8; 20]) -> Location {
+    Location::new(
+        0,
+        [AccountKey20 {
+            network: None,
+            key: account,
+        }],
+    )
+}
+
+/// Registers the foreign asset and trusts both the siblings.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (ALICE.into(), 10 * tVFY),
+            (sovereign_account(RESERVE), 10 * tVFY),
+            (sovereign_account(OTHER_SIBLING), 10 * tVFY),
+        ])
+        .build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            ALICE.into(),
+            true,
+            1
+        ));
+        assert_ok!(AssetRegistry::register_foreign_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            Box::new(foreign_location().into())
+        ));
+        for para_id in [RESERVE, OTHER_SIBLING] {
+            assert_ok!(TrustedSiblings::trust_sibling(
+                RuntimeOrigin::root(),
+                para_id.into()
+            ));
+        }
+    });
+    ext
+}
+
+/// Delivers `message` from the sibling `para_id` through the XCMP queue and processes it.
+fn receive_from_sibling(para_id: u32, message: Xcm<()>) {
+    let mut data = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+    data.extend(VersionedXcm::from(message).encode());
+    XcmpQueue::handle_xcmp_messages(
+        [(ParaId::from(para_id), 1, &data[..])].into_iter(),
+        Weight::MAX,
+    );
+    MessageQueue::service_queues(Weight::MAX);
+}
+
+fn processed_successfully() -> bool {
+    System::events().iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+                success: true,
+                ..
+            })
+        )
+    })
+}
+
+/// The message sent by a sibling transferring `AMOUNT` of `asset` to BOB, paying the fees in tVFY
+/// from its sovereign account.
+fn reserve_asset_deposited_message(asset: Location) -> Xcm<()> {
+    Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), FEE).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), FEE).into(),
+            weight_limit: Unlimited,
+        },
+        ReserveAssetDeposited((asset, AMOUNT).into()),
+        ClearOrigin,
+        DepositAsset {
+            assets: Wild(AllCounted(2)),
+            beneficiary: beneficiary(BOB),
+        },
+    ])
+}
+
+fn asset_balance(account: [u8; 20]) -> u128 {
+    ForeignAssets::balance(ASSET_ID, AccountId::from(account))
+}
+
+fn native_balance(account: impl Into<AccountId>) -> u128 {
+    Balances::free_balance(account.into())
+}
+
+mod inbound {
+    use super::*;
+
+    #[test]
+    fn reserve_can_transfer_its_asset() {
+        new_test_ext().execute_with(|| {
+            let pot_before = native_balance(StakingPot::get());
+
+            receive_from_sibling(RESERVE, reserve_asset_deposited_message(foreign_location()));
+
+            assert!(processed_successfully());
+            assert_eq!(asset_balance(BOB), AMOUNT);
+            // The fees not used to buy execution are deposited to the beneficiary, the others go
+            // to the collators.
+            let refunded = native_balance(BOB);
+            let collected = native_balance(StakingPot::get()) - pot_before;
+            assert!(refunded > 0 && collected > 0);
+            assert_eq!(refunded + collected, FEE);
+            assert_eq!(native_balance(sovereign_account(RESERVE)), 10 * tVFY - FEE);
+        });
+    }
+
+    #[test]
+    fn other_sibling_is_not_trusted_as_reserve() {
+        new_test_ext().execute_with(|| {
+            receive_from_sibling(
+                OTHER_SIBLING,
+                reserve_asset_deposited_message(foreign_location()),
+            );
+
+            assert!(!processed_successfully());
+            assert_eq!(asset_balance(BOB), 0);
+        });
+    }
+
+    #[test]
+    fn unregistered_asset_is_not_accepted() {
+        new_test_ext().execute_with(|| {
+            receive_from_sibling(
+                RESERVE,
+                reserve_asset_deposited_message(Location::new(
+                    1,
+                    [Parachain(RESERVE), PalletInstance(50), GeneralIndex(1337)],
+                )),
+            );
+
+            assert!(!processed_successfully());
+        });
+    }
+}
+
+mod outbound {
+    use super::*;
+
+    fn reserve_transfer(asset: Location, amount: u128) -> frame_support::dispatch::DispatchResult {
+        ZKVXcm::limited_reserve_transfer_assets(
+            RuntimeOrigin::signed(ALICE.into()),
+            Box::new(VersionedLocation::from(Location::new(
+                1,
+                [Parachain(RESERVE)],
+            ))),
+            Box::new(VersionedLocation::from(beneficiary(BOB))),
+            Box::new(VersionedAssets::from(Assets::from((asset, amount)))),
+            0,
+            Unlimited,
+        )
+    }
+
+    #[test]
+    fn can_reserve_transfer_foreign_asset_back_to_its_reserve() {
+        new_test_ext().execute_with(|| {
+            ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(RESERVE.into());
+            assert_ok!(ForeignAssets::mint(
+                RuntimeOrigin::signed(ALICE.into()),
+                ASSET_ID,
+                ALICE.into(),
+                AMOUNT
+            ));
+
+            assert_ok!(reserve_transfer(foreign_location(), AMOUNT / 2));
+
+            // The transferred assets are burnt here and released by the reserve.
+            assert_eq!(asset_balance(ALICE), AMOUNT / 2);
+            assert_eq!(ForeignAssets::total_issuance(ASSET_ID), AMOUNT / 2);
+
+            // The reserve withdraws the asset from our sovereign account and uses it to pay the
+            // fees.
+            let reanchored: Asset = (
+                Location::new(0, [PalletInstance(50), GeneralIndex(1984)]),
+                AMOUNT / 2,
+            )
+                .into();
+            let sent = System::events()
+                .into_iter()
+                .find_map(|record| match record.event {
+                    RuntimeEvent::ZKVXcm(pallet_xcm::Event::Sent {
+                        destination,
+                        message,
+                        ..
+                    }) if destination == Location::new(1, [Parachain(RESERVE)]) => Some(message),
+                    _ => None,
+                })
+                .expect("The message should be sent to the reserve");
+            assert!(matches!(
+                sent.0.as_slice(),
+                [
+                    WithdrawAsset(assets),
+                    ClearOrigin,
+                    BuyExecution { fees, .. },
+                    DepositAsset { .. },
+                    ..
+                ] if assets.inner() == [reanchored.clone()] && *fees == reanchored
+            ));
+        });
+    }
+
+    #[test]
+    fn cannot_reserve_transfer_native_token() {
+        new_test_ext().execute_with(|| {
+            ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(RESERVE.into());
+
+            assert_noop!(
+                reserve_transfer(RelayLocation::get(), tVFY),
+                pallet_xcm::Error::<Runtime>::Filtered
+            );
+        });
+    }
+}
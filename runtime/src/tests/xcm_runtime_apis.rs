@@ -0,0 +1,219 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::xcm_teleport_integration::new_test_ext;
+use crate::{
+    configs::xcm::{LocationToAccountId, NativeAssetId, RelayLocation},
+    constants::currency::tVFY,
+    tests::{ExtBuilder, ALICE, BOB},
+    AccountId, OriginCaller, Runtime, RuntimeCall, RuntimeEvent,
+};
+use frame_support::weights::{Weight, WeightToFee};
+use parity_scale_codec::Encode;
+use sp_core::{ConstU32, H160, U256};
+use sp_runtime::BoundedVec;
+use xcm::{latest::prelude::*, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_executor::traits::ConvertLocation;
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV2};
+use xcm_runtime_apis::{
+    conversions::runtime_decl_for_location_to_account_api::LocationToAccountApiV1,
+    dry_run::runtime_decl_for_dry_run_api::DryRunApiV1,
+    fees::{runtime_decl_for_xcm_payment_api::XcmPaymentApiV1, Error as XcmPaymentApiError},
+};
+
+const FEE: u128 = tVFY / 2;
+
+fn teleport_to_relay_call() -> RuntimeCall {
+    pallet_xcm::Call::<Runtime>::limited_teleport_assets {
+        dest: Box::new(VersionedLocation::from(RelayLocation::get())),
+        beneficiary: Box::new(VersionedLocation::from(Location::new(
+            0,
+            [AccountId32 {
+                network: None,
+                id: [0x42; 32],
+            }],
+        ))),
+        assets: Box::new(VersionedAssets::from(Assets::from(vec![Asset {
+            id: NativeAssetId::get(),
+            fun: Fungible(tVFY),
+        }]))),
+        fee_asset_item: 0,
+        weight_limit: Unlimited,
+    }
+    .into()
+}
+
+/// The relay chain paying for the execution of an `EthereumXcm::transact` of `value` to BOB.
+fn inbound_ethereum_transact(value: u128) -> Xcm<RuntimeCall> {
+    let call = RuntimeCall::from(pallet_ethereum_xcm::Call::<Runtime>::transact {
+        xcm_transaction: EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+            gas_limit: U256::from(21000),
+            action: ethereum::TransactionAction::Call(BOB.into()),
+            value: U256::from(value),
+            input:
+                BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::default(
+                ),
+            access_list: None,
+        }),
+    });
+    Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), FEE).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), FEE).into(),
+            weight_limit: Unlimited,
+        },
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            fallback_max_weight: None,
+            call: call.encode().into(),
+        },
+    ])
+}
+
+fn relay_sovereign_account() -> AccountId {
+    LocationToAccountId::convert_location(&RelayLocation::get()).unwrap()
+}
+
+mod dry_run_api {
+    use super::*;
+
+    #[test]
+    fn dry_run_call_forwards_teleport_to_relay() {
+        new_test_ext().execute_with(|| {
+            let origin = OriginCaller::system(frame_system::RawOrigin::Signed(ALICE.into()));
+
+            let effects = Runtime::dry_run_call(origin, teleport_to_relay_call()).unwrap();
+
+            assert!(effects.execution_result.is_ok());
+            assert_eq!(effects.forwarded_xcms.len(), 1);
+            let (destination, messages) = &effects.forwarded_xcms[0];
+            assert_eq!(destination, &VersionedLocation::from(RelayLocation::get()));
+            assert_eq!(messages.len(), 1);
+            let message: Xcm<()> = messages[0].clone().try_into().unwrap();
+            assert!(matches!(message.0[0], ReceiveTeleportedAsset(_)));
+            assert!(effects.emitted_events.iter().any(|event| matches!(
+                event,
+                RuntimeEvent::ZKVXcm(pallet_xcm::Event::Attempted { .. })
+            )));
+        });
+    }
+
+    #[test]
+    fn dry_run_xcm_executes_inbound_ethereum_transact() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (relay_sovereign_account(), 10 * tVFY),
+                (BOB.into(), tVFY),
+            ])
+            .build()
+            .execute_with(|| {
+                let effects = Runtime::dry_run_xcm(
+                    VersionedLocation::from(RelayLocation::get()),
+                    VersionedXcm::from(inbound_ethereum_transact(tVFY / 10)),
+                )
+                .unwrap();
+
+                assert!(matches!(effects.execution_result, Outcome::Complete { .. }));
+                assert!(effects.emitted_events.iter().any(|event| matches!(
+                    event,
+                    RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed { to, .. })
+                        if *to == H160::from(BOB)
+                )));
+            });
+    }
+}
+
+mod xcm_payment_api {
+    use super::*;
+
+    #[test]
+    fn only_native_asset_is_accepted() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(
+                Runtime::query_acceptable_payment_assets(XCM_VERSION),
+                Ok(vec![VersionedAssetId::from(NativeAssetId::get())])
+            );
+            assert_eq!(
+                Runtime::query_weight_to_asset_fee(
+                    Weight::from_parts(1_000_000, 0),
+                    VersionedAssetId::from(AssetId(Location::here())),
+                ),
+                Err(XcmPaymentApiError::AssetNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn fee_of_inbound_ethereum_transact_matches_weight_to_fee() {
+        new_test_ext().execute_with(|| {
+            let message: Xcm<()> = inbound_ethereum_transact(tVFY / 10).into();
+
+            let weight = Runtime::query_xcm_weight(VersionedXcm::from(message)).unwrap();
+            let fee = Runtime::query_weight_to_asset_fee(
+                weight,
+                VersionedAssetId::from(NativeAssetId::get()),
+            )
+            .unwrap();
+
+            assert!(weight.ref_time() > 0);
+            assert_eq!(
+                fee,
+                <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+                    &weight
+                )
+            );
+            assert!(fee <= FEE);
+        });
+    }
+
+    #[test]
+    fn delivery_fees_to_relay_are_paid_in_native_asset() {
+        new_test_ext().execute_with(|| {
+            let origin = OriginCaller::system(frame_system::RawOrigin::Signed(ALICE.into()));
+            let effects = Runtime::dry_run_call(origin, teleport_to_relay_call()).unwrap();
+            let (destination, messages) = effects.forwarded_xcms[0].clone();
+
+            let fees = Runtime::query_delivery_fees(destination, messages[0].clone()).unwrap();
+
+            let fees: Assets = fees.try_into().unwrap();
+            assert_eq!(fees.len(), 1);
+            assert_eq!(fees.get(0).unwrap().id, NativeAssetId::get());
+        });
+    }
+}
+
+mod location_to_account_api {
+    use super::*;
+
+    #[test]
+    fn converts_local_and_relay_locations() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(
+                Runtime::convert_location(VersionedLocation::from(Location::new(
+                    0,
+                    [AccountKey20 {
+                        network: None,
+                        key: ALICE,
+                    }],
+                ))),
+                Ok(AccountId::from(ALICE))
+            );
+            assert_eq!(
+                Runtime::convert_location(VersionedLocation::from(RelayLocation::get())),
+                Ok(relay_sovereign_account())
+            );
+        });
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The calls which XCM `Transact` may dispatch, kept by governance in `XcmSafeCalls`.
+
+use crate::{
+    configs::xcm::{RelayLocation, SafeCallFilter},
+    constants::currency::tVFY,
+    tests::{ExtBuilder, ALICE},
+    Runtime, RuntimeCall, RuntimeOrigin, XcmSafeCalls,
+};
+use frame_support::{
+    assert_err_ignore_postinfo, assert_ok, dispatch::DispatchResultWithPostInfo, traits::Contains,
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
+use sp_runtime::{DispatchError, ModuleError};
+use xcm::{latest::prelude::*, VersionedXcm};
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark {
+        remark: b"remark".to_vec(),
+    })
+}
+
+fn remark_with_event() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: b"remark".to_vec(),
+    })
+}
+
+fn call_index(call: &RuntimeCall) -> (u8, u8) {
+    call.using_encoded(|encoded| (encoded[0], encoded[1]))
+}
+
+fn allow(call: &RuntimeCall) {
+    let (pallet_index, call_index) = call_index(call);
+    assert_ok!(XcmSafeCalls::allow_call(
+        RuntimeOrigin::root(),
+        pallet_index,
+        call_index
+    ));
+}
+
+fn disallow(call: &RuntimeCall) {
+    let (pallet_index, call_index) = call_index(call);
+    assert_ok!(XcmSafeCalls::disallow_call(
+        RuntimeOrigin::root(),
+        pallet_index,
+        call_index
+    ));
+}
+
+mod default_filter {
+    use super::*;
+
+    #[test]
+    fn allows_ethereum_xcm_calls() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert!(SafeCallFilter::contains(&RuntimeCall::EthereumXcm(
+                pallet_ethereum_xcm::Call::suspend_ethereum_xcm_execution {}
+            )));
+            assert!(SafeCallFilter::contains(&RuntimeCall::EthereumXcm(
+                pallet_ethereum_xcm::Call::resume_ethereum_xcm_execution {}
+            )));
+        });
+    }
+
+    #[test]
+    fn allows_account_mapping_calls() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert!(SafeCallFilter::contains(&RuntimeCall::AccountMapping(
+                pallet_account_mapping::Call::unlink_account {}
+            )));
+        });
+    }
+
+    #[test]
+    fn allows_remark_with_event() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert!(SafeCallFilter::contains(&remark_with_event()));
+        });
+    }
+
+    #[test]
+    fn does_not_allow_other_calls() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert!(!SafeCallFilter::contains(&remark()));
+            assert!(!SafeCallFilter::contains(&RuntimeCall::Balances(
+                pallet_balances::Call::transfer_allow_death {
+                    dest: ALICE.into(),
+                    value: tVFY,
+                }
+            )));
+        });
+    }
+}
+
+mod edited_filter {
+    use super::*;
+
+    #[test]
+    fn allows_allowed_call() {
+        ExtBuilder::default().build().execute_with(|| {
+            allow(&remark());
+
+            assert!(SafeCallFilter::contains(&remark()));
+            assert!(SafeCallFilter::contains(&remark_with_event()));
+        });
+    }
+
+    #[test]
+    fn allows_allowed_calls_of_any_pallet() {
+        ExtBuilder::default().build().execute_with(|| {
+            let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                dest: ALICE.into(),
+                value: tVFY,
+            });
+            let allow_call = RuntimeCall::XcmSafeCalls(pallet_xcm_safe_calls::Call::allow_call {
+                pallet_index: 0,
+                call_index: 0,
+            });
+            allow(&transfer);
+            allow(&allow_call);
+
+            assert!(SafeCallFilter::contains(&transfer));
+            assert!(SafeCallFilter::contains(&allow_call));
+            assert!(!SafeCallFilter::contains(&RuntimeCall::XcmSafeCalls(
+                pallet_xcm_safe_calls::Call::disallow_call {
+                    pallet_index: 0,
+                    call_index: 0,
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn does_not_allow_disallowed_call() {
+        ExtBuilder::default().build().execute_with(|| {
+            disallow(&remark_with_event());
+
+            assert!(!SafeCallFilter::contains(&remark_with_event()));
+            assert!(SafeCallFilter::contains(&RuntimeCall::AccountMapping(
+                pallet_account_mapping::Call::unlink_account {}
+            )));
+        });
+    }
+}
+
+mod transact {
+    use super::*;
+
+    fn execute_transact(call: RuntimeCall) -> DispatchResultWithPostInfo {
+        let xcm_cost = tVFY / 2;
+        let message = Box::new(VersionedXcm::from(Xcm(vec![
+            WithdrawAsset((RelayLocation::get(), xcm_cost).into()),
+            BuyExecution {
+                fees: (RelayLocation::get(), xcm_cost).into(),
+                weight_limit: Unlimited,
+            },
+            Transact {
+                origin_kind: OriginKind::Native,
+                call: call.encode().into(),
+                fallback_max_weight: None,
+            },
+        ])));
+
+        pallet_xcm::Pallet::<Runtime>::execute(
+            RuntimeOrigin::signed(ALICE.into()),
+            message,
+            Weight::from_parts(10000000000, 10000),
+        )
+    }
+
+    #[test]
+    fn can_transact_allowed_call() {
+        ExtBuilder::default()
+            .with_balances(vec![(ALICE.into(), tVFY)])
+            .build()
+            .execute_with(|| {
+                allow(&remark());
+
+                assert_ok!(execute_transact(remark()));
+            });
+    }
+
+    #[test]
+    fn cannot_transact_disallowed_call() {
+        ExtBuilder::default()
+            .with_balances(vec![(ALICE.into(), tVFY)])
+            .build()
+            .execute_with(|| {
+                disallow(&remark_with_event());
+
+                assert_err_ignore_postinfo!(
+                    execute_transact(remark_with_event()),
+                    DispatchError::Module(ModuleError {
+                        index: 31,
+                        error: [24, 0, 0, 0],
+                        message: Some("LocalExecutionIncomplete")
+                    }),
+                );
+            });
+    }
+}
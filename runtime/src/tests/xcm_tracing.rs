@@ -0,0 +1,161 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tracing of the inbound and outbound XCM messages by `XcmTracing`.
+
+use crate::{
+    configs::xcm::{LocationToAccountId, RelayLocation, XcmRouter},
+    constants::currency::tVFY,
+    tests::{ExtBuilder, BOB},
+    AccountId, MessageQueue, RuntimeOrigin, System, TrustedSiblings, XcmTracing, XcmpQueue,
+};
+use cumulus_primitives_core::{ParaId, XcmpMessageFormat, XcmpMessageHandler};
+use frame_support::{assert_ok, traits::ServiceQueues, weights::Weight};
+use pallet_xcm_tracing::{Direction, TraceOutcome};
+use parity_scale_codec::Encode;
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::traits::ConvertLocation;
+
+const SIBLING: u32 = 2000;
+const FEE: u128 = tVFY / 2;
+const TOPIC: XcmHash = [42; 32];
+
+fn sibling() -> Location {
+    Location::new(1, [Parachain(SIBLING)])
+}
+
+fn sibling_sovereign_account() -> AccountId {
+    LocationToAccountId::convert_location(&sibling()).unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(sibling_sovereign_account(), 10 * tVFY)])
+        .build();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Delivers `message` from `SIBLING` through the XCMP queue and processes it.
+fn receive_from_sibling(message: Xcm<()>) {
+    let mut data = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+    data.extend(VersionedXcm::from(message).encode());
+    XcmpQueue::handle_xcmp_messages(
+        [(ParaId::from(SIBLING), 1, &data[..])].into_iter(),
+        Weight::MAX,
+    );
+    MessageQueue::service_queues(Weight::MAX);
+}
+
+fn paid_message(instruction: Instruction<()>) -> Xcm<()> {
+    Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), FEE).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), FEE).into(),
+            weight_limit: Unlimited,
+        },
+        instruction,
+        SetTopic(TOPIC),
+    ])
+}
+
+fn trust_sibling() {
+    assert_ok!(TrustedSiblings::trust_sibling(
+        RuntimeOrigin::root(),
+        SIBLING.into()
+    ));
+}
+
+mod inbound {
+    use super::*;
+
+    #[test]
+    fn traces_complete_message() {
+        new_test_ext().execute_with(|| {
+            trust_sibling();
+
+            receive_from_sibling(paid_message(DepositAsset {
+                assets: AllCounted(1).into(),
+                beneficiary: AccountKey20 {
+                    network: None,
+                    key: BOB,
+                }
+                .into(),
+            }));
+
+            let traces = XcmTracing::traces_by_topic(TOPIC);
+            assert_eq!(traces.len(), 1);
+            let trace = &traces[0];
+            assert_eq!(trace.direction, Direction::Inbound);
+            assert_eq!(trace.outcome, TraceOutcome::Complete);
+            assert_eq!(trace.location, sibling());
+            assert_eq!(trace.account, Some(sibling_sovereign_account()));
+            assert_eq!(trace.fees, Some((RelayLocation::get(), FEE)));
+            assert!(trace.weight_used.any_gt(Weight::zero()));
+            assert_eq!(
+                XcmTracing::traces_by_account(sibling_sovereign_account()),
+                traces
+            );
+        });
+    }
+
+    #[test]
+    fn traces_incomplete_message() {
+        new_test_ext().execute_with(|| {
+            trust_sibling();
+
+            receive_from_sibling(paid_message(Trap(1)));
+
+            let traces = XcmTracing::traces_by_topic(TOPIC);
+            assert_eq!(traces.len(), 1);
+            assert_eq!(traces[0].outcome, TraceOutcome::Incomplete);
+        });
+    }
+
+    #[test]
+    fn does_not_trace_rejected_message() {
+        new_test_ext().execute_with(|| {
+            receive_from_sibling(paid_message(ClearOrigin));
+
+            assert!(XcmTracing::traces_by_topic(TOPIC).is_empty());
+        });
+    }
+}
+
+mod outbound {
+    use super::*;
+
+    #[test]
+    fn traces_sent_message() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(
+                pallet_xcm::Pallet::<crate::Runtime>::force_default_xcm_version(
+                    RuntimeOrigin::root(),
+                    Some(XCM_VERSION)
+                )
+            );
+
+            let (topic, _) =
+                send_xcm::<XcmRouter>(RelayLocation::get(), Xcm(vec![ClearOrigin])).unwrap();
+
+            let traces = XcmTracing::traces_by_topic(topic);
+            assert_eq!(traces.len(), 1);
+            assert_eq!(traces[0].direction, Direction::Outbound);
+            assert_eq!(traces[0].outcome, TraceOutcome::Sent);
+            assert_eq!(traces[0].location, RelayLocation::get());
+            assert_eq!(traces[0].account, None);
+        });
+    }
+}
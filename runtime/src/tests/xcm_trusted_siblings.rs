@@ -0,0 +1,170 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    configs::xcm::{LocationToAccountId, RelayLocation},
+    constants::currency::tVFY,
+    tests::{ExtBuilder, BOB},
+    AccountId, Balances, MessageQueue, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System,
+    TrustedSiblings, XcmpQueue,
+};
+use cumulus_primitives_core::{ParaId, XcmpMessageFormat, XcmpMessageHandler};
+use frame_support::{assert_ok, traits::ServiceQueues, weights::Weight};
+use parity_scale_codec::Encode;
+use sp_core::{ConstU32, U256};
+use sp_runtime::BoundedVec;
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::traits::ConvertLocation;
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV2};
+
+const SIBLING: u32 = 2000;
+const FEE: u128 = tVFY / 2;
+const VALUE: u128 = tVFY / 10;
+
+fn sibling_sovereign_account() -> AccountId {
+    LocationToAccountId::convert_location(&Location::new(1, [Parachain(SIBLING)])).unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (sibling_sovereign_account(), 10 * tVFY),
+            (BOB.into(), tVFY),
+        ])
+        .build();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// The sibling paying, with its sovereign account, for an `EthereumXcm::transact` of `VALUE` to
+/// BOB.
+fn ethereum_transact_message() -> Xcm<()> {
+    let call = RuntimeCall::from(pallet_ethereum_xcm::Call::<Runtime>::transact {
+        xcm_transaction: EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+            gas_limit: U256::from(21000),
+            action: ethereum::TransactionAction::Call(BOB.into()),
+            value: U256::from(VALUE),
+            input:
+                BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::default(
+                ),
+            access_list: None,
+        }),
+    });
+    Xcm(vec![
+        WithdrawAsset((RelayLocation::get(), FEE).into()),
+        BuyExecution {
+            fees: (RelayLocation::get(), FEE).into(),
+            weight_limit: Unlimited,
+        },
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            fallback_max_weight: None,
+            call: call.encode().into(),
+        },
+    ])
+}
+
+/// Delivers `message` from `SIBLING` through the XCMP queue and processes it.
+fn receive_from_sibling(message: Xcm<()>) {
+    let mut data = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+    data.extend(VersionedXcm::from(message).encode());
+    XcmpQueue::handle_xcmp_messages(
+        [(ParaId::from(SIBLING), 1, &data[..])].into_iter(),
+        Weight::MAX,
+    );
+    MessageQueue::service_queues(Weight::MAX);
+}
+
+fn processed_successfully() -> bool {
+    System::events().iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+                success: true,
+                ..
+            })
+        )
+    })
+}
+
+fn bob_balance() -> u128 {
+    Balances::free_balance(AccountId::from(BOB))
+}
+
+#[test]
+fn untrusted_sibling_cannot_execute() {
+    new_test_ext().execute_with(|| {
+        let bob_before = bob_balance();
+
+        receive_from_sibling(ethereum_transact_message());
+
+        assert!(!processed_successfully());
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::MessageQueue(pallet_message_queue::Event::ProcessingFailed { .. })
+        )));
+        assert_eq!(bob_balance(), bob_before);
+    });
+}
+
+#[test]
+fn trusted_sibling_can_execute_ethereum_transact() {
+    new_test_ext().execute_with(|| {
+        let bob_before = bob_balance();
+        assert_ok!(TrustedSiblings::trust_sibling(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+
+        receive_from_sibling(ethereum_transact_message());
+
+        assert!(processed_successfully());
+        assert_eq!(bob_balance(), bob_before + VALUE);
+    });
+}
+
+#[test]
+fn trusted_sibling_cannot_execute_unpaid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(TrustedSiblings::trust_sibling(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+
+        receive_from_sibling(Xcm(vec![ClearOrigin]));
+
+        assert!(!processed_successfully());
+    });
+}
+
+#[test]
+fn untrusted_sibling_cannot_execute_anymore() {
+    new_test_ext().execute_with(|| {
+        let bob_before = bob_balance();
+        assert_ok!(TrustedSiblings::trust_sibling(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+        assert_ok!(TrustedSiblings::untrust_sibling(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+
+        receive_from_sibling(ethereum_transact_message());
+
+        assert!(!processed_successfully());
+        assert_eq!(bob_balance(), bob_before);
+    });
+}
@@ -23,18 +23,28 @@ pub mod db;
 pub mod extrinsic_weights;
 pub mod frame_system;
 pub mod frame_system_extensions;
+pub mod pallet_account_mapping;
+pub mod pallet_aggregation_roots;
+pub mod pallet_asset_registry;
 pub mod pallet_balances;
 pub mod pallet_collator_selection;
 pub mod pallet_deployment_permissions;
 pub mod pallet_evm;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
+pub mod pallet_precompile_kill_switch;
 pub mod pallet_proxy;
+pub mod pallet_relay_randomness;
+pub mod pallet_relay_storage_roots;
 pub mod pallet_session;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
+pub mod pallet_trusted_aliasers;
+pub mod pallet_trusted_siblings;
 pub mod pallet_utility;
 pub mod pallet_xcm;
 pub mod pallet_xcm_benchmarks;
+pub mod pallet_xcm_safe_calls;
+pub mod pallet_xcm_tracing;
 pub use extrinsic_weights::ExtrinsicBaseWeight;
@@ -0,0 +1,93 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hand-written weights for `pallet_account_mapping`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+//!
+//! The execution time of `link_account` is the time of checking two relay chain signatures (the
+//! raw and the `<Bytes>`-wrapped payload), 55µs each for the slowest scheme (sr25519), and the one
+//! of `link_account_from_relay` the time of recovering an Ethereum signer, 50µs. Both were measured
+//! natively, which is how the signature checks run through the host functions.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_account_mapping`.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_account_mapping::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `System::BlockHash` (r:1 w:0)
+    /// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkNonces` (r:1 w:1)
+    /// Proof: `AccountMapping::LinkNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkedAccounts` (r:1 w:1)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::RelayAccounts` (r:1 w:1)
+    /// Proof: `AccountMapping::RelayAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn link_account() -> Weight {
+        Weight::from_parts(110_000_000, 11122)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `System::BlockHash` (r:1 w:0)
+    /// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkNonces` (r:1 w:1)
+    /// Proof: `AccountMapping::LinkNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkedAccounts` (r:1 w:1)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::RelayAccounts` (r:1 w:1)
+    /// Proof: `AccountMapping::RelayAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn link_account_from_relay() -> Weight {
+        Weight::from_parts(50_000_000, 11122)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `AccountMapping::RelayAccounts` (r:1 w:1)
+    /// Proof: `AccountMapping::RelayAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkedAccounts` (r:0 w:1)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn unlink_account() -> Weight {
+        Weight::from_parts(0, 3533)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `AccountMapping::LinkedAccounts` (r:1 w:1)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::RelayAccounts` (r:0 w:1)
+    /// Proof: `AccountMapping::RelayAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn unlink_account_from_relay() -> Weight {
+        Weight::from_parts(0, 3533)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `AccountMapping::RelayAccounts` (r:1 w:0)
+    /// Proof: `AccountMapping::RelayAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    fn claim_legacy_account() -> Weight {
+        Weight::from_parts(0, 8715)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hand-written weights for `pallet_asset_registry`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_asset_registry`.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `ForeignAssets::Asset` (r:1 w:0)
+    /// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::AssetIdToLocation` (r:1 w:1)
+    /// Proof: `AssetRegistry::AssetIdToLocation` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:1)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    fn register_foreign_asset() -> Weight {
+        Weight::from_parts(0, 9817)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `AssetRegistry::AssetIdToLocation` (r:1 w:1)
+    /// Proof: `AssetRegistry::AssetIdToLocation` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:0 w:1)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::FeeRates` (r:0 w:1)
+    /// Proof: `AssetRegistry::FeeRates` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn unregister_foreign_asset() -> Weight {
+        Weight::from_parts(0, 4085)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `AssetRegistry::AssetIdToLocation` (r:1 w:0)
+    /// Proof: `AssetRegistry::AssetIdToLocation` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::FeeRates` (r:0 w:1)
+    /// Proof: `AssetRegistry::FeeRates` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn set_fee_rate() -> Weight {
+        Weight::from_parts(0, 4085)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `AssetRegistry::FeeRates` (r:1 w:1)
+    /// Proof: `AssetRegistry::FeeRates` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn remove_fee_rate() -> Weight {
+        Weight::from_parts(0, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
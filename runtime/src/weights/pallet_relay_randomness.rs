@@ -0,0 +1,87 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hand-written weights for `pallet_relay_randomness`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+//!
+//! The execution time of `on_finalize`, which is charged in every block, is the time of reading
+//! the randomness from a 19 kB relay state proof (100 inbound and 100 outbound HRMP channels):
+//! 113µs measured natively, multiplied by 4 for the execution in WASM.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_relay_randomness`.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_relay_randomness::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::NextRequestId` (r:1 w:1)
+    /// Proof: `RelayRandomness::NextRequestId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::Requests` (r:0 w:1)
+    /// Proof: `RelayRandomness::Requests` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+    fn request_randomness() -> Weight {
+        Weight::from_parts(0, 6608)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `RelayRandomness::Requests` (r:1 w:1)
+    /// Proof: `RelayRandomness::Requests` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    fn fulfil_randomness() -> Weight {
+        Weight::from_parts(0, 8668)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RelayRandomness::Requests` (r:1 w:1)
+    /// Proof: `RelayRandomness::Requests` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    fn purge_expired_request() -> Weight {
+        Weight::from_parts(0, 8668)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RelayRandomness::RandomnessResults` (r:1 w:1)
+    /// Proof: `RelayRandomness::RandomnessResults` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+    /// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::RelayStateProof` (r:1 w:0)
+    /// Proof: `ParachainSystem::RelayStateProof` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn on_finalize() -> Weight {
+        Weight::from_parts(452_000_000, 4013)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hand-written weights for `pallet_trusted_aliasers`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_trusted_aliasers`.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_trusted_aliasers::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `TrustedAliasers::TrustedAliasers` (r:1 w:1)
+    /// Proof: `TrustedAliasers::TrustedAliasers` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+    fn trust_aliaser() -> Weight {
+        Weight::from_parts(0, 4097)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `TrustedAliasers::TrustedAliasers` (r:1 w:1)
+    /// Proof: `TrustedAliasers::TrustedAliasers` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+    fn untrust_aliaser() -> Weight {
+        Weight::from_parts(0, 4097)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
@@ -14,10 +14,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod foreign_assets;
 pub mod fungible;
 pub mod generic;
 
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight};
 
 use crate::Runtime;
 use core::marker::PhantomData;
@@ -29,6 +30,7 @@ use xcm::{
     DoubleEncoded,
 };
 
+use foreign_assets::ZKVEvmWeight as XcmForeignAssetsWeight;
 use fungible::ZKVEvmWeight as XcmBalancesWeight;
 use generic::ZKVEvmWeight as XcmGenericWeight;
 
@@ -36,6 +38,8 @@ use generic::ZKVEvmWeight as XcmGenericWeight;
 pub enum AssetTypes {
     /// An asset backed by `pallet-balances`.
     Balances,
+    /// A foreign asset registered in `pallet-asset-registry`, backed by `pallet-assets`.
+    ForeignAssets,
     /// Unknown asset.
     Unknown,
 }
@@ -51,50 +55,67 @@ impl From<&Asset> for AssetTypes {
                     }),
                 ..
             } => AssetTypes::Balances,
+            Asset {
+                id: AssetId(location),
+                ..
+            } if pallet_asset_registry::LocationToAssetId::<Runtime>::contains_key(location) => {
+                AssetTypes::ForeignAssets
+            }
             _ => AssetTypes::Unknown,
         }
     }
 }
 
+impl AssetTypes {
+    /// The foreign assets also pay for the lookup of their location in
+    /// `AssetRegistry::LocationToAssetId` done by `AssetTypes::from`.
+    fn weigh(self, balances_weight: Weight, foreign_assets_weight: Weight) -> Weight {
+        match self {
+            AssetTypes::Balances => balances_weight,
+            AssetTypes::ForeignAssets => foreign_assets_weight
+                .saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1)),
+            AssetTypes::Unknown => Weight::MAX,
+        }
+    }
+}
+
 trait WeighAssets {
-    fn weigh_multi_assets(&self, balances_weight: Weight) -> Weight;
+    fn weigh_multi_assets(&self, balances_weight: Weight, foreign_assets_weight: Weight) -> Weight;
 }
 
-// ZKV only knows about one asset, the balances pallet.
-const MAX_ASSETS: u64 = 1;
+// The holding register can hold the native token and any number of foreign assets: the
+// wildcards can match up to as many assets as it can contain.
+const MAX_ASSETS: u64 = crate::configs::xcm::MaxAssetsIntoHolding::get() as u64;
 
 impl WeighAssets for AssetFilter {
-    fn weigh_multi_assets(&self, balances_weight: Weight) -> Weight {
+    fn weigh_multi_assets(&self, balances_weight: Weight, foreign_assets_weight: Weight) -> Weight {
+        // The wildcards are weighed as the most expensive kind of asset.
+        let wild_weight = balances_weight
+            .max(AssetTypes::ForeignAssets.weigh(balances_weight, foreign_assets_weight));
         match self {
             Self::Definite(assets) => assets
                 .inner()
                 .iter()
                 .map(From::from)
-                .map(|t| match t {
-                    AssetTypes::Balances => balances_weight,
-                    AssetTypes::Unknown => Weight::MAX,
-                })
+                .map(|t| t.weigh(balances_weight, foreign_assets_weight))
                 .fold(Weight::zero(), |acc, x| acc.saturating_add(x)),
             // We don't support any NFTs on ZKV, so these two variants will always match
             // only 1 kind of fungible asset.
-            Self::Wild(AllOf { .. } | AllOfCounted { .. }) => balances_weight,
+            Self::Wild(AllOf { .. } | AllOfCounted { .. }) => wild_weight,
             Self::Wild(AllCounted(count)) => {
-                balances_weight.saturating_mul(MAX_ASSETS.min(*count as u64))
+                wild_weight.saturating_mul(MAX_ASSETS.min(*count as u64))
             }
-            Self::Wild(All) => balances_weight.saturating_mul(MAX_ASSETS),
+            Self::Wild(All) => wild_weight.saturating_mul(MAX_ASSETS),
         }
     }
 }
 
 impl WeighAssets for Assets {
-    fn weigh_multi_assets(&self, balances_weight: Weight) -> Weight {
+    fn weigh_multi_assets(&self, balances_weight: Weight, foreign_assets_weight: Weight) -> Weight {
         self.inner()
             .iter()
             .map(<AssetTypes as From<&Asset>>::from)
-            .map(|t| match t {
-                AssetTypes::Balances => balances_weight,
-                AssetTypes::Unknown => Weight::MAX,
-            })
+            .map(|t| t.weigh(balances_weight, foreign_assets_weight))
             .fold(Weight::zero(), |acc, x| acc.saturating_add(x))
     }
 }
@@ -104,13 +125,23 @@ pub struct ZKVEvmWeight<T>(PhantomData<T>);
 
 impl<T> xcm::latest::XcmWeightInfo<T> for ZKVEvmWeight<T> {
     fn withdraw_asset(assets: &Assets) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::withdraw_asset())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::withdraw_asset(),
+            XcmForeignAssetsWeight::<Runtime>::withdraw_asset(),
+        )
     }
     fn reserve_asset_deposited(assets: &Assets) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::reserve_asset_deposited())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::reserve_asset_deposited(),
+            XcmForeignAssetsWeight::<Runtime>::reserve_asset_deposited(),
+        )
     }
     fn receive_teleported_asset(assets: &Assets) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::receive_teleported_asset())
+        // Foreign assets are never teleported.
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::receive_teleported_asset(),
+            Weight::MAX,
+        )
     }
     fn query_response(
         _id: &u64,
@@ -121,22 +152,27 @@ impl<T> xcm::latest::XcmWeightInfo<T> for ZKVEvmWeight<T> {
         XcmGenericWeight::<Runtime>::query_response()
     }
     fn transfer_asset(assets: &Assets, _dest: &Location) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::transfer_asset())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::transfer_asset(),
+            XcmForeignAssetsWeight::<Runtime>::transfer_asset(),
+        )
     }
     fn transfer_reserve_asset(
         assets: &Assets,
         _dest: &Location,
         _xcm: &Xcm<()>,
     ) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::transfer_reserve_asset())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::transfer_reserve_asset(),
+            XcmForeignAssetsWeight::<Runtime>::transfer_reserve_asset(),
+        )
     }
     fn transact(
         _origin_kind: &OriginKind,
         _require_weight_at_most: &Option<Weight>,
         _call: &DoubleEncoded<T>,
     ) -> sp_weights::Weight {
-        Weight::default()
-        //XcmGenericWeight::<Runtime>::transact()
+        XcmGenericWeight::<Runtime>::transact()
     }
     fn hrmp_new_channel_open_request(_: &u32, _: &u32, _: &u32) -> sp_weights::Weight {
         // XCM Executor does not currently support HRMP channel operations
@@ -160,14 +196,20 @@ impl<T> xcm::latest::XcmWeightInfo<T> for ZKVEvmWeight<T> {
         XcmGenericWeight::<Runtime>::report_error()
     }
     fn deposit_asset(assets: &AssetFilter, _dest: &Location) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::deposit_asset())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::deposit_asset(),
+            XcmForeignAssetsWeight::<Runtime>::deposit_asset(),
+        )
     }
     fn deposit_reserve_asset(
         assets: &AssetFilter,
         _dest: &Location,
         _xcm: &Xcm<()>,
     ) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::deposit_reserve_asset())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::deposit_reserve_asset(),
+            XcmForeignAssetsWeight::<Runtime>::deposit_reserve_asset(),
+        )
     }
     fn exchange_asset(_: &AssetFilter, _: &Assets, _: &bool) -> sp_weights::Weight {
         // ZKV does not currently support exchange asset operations
@@ -178,14 +220,20 @@ impl<T> xcm::latest::XcmWeightInfo<T> for ZKVEvmWeight<T> {
         _reserve: &Location,
         _xcm: &Xcm<()>,
     ) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::initiate_reserve_withdraw())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::initiate_reserve_withdraw(),
+            XcmForeignAssetsWeight::<Runtime>::initiate_reserve_withdraw(),
+        )
     }
     fn initiate_teleport(
         assets: &AssetFilter,
         _dest: &Location,
         _xcm: &Xcm<()>,
     ) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmBalancesWeight::<Runtime>::initiate_teleport())
+        assets.weigh_multi_assets(
+            XcmBalancesWeight::<Runtime>::initiate_teleport(),
+            XcmForeignAssetsWeight::<Runtime>::initiate_teleport(),
+        )
     }
     fn report_holding(_: &QueryResponseInfo, _: &AssetFilter) -> sp_weights::Weight {
         XcmGenericWeight::<Runtime>::report_holding()
@@ -218,10 +266,12 @@ impl<T> xcm::latest::XcmWeightInfo<T> for ZKVEvmWeight<T> {
         XcmGenericWeight::<Runtime>::unsubscribe_version()
     }
     fn burn_asset(assets: &Assets) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmGenericWeight::<Runtime>::burn_asset())
+        let weight = XcmGenericWeight::<Runtime>::burn_asset();
+        assets.weigh_multi_assets(weight, weight)
     }
     fn expect_asset(assets: &Assets) -> sp_weights::Weight {
-        assets.weigh_multi_assets(XcmGenericWeight::<Runtime>::expect_asset())
+        let weight = XcmGenericWeight::<Runtime>::expect_asset();
+        assets.weigh_multi_assets(weight, weight)
     }
     fn expect_origin(_: &core::option::Option<Location>) -> sp_weights::Weight {
         XcmGenericWeight::<Runtime>::expect_origin()
@@ -278,8 +328,7 @@ impl<T> xcm::latest::XcmWeightInfo<T> for ZKVEvmWeight<T> {
         XcmGenericWeight::<Runtime>::clear_topic()
     }
     fn alias_origin(_: &Location) -> sp_weights::Weight {
-        // XCM Executor does not currently support alias origin operations
-        Weight::MAX
+        XcmGenericWeight::<Runtime>::alias_origin()
     }
     fn unpaid_execution(_: &WeightLimit, _: &core::option::Option<Location>) -> sp_weights::Weight {
         XcmGenericWeight::<Runtime>::unpaid_execution()
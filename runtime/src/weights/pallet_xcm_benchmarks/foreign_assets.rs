@@ -0,0 +1,140 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hand-written weights for `pallet_xcm_benchmarks :: fungible` with the `ForeignFungiblesTransactor`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_benchmarks :: fungible` with the `ForeignFungiblesTransactor`.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> ZKVEvmWeight<T> {
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Asset` (r:1 w:1)
+    /// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Account` (r:1 w:1)
+    /// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    pub(crate) fn withdraw_asset() -> Weight {
+        Weight::from_parts(0, 11898)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Asset` (r:1 w:1)
+    /// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Account` (r:2 w:2)
+    /// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    pub(crate) fn transfer_asset() -> Weight {
+        Weight::from_parts(0, 17074)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Asset` (r:1 w:1)
+    /// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Account` (r:2 w:2)
+    /// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+    /// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+    /// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    pub(crate) fn transfer_reserve_asset() -> Weight {
+        Weight::from_parts(0, 17573)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    pub(crate) fn reserve_asset_deposited() -> Weight {
+        Weight::from_parts(0, 4085)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+    /// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+    /// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    pub(crate) fn initiate_reserve_withdraw() -> Weight {
+        Weight::from_parts(0, 1489)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Asset` (r:1 w:1)
+    /// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Account` (r:1 w:1)
+    /// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    pub(crate) fn deposit_asset() -> Weight {
+        Weight::from_parts(0, 11898)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+    /// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Asset` (r:1 w:1)
+    /// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `ForeignAssets::Account` (r:1 w:1)
+    /// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+    /// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+    /// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    pub(crate) fn deposit_reserve_asset() -> Weight {
+        Weight::from_parts(0, 12397)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+    /// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+    /// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    pub(crate) fn initiate_teleport() -> Weight {
+        Weight::from_parts(0, 1489)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
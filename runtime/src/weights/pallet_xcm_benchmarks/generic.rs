@@ -104,12 +104,13 @@ impl<T: frame_system::Config> ZKVEvmWeight<T> {
         Weight::from_parts(6_776_000, 3465)
             .saturating_add(T::DbWeight::get().reads(1_u64))
     }
+    /// Hand-written: `SafeCallFilter` reads `XcmSafeCalls::SafeCalls` since this file was
+    /// generated. The execution time is the generated one until the benchmark is run again.
+    /// Storage: `XcmSafeCalls::SafeCalls` (r:1 w:0)
+    /// Proof: `XcmSafeCalls::SafeCalls` (`max_values`: Some(1), `max_size`: Some(258), added: 753, mode: `MaxEncodedLen`)
     pub(crate) fn transact() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 9_104_000 picoseconds.
-        Weight::from_parts(9_355_000, 0)
+        Weight::from_parts(9_355_000, 1743)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
     }
     pub(crate) fn refund_surplus() -> Weight {
         // Proof Size summary in bytes:
@@ -327,4 +328,15 @@ impl<T: frame_system::Config> ZKVEvmWeight<T> {
         // Minimum execution time: 789_000 picoseconds.
         Weight::from_parts(849_000, 0)
     }
+    /// Hand-written: `alias_origin` was added to the benchmark config after this file was
+    /// generated. The execution time is the one of `descend_origin`, which replaces the origin in
+    /// the same way, until the benchmark is run.
+    /// Storage: `TrustedAliasers::TrustedAliasers` (r:1 w:0)
+    /// Proof: `TrustedAliasers::TrustedAliasers` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkedAccounts` (r:1 w:0)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    pub(crate) fn alias_origin() -> Weight {
+        Weight::from_parts(1_043_000, 6640)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+    }
 }
@@ -0,0 +1,52 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hand-written weights for `pallet_xcm_tracing`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. Each function charges the database reads and writes of
+//! the storage items listed above it and a proof size bounded by the `MaxEncodedLen` of the items
+//! it reads. The execution time of the functions is not accounted for: replace this file with the
+//! output of `benchmark pallet` before relying on it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_tracing`.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_xcm_tracing::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `XcmTracing::NextTraceId` (r:1 w:1)
+    /// Proof: `XcmTracing::NextTraceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::OldestTraceId` (r:1 w:1)
+    /// Proof: `XcmTracing::OldestTraceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::Traces` (r:2 w:3)
+    /// Proof: `XcmTracing::Traces` (`max_values`: None, `max_size`: Some(1342), added: 3817, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::TopicTraces` (r:0 w:3)
+    /// Proof: `XcmTracing::TopicTraces` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `XcmTracing::AccountTraces` (r:0 w:3)
+    /// Proof: `XcmTracing::AccountTraces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `AccountMapping::LinkedAccounts` (r:1 w:0)
+    /// Proof: `AccountMapping::LinkedAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn record_trace() -> Weight {
+        Weight::from_parts(0, 12173)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+}